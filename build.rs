@@ -0,0 +1,15 @@
+// สร้าง promptpay.h ใน OUT_DIR จาก src/ffi.rs เมื่อเปิด feature `ffi`
+// (ไม่เขียนลง source tree; test ใน src/ffi.rs ตรวจว่า include/promptpay.h ตรงกัน)
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "ffi")]
+    {
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out_dir = std::env::var("OUT_DIR").unwrap();
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+        cbindgen::generate(&crate_dir)
+            .expect("Unable to generate C header")
+            .write_to_file(format!("{}/promptpay.h", out_dir));
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * Generates and decodes a payload through the C API.
+ *
+ *   cargo rustc --lib --release --features ffi --crate-type staticlib,cdylib
+ *   cc examples/c/pos.c -Iinclude target/release/libpromptpay_rs.a -lpthread -ldl -lm -o pos
+ *   ./pos 0812345678 150.00
+ */
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "promptpay.h"
+
+int main(int argc, char **argv) {
+    if (argc < 2) {
+        fprintf(stderr, "usage: %s PROXY [AMOUNT]\n", argv[0]);
+        return 2;
+    }
+
+    PromptPayQR *qr = promptpay_new(argv[1]);
+    if (argc > 2) {
+        promptpay_set_amount(qr, atof(argv[2]));
+    }
+    promptpay_set_label(qr, PROMPT_PAY_LABEL_TERMINAL_LABEL, "POS-01");
+
+    char payload[513];
+    size_t len = 0;
+    PromptPayStatus status = promptpay_create(qr, payload, sizeof payload, &len);
+    promptpay_free(qr);
+    if (status != PROMPT_PAY_STATUS_OK) {
+        fprintf(stderr, "error %d: %s\n", status, promptpay_last_error());
+        return 1;
+    }
+    printf("%s\n", payload);
+
+    /* decode it again */
+    PromptPayQR *decoded = NULL;
+    status = promptpay_parse(payload, &decoded);
+    if (status != PROMPT_PAY_STATUS_OK) {
+        fprintf(stderr, "error %d: %s\n", status, promptpay_last_error());
+        return 1;
+    }
+    char proxy[64];
+    promptpay_get_field(decoded, PROMPT_PAY_FIELD_MERCHANT_ID, proxy, sizeof proxy, &len);
+    double amount = 0;
+    if (promptpay_get_amount(decoded, &amount) == PROMPT_PAY_STATUS_OK) {
+        printf("=> %s, %.2f THB\n", proxy, amount);
+    } else {
+        printf("=> %s\n", proxy);
+    }
+    promptpay_free(decoded);
+    return 0;
+}
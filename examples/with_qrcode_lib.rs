@@ -1,5 +1,4 @@
-use promptpay_rs::{PromptPayError, PromptPayQR};
-use qrcode::{EcLevel, QrCode, render::unicode};
+use promptpay_rs::{EcLevel, PromptPayQR, render::RenderOptions};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize a new PromptPay QR object with a phone number
@@ -14,19 +13,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let payload = qr.create()?;
     println!("Payload: {}", payload);
 
-    // Generate the QR code image from the payload
-    let qr_code = QrCode::with_error_correction_level(payload.as_bytes(), EcLevel::M)
-        .map_err(|e| PromptPayError::new(&format!("Failed to create QRCode: {}", e)))?;
+    // Render the QR code for terminal display (requires the `qrcode` feature)
+    let options = RenderOptions {
+        ec_level: EcLevel::M,
+        ..RenderOptions::default()
+    };
+    println!("{}", qr.to_terminal_string(&options)?);
 
-    // Render the QR code in Unicode format for terminal display
-    let image = qr_code
-        .render::<unicode::Dense1x2>()
-        .dark_color(unicode::Dense1x2::Light)
-        .light_color(unicode::Dense1x2::Dark)
-        .build();
-
-    // Print the QR code in the terminal
-    println!("{}", image);
+    // The same options can be used to write SVG or PNG files
+    std::fs::write("promptpay.svg", qr.to_svg(&options)?)?;
+    std::fs::write("promptpay.png", qr.to_png_bytes(&options)?)?;
 
     Ok(())
 }
@@ -0,0 +1,193 @@
+/* PromptPay QR C API. Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#ifndef PROMPTPAY_H
+#define PROMPTPAY_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Result code of the C API functions.
+typedef enum PromptPayStatus {
+  // Success
+  PROMPT_PAY_STATUS_OK = 0,
+  // A required pointer argument is `NULL`
+  PROMPT_PAY_STATUS_NULL_POINTER = 1,
+  // A string argument is not valid UTF-8
+  PROMPT_PAY_STATUS_INVALID_UTF8 = 2,
+  // Input supplied by the caller is missing or malformed
+  PROMPT_PAY_STATUS_INVALID_INPUT = 3,
+  // The payload could not be decoded
+  PROMPT_PAY_STATUS_INVALID_PAYLOAD = 4,
+  // The payload CRC does not match its content
+  PROMPT_PAY_STATUS_CRC_MISMATCH = 5,
+  // The output buffer is too small, `out_len` holds the required length
+  PROMPT_PAY_STATUS_BUFFER_TOO_SMALL = 6,
+  // The requested field is not present in the payload
+  PROMPT_PAY_STATUS_NOT_FOUND = 7,
+  // Any other error
+  PROMPT_PAY_STATUS_OTHER = 99,
+} PromptPayStatus;
+
+// Additional data label (tag 62) set with `promptpay_set_label`.
+typedef enum PromptPayLabel {
+  // Bill number (62-01)
+  PROMPT_PAY_LABEL_BILL_NUMBER = 1,
+  // Store label (62-03)
+  PROMPT_PAY_LABEL_STORE_LABEL = 3,
+  // Reference label (62-05)
+  PROMPT_PAY_LABEL_REFERENCE_LABEL = 5,
+  // Customer label (62-06)
+  PROMPT_PAY_LABEL_CUSTOMER_LABEL = 6,
+  // Terminal label (62-07)
+  PROMPT_PAY_LABEL_TERMINAL_LABEL = 7,
+} PromptPayLabel;
+
+// Text field read with `promptpay_get_field`.
+typedef enum PromptPayField {
+  // Proxy (mobile number, tax ID, e-wallet ID or biller ID)
+  PROMPT_PAY_FIELD_MERCHANT_ID = 0,
+  // Merchant name (tag 59)
+  PROMPT_PAY_FIELD_MERCHANT_NAME = 1,
+  // Merchant city (tag 60)
+  PROMPT_PAY_FIELD_MERCHANT_CITY = 2,
+  // Ref1 of a bill payment QR
+  PROMPT_PAY_FIELD_REFERENCE1 = 3,
+  // Ref2 of a bill payment QR
+  PROMPT_PAY_FIELD_REFERENCE2 = 4,
+  // Country code, `"TH"`
+  PROMPT_PAY_FIELD_COUNTRY_CODE = 5,
+  // Numeric currency code, `"764"`
+  PROMPT_PAY_FIELD_CURRENCY_CODE = 6,
+} PromptPayField;
+
+// Kind of proxy a payload pays to.
+typedef enum PromptPayMerchantType {
+  PROMPT_PAY_MERCHANT_TYPE_MOBILE_NUMBER = 0,
+  PROMPT_PAY_MERCHANT_TYPE_TAX_ID = 1,
+  PROMPT_PAY_MERCHANT_TYPE_E_WALLET_ID = 2,
+  PROMPT_PAY_MERCHANT_TYPE_BILLER_ID = 3,
+} PromptPayMerchantType;
+
+// Opaque handle to a payload, `PromptPayQR` in C.
+typedef struct PromptPayQR PromptPayQR;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns the library version, e.g. `"0.6.0"`.
+const char *promptpay_version(void);
+
+// Returns the message of the last failed call on this thread (empty if none).
+//
+// The pointer stays valid until the next failing call on the same thread.
+const char *promptpay_last_error(void);
+
+// Creates a credit transfer QR for a mobile number, national / tax ID or e-wallet ID.
+//
+// Returns `NULL` if `proxy` is `NULL` or not UTF-8. The proxy itself is
+// validated by `promptpay_create`.
+//
+// # Safety
+// `proxy` must be `NULL` or a NUL-terminated string.
+struct PromptPayQR *promptpay_new(const char *proxy);
+
+// Creates a bill payment QR (tag 30). `ref2` may be `NULL`.
+//
+// Returns `NULL` if `biller_id` or `ref1` is `NULL`, or a string is not UTF-8.
+//
+// # Safety
+// Each argument must be `NULL` or a NUL-terminated string.
+struct PromptPayQR *promptpay_bill_payment(const char *biller_id,
+                                           const char *ref1,
+                                           const char *ref2);
+
+// Decodes and CRC-checks a payload into a new handle stored in `out`.
+//
+// # Safety
+// `payload` must be `NULL` or a NUL-terminated string, `out` must be `NULL`
+// or point to writable storage for a pointer.
+enum PromptPayStatus promptpay_parse(const char *payload, struct PromptPayQR **out);
+
+// Checks a payload without keeping the decoded handle.
+//
+// # Safety
+// `payload` must be `NULL` or a NUL-terminated string.
+enum PromptPayStatus promptpay_verify(const char *payload);
+
+// Releases a handle. Passing `NULL` does nothing.
+//
+// # Safety
+// `qr` must be `NULL` or a handle that has not been freed yet.
+void promptpay_free(struct PromptPayQR *qr);
+
+// Sets the amount in THB, making the QR dynamic.
+//
+// # Safety
+// `qr` must be `NULL` or a live handle.
+enum PromptPayStatus promptpay_set_amount(struct PromptPayQR *qr, double amount);
+
+// Sets the merchant name in Latin script (tag 59).
+//
+// # Safety
+// `qr` must be `NULL` or a live handle, `name` `NULL` or a NUL-terminated string.
+enum PromptPayStatus promptpay_set_merchant_name(struct PromptPayQR *qr, const char *name);
+
+// Sets the merchant city in Latin script (tag 60).
+//
+// # Safety
+// `qr` must be `NULL` or a live handle, `city` `NULL` or a NUL-terminated string.
+enum PromptPayStatus promptpay_set_merchant_city(struct PromptPayQR *qr, const char *city);
+
+// Sets the merchant name in Thai (tag 64).
+//
+// # Safety
+// `qr` must be `NULL` or a live handle, `name` `NULL` or a NUL-terminated string.
+enum PromptPayStatus promptpay_set_thai_name(struct PromptPayQR *qr, const char *name);
+
+// Sets an additional data label (tag 62), keeping the other labels.
+//
+// # Safety
+// `qr` must be `NULL` or a live handle, `value` `NULL` or a NUL-terminated string.
+enum PromptPayStatus promptpay_set_label(struct PromptPayQR *qr,
+                                         enum PromptPayLabel label,
+                                         const char *value);
+
+// Builds the payload into `buf` (at most 512 characters plus the NUL).
+//
+// # Safety
+// `qr` must be `NULL` or a live handle, `buf` `NULL` or writable for `buf_len`
+// bytes, `out_len` `NULL` or writable.
+enum PromptPayStatus promptpay_create(const struct PromptPayQR *qr,
+                                      char *buf,
+                                      size_t buf_len,
+                                      size_t *out_len);
+
+// Returns the kind of proxy, or `-1` if `qr` is `NULL`.
+//
+// # Safety
+// `qr` must be `NULL` or a live handle.
+int32_t promptpay_get_merchant_type(const struct PromptPayQR *qr);
+
+// Stores the amount in `out`, or returns `PROMPT_PAY_STATUS_NOT_FOUND` for a static QR.
+//
+// # Safety
+// `qr` must be `NULL` or a live handle, `out` `NULL` or writable.
+enum PromptPayStatus promptpay_get_amount(const struct PromptPayQR *qr, double *out);
+
+// Writes a text field into `buf`, or returns `PROMPT_PAY_STATUS_NOT_FOUND` if absent.
+//
+// # Safety
+// `qr` must be `NULL` or a live handle, `buf` `NULL` or writable for `buf_len`
+// bytes, `out_len` `NULL` or writable.
+enum PromptPayStatus promptpay_get_field(const struct PromptPayQR *qr,
+                                         enum PromptPayField field,
+                                         char *buf,
+                                         size_t buf_len,
+                                         size_t *out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PROMPTPAY_H */
@@ -0,0 +1,100 @@
+"""Type stubs for the `promptpay` extension module (pyo3 bindings of promptpay-rs)."""
+
+from typing import Dict, Literal, Mapping, Optional
+
+__version__: str
+
+MerchantType = Literal["mobile_number", "tax_id", "ewallet_id", "biller_id"]
+EcLevel = Literal["L", "M", "Q", "H"]
+AdditionalDataKey = Literal[
+    "bill_number",
+    "mobile_number",
+    "store_label",
+    "loyalty_number",
+    "reference_label",
+    "customer_label",
+    "terminal_label",
+    "purpose",
+]
+
+class PromptPayError(ValueError):
+    """Base class of all errors raised by promptpay."""
+
+class InvalidInputError(PromptPayError):
+    """Input supplied by the caller is missing or malformed."""
+
+class InvalidPayloadError(PromptPayError):
+    """A payload could not be decoded."""
+
+class CrcMismatchError(PromptPayError):
+    """The payload CRC does not match its content."""
+
+class RenderError(PromptPayError):
+    """The QR code could not be rendered."""
+
+class PromptPayQR:
+    """A PromptPay payload."""
+
+    def __init__(
+        self,
+        proxy: str,
+        amount: Optional[float] = None,
+        *,
+        merchant_name: Optional[str] = None,
+        merchant_city: Optional[str] = None,
+        thai_name: Optional[str] = None,
+        additional_data: Optional[Mapping[AdditionalDataKey, str]] = None,
+    ) -> None:
+        """Creates a credit transfer QR for a mobile number, national / tax ID or e-wallet ID."""
+    @staticmethod
+    def bill_payment(
+        biller_id: str,
+        ref1: str,
+        ref2: Optional[str] = None,
+        amount: Optional[float] = None,
+        *,
+        merchant_name: Optional[str] = None,
+        merchant_city: Optional[str] = None,
+        thai_name: Optional[str] = None,
+        additional_data: Optional[Mapping[AdditionalDataKey, str]] = None,
+    ) -> PromptPayQR:
+        """Creates a bill payment QR (tag 30)."""
+    @staticmethod
+    def parse(payload: str) -> PromptPayQR:
+        """Decodes and CRC-checks a payload.
+
+        Raises `CrcMismatchError` or `InvalidPayloadError`.
+        """
+    def create(self) -> str:
+        """Builds the payload string. Raises `InvalidInputError`."""
+    def to_svg(self, ec_level: EcLevel = "M") -> str:
+        """Renders the payload as an SVG document with the built-in encoder."""
+    @property
+    def merchant_id(self) -> str: ...
+    @property
+    def merchant_type(self) -> MerchantType: ...
+    @property
+    def amount(self) -> Optional[float]: ...
+    @property
+    def country_code(self) -> Literal["TH"]: ...
+    @property
+    def currency_code(self) -> Literal["764"]: ...
+    @property
+    def reference1(self) -> Optional[str]: ...
+    @property
+    def reference2(self) -> Optional[str]: ...
+    @property
+    def merchant_name(self) -> Optional[str]: ...
+    @property
+    def merchant_city(self) -> Optional[str]: ...
+    @property
+    def thai_name(self) -> Optional[str]: ...
+    @property
+    def additional_data(self) -> Dict[AdditionalDataKey, str]: ...
+    def __eq__(self, other: object) -> bool: ...
+
+def verify_crc(payload: str) -> bool:
+    """Returns `True` if the payload ends with a CRC field (tag 63) that matches its content."""
+
+def calculate_crc(data: str) -> str:
+    """Returns the CRC-16/CCITT-FALSE of `data` as 4 uppercase hex digits."""
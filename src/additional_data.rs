@@ -0,0 +1,113 @@
+//! **Additional Data Field Template** (tag `62`).
+//!
+//! Carries references the merchant uses to match a payment (bill number,
+//! terminal label, ...). Each sub-field is up to 25 characters.
+
+use crate::{PromptPayError, error::ErrorKind, tlv};
+
+// ความยาวสูงสุดของแต่ละ sub-field ตาม EMVCo
+const MAX_FIELD_LENGTH: usize = 25;
+
+/// Sub-fields of the **Additional Data Field Template** (tag `62`).
+///
+/// All fields are optional; empty templates are not written to the payload.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{PromptPayQR, additional_data::AdditionalData};
+///
+/// let mut qr = PromptPayQR::new("0812345678");
+/// qr.set_additional_data(AdditionalData {
+///     terminal_label: Some("TABLE-07".to_string()),
+///     ..AdditionalData::default()
+/// });
+/// assert!(qr.create().unwrap().contains("62120708TABLE-07"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct AdditionalData {
+    /// `01` Bill number or invoice number
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub bill_number: Option<String>,
+    /// `02` Mobile number (e.g. for top-up payments)
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub mobile_number: Option<String>,
+    /// `03` Store label
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub store_label: Option<String>,
+    /// `04` Loyalty number
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub loyalty_number: Option<String>,
+    /// `05` Reference label (transaction reference)
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub reference_label: Option<String>,
+    /// `06` Customer label
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub customer_label: Option<String>,
+    /// `07` Terminal label (e.g. table or counter number)
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub terminal_label: Option<String>,
+    /// `08` Purpose of transaction
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub purpose: Option<String>,
+}
+
+impl AdditionalData {
+    // (sub-tag, ค่า, ชื่อฟิลด์) เรียงตาม sub-tag
+    fn entries(&self) -> [(&'static str, &Option<String>, &'static str); 8] {
+        [
+            ("01", &self.bill_number, "Bill number"),
+            ("02", &self.mobile_number, "Mobile number"),
+            ("03", &self.store_label, "Store label"),
+            ("04", &self.loyalty_number, "Loyalty number"),
+            ("05", &self.reference_label, "Reference label"),
+            ("06", &self.customer_label, "Customer label"),
+            ("07", &self.terminal_label, "Terminal label"),
+            ("08", &self.purpose, "Purpose of transaction"),
+        ]
+    }
+
+    /// Returns `true` if no sub-field is set.
+    pub fn is_empty(&self) -> bool {
+        self.entries().iter().all(|(_, value, _)| value.is_none())
+    }
+
+    /// Encodes the template value (without the outer tag `62`).
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Concatenated sub-fields
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if a sub-field is empty, longer than
+    ///   25 characters, or the template exceeds 99 characters
+    pub fn encode(&self) -> Result<String, PromptPayError> {
+        let mut value = String::new();
+        for (tag, field, name) in self.entries() {
+            if let Some(field) = field {
+                value.push_str(&tlv::encode_checked(tag, field, name, MAX_FIELD_LENGTH)?);
+            }
+        }
+        if value.chars().count() > 99 {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "Additional data (tag 62) must not exceed 99 characters",
+            ));
+        }
+        Ok(value)
+    }
+
+    /// Decodes the template value of tag `62`; unknown sub-fields are ignored.
+    pub fn parse(value: &str) -> Result<Self, PromptPayError> {
+        let fields = tlv::parse(value)?;
+        let get = |tag: &str| tlv::find(&fields, tag).map(|v| v.to_string());
+        Ok(AdditionalData {
+            bill_number: get("01"),
+            mobile_number: get("02"),
+            store_label: get("03"),
+            loyalty_number: get("04"),
+            reference_label: get("05"),
+            customer_label: get("06"),
+            terminal_label: get("07"),
+            purpose: get("08"),
+        })
+    }
+}
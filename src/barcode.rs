@@ -0,0 +1,270 @@
+use crate::{
+    PromptPayError, PromptPayQR,
+    constants::MerchantType,
+    error::ErrorKind,
+    utils::{is_valid_biller_id, is_valid_reference, sanitize_target},
+};
+
+/// Thai **cross-bank bill payment barcode** (Code 128 text).
+///
+/// Printed utility bills encode the following text in a Code 128 barcode:
+///
+/// ```text
+/// |{Biller ID}\r{Ref1}\r{Ref2}\r{Amount in satang}
+/// ```
+///
+/// - Biller ID: 15 digits (Tax ID + 2-digit suffix)
+/// - Ref2: may be empty
+/// - Amount: integer number of satang without decimal point, `0` when the payer enters the amount
+///
+/// The same data can be carried by a bill payment QR (tag `30`); use
+/// [`BillPaymentBarcode::to_qr`] and [`BillPaymentBarcode::from_qr`] to convert
+/// between them without losing information.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::barcode::BillPaymentBarcode;
+///
+/// let bill = BillPaymentBarcode::parse("|010753600031508\rCUST001\rINV2025001\r150000").unwrap();
+/// assert_eq!(bill.amount, Some(1500.0));
+///
+/// let payload = bill.to_qr().create().unwrap();
+/// assert!(payload.contains("54071500.00"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BillPaymentBarcode {
+    /// 15-digit Biller ID
+    pub biller_id: String,
+    /// `Ref1` (customer or account number)
+    pub reference1: String,
+    /// `Ref2` (e.g. invoice number), `None` when empty
+    pub reference2: Option<String>,
+    /// Amount in THB, `None` when the payer enters the amount
+    ///
+    /// The barcode writes `0` for "no amount", so a zero amount means the same
+    /// as `None`: it is parsed back as `None` and converted to a static QR.
+    pub amount: Option<f64>,
+}
+
+impl BillPaymentBarcode {
+    /// Creates a bill payment barcode without amount.
+    ///
+    /// # Arguments
+    /// * `biller_id` - 15-digit Biller ID
+    /// * `reference1` - `Ref1`
+    /// * `reference2` - `Ref2` (optional)
+    pub fn new(biller_id: &str, reference1: &str, reference2: Option<&str>) -> Self {
+        BillPaymentBarcode {
+            biller_id: biller_id.to_string(),
+            reference1: reference1.to_string(),
+            reference2: reference2.map(|r| r.to_string()),
+            amount: None,
+        }
+    }
+
+    /// Sets the amount to pay.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount in THB (e.g., `1500.25`)
+    ///
+    /// # Returns
+    /// `&mut self` for method chaining
+    pub fn set_amount(&mut self, amount: f64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Generates the barcode text (the data to encode in Code 128).
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Barcode text, e.g. `"|010753600031508\rCUST001\rINV2025001\r150000"`
+    /// * `Err(PromptPayError)` - If the Biller ID, a reference or the amount is invalid
+    pub fn create(&self) -> Result<String, PromptPayError> {
+        let biller_id = sanitize_target(&self.biller_id);
+        if !is_valid_biller_id(&biller_id) {
+            return Err(input_error(&format!(
+                "Biller ID must be 15 digits: {}",
+                self.biller_id
+            )));
+        }
+        if !is_valid_reference(&self.reference1) {
+            return Err(input_error(&format!(
+                "Ref1 must be 1-20 digits or uppercase letters: {}",
+                self.reference1
+            )));
+        }
+        if let Some(reference2) = &self.reference2
+            && !is_valid_reference(reference2)
+        {
+            return Err(input_error(&format!(
+                "Ref2 must be 1-20 digits or uppercase letters: {}",
+                reference2
+            )));
+        }
+
+        // จำนวนเงินเป็นหน่วยสตางค์ (ไม่มีจุดทศนิยม), 0 = ผู้จ่ายกรอกเอง
+        let satang = match self.amount {
+            Some(amount) if amount.is_finite() && amount >= 0.0 => (amount * 100.0).round() as u64,
+            Some(amount) => return Err(input_error(&format!("Invalid amount: {}", amount))),
+            None => 0,
+        };
+
+        Ok(format!(
+            "|{}\r{}\r{}\r{}",
+            biller_id,
+            self.reference1,
+            self.reference2.as_deref().unwrap_or(""),
+            satang
+        ))
+    }
+
+    /// Parses barcode text scanned from a printed bill.
+    ///
+    /// # Arguments
+    /// * `text` - Barcode text starting with `|`, fields separated by carriage returns
+    ///
+    /// # Returns
+    /// * `Ok(BillPaymentBarcode)` - Decoded bill
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidPayload`] if the text is not a bill payment barcode
+    pub fn parse(text: &str) -> Result<Self, PromptPayError> {
+        let body = text
+            .strip_prefix('|')
+            .ok_or_else(|| invalid("Bill payment barcode must start with '|'"))?;
+
+        let parts: Vec<&str> = body.split('\r').collect();
+        let [biller_id, reference1, reference2, amount] = parts.as_slice() else {
+            return Err(invalid(&format!(
+                "Bill payment barcode must have 4 fields, found {}",
+                parts.len()
+            )));
+        };
+
+        if !is_valid_biller_id(biller_id) {
+            return Err(invalid(&format!("Invalid Biller ID: {}", biller_id)));
+        }
+        if !is_valid_reference(reference1) {
+            return Err(invalid(&format!("Invalid Ref1: {}", reference1)));
+        }
+        if !reference2.is_empty() && !is_valid_reference(reference2) {
+            return Err(invalid(&format!("Invalid Ref2: {}", reference2)));
+        }
+        let satang: u64 = match amount.parse() {
+            Ok(satang) if amount.bytes().all(|b| b.is_ascii_digit()) => satang,
+            _ => return Err(invalid(&format!("Invalid amount: {}", amount))),
+        };
+
+        Ok(BillPaymentBarcode {
+            biller_id: biller_id.to_string(),
+            reference1: reference1.to_string(),
+            reference2: (!reference2.is_empty()).then(|| reference2.to_string()),
+            amount: (satang > 0).then(|| satang as f64 / 100.0),
+        })
+    }
+
+    /// Converts the bill into a bill payment `PromptPayQR` (tag `30`).
+    ///
+    /// A zero amount gives a QR without amount, like `None`.
+    pub fn to_qr(&self) -> PromptPayQR {
+        let mut qr = PromptPayQR::bill_payment(
+            &self.biller_id,
+            &self.reference1,
+            self.reference2.as_deref(),
+        );
+        if let Some(amount) = self.amount.filter(|&amount| amount != 0.0) {
+            qr.set_amount(amount);
+        }
+        qr
+    }
+
+    /// Converts a bill payment `PromptPayQR` (e.g. from [`PromptPayQR::parse`]) into a bill barcode.
+    ///
+    /// An amount of `0.00` in the QR becomes `None`, since the barcode cannot
+    /// tell it apart from "no amount".
+    ///
+    /// # Returns
+    /// * `Ok(BillPaymentBarcode)` - Bill with the same Biller ID, references and amount
+    /// * `Err(PromptPayError)` - If the QR is a credit transfer QR (tag `29`) rather than bill payment
+    pub fn from_qr(qr: &PromptPayQR) -> Result<Self, PromptPayError> {
+        if qr.merchant_type() != MerchantType::BillerId {
+            return Err(input_error("QR is not a bill payment QR"));
+        }
+        Ok(BillPaymentBarcode {
+            biller_id: sanitize_target(qr.merchant_id()),
+            reference1: qr.reference1().unwrap_or_default().to_string(),
+            reference2: qr.reference2().map(|r| r.to_string()),
+            // barcode ใช้ 0 แทน "ไม่ระบุจำนวนเงิน"
+            amount: qr.amount().filter(|&amount| amount != 0.0),
+        })
+    }
+}
+
+fn input_error(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidInput, msg)
+}
+
+fn invalid(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidPayload, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการสร้างและอ่านข้อความ barcode
+    #[test]
+    fn test_barcode_roundtrip() {
+        let mut bill = BillPaymentBarcode::new("010753600031508", "CUST001", Some("INV2025001"));
+        bill.set_amount(1234.56);
+        let text = bill.create().unwrap();
+        assert_eq!(text, "|010753600031508\rCUST001\rINV2025001\r123456");
+        assert_eq!(BillPaymentBarcode::parse(&text).unwrap(), bill);
+
+        let bill = BillPaymentBarcode::new("010753600031508", "CUST001", None);
+        let text = bill.create().unwrap();
+        assert_eq!(text, "|010753600031508\rCUST001\r\r0");
+        assert_eq!(BillPaymentBarcode::parse(&text).unwrap(), bill);
+    }
+
+    /// ทดสอบการแปลงระหว่าง barcode และ QR (tag 30) โดยไม่สูญเสียข้อมูล
+    #[test]
+    fn test_barcode_qr_conversion() {
+        let text = "|099400016550100\r0000123456\rP2025\r49950";
+        let bill = BillPaymentBarcode::parse(text).unwrap();
+        let payload = bill.to_qr().create().unwrap();
+
+        let qr = PromptPayQR::parse(&payload).unwrap();
+        let converted = BillPaymentBarcode::from_qr(&qr).unwrap();
+        assert_eq!(converted, bill);
+        assert_eq!(converted.create().unwrap(), text);
+
+        // จำนวนเงิน 0.00 ใน QR เท่ากับไม่ระบุจำนวนเงินใน barcode
+        let mut qr = PromptPayQR::bill_payment("010753600031508", "CUST001", None);
+        qr.set_amount(0.0);
+        let qr = PromptPayQR::parse(&qr.create().unwrap()).unwrap();
+        let converted = BillPaymentBarcode::from_qr(&qr).unwrap();
+        assert_eq!(converted.amount, None);
+        let text = converted.create().unwrap();
+        assert_eq!(text, "|010753600031508\rCUST001\r\r0");
+        assert_eq!(BillPaymentBarcode::parse(&text).unwrap(), converted);
+        let mut bill = converted;
+        bill.set_amount(0.0);
+        assert_eq!(bill.to_qr().amount(), None);
+
+        let err = BillPaymentBarcode::from_qr(&PromptPayQR::new("0812345678")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    /// ทดสอบข้อความ barcode ที่ไม่ถูกต้อง
+    #[test]
+    fn test_barcode_parse_invalid() {
+        for text in [
+            "010753600031508\rCUST001\r\r0",
+            "|010753600031508\rCUST001\r0",
+            "|0107536000315\rCUST001\r\r0",
+            "|010753600031508\rCUST001\r\r12.50",
+        ] {
+            let err = BillPaymentBarcode::parse(text).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidPayload, "{:?}", text);
+        }
+    }
+}
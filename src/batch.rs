@@ -0,0 +1,609 @@
+//! **Batch generation** of payloads and QR images from CSV (requires the `batch` feature).
+//!
+//! Each CSV row describes one QR (e.g. one branch sticker). Rows are validated
+//! and rendered in parallel; a row that fails is reported with its line number
+//! and the rest of the batch still completes. Every run writes `manifest.csv`
+//! to the output directory with the status, file name and SHA-256 hash of the
+//! payload of each row, so reprints can be checked against the previous run.
+//!
+//! Recognised columns (header row required, any order, case-insensitive):
+//! - `proxy` (required) — mobile number, tax ID, e-wallet ID, or Biller ID with `ref1`
+//! - `id` — file name stem, defaults to `row-<line>`
+//! - `amount`, `merchant_name`, `merchant_city`, `ref1`, `ref2`
+//! - tag `62` labels: `bill_number`, `mobile_number`, `store_label`, `loyalty_number`,
+//!   `reference_label`, `customer_label`, `terminal_label`, `purpose`
+//!
+//! ```toml
+//! [dependencies]
+//! promptpay-rs = { version = "0.6.0", features = ["batch"] }
+//! ```
+
+use crate::{
+    EcLevel, PromptPayError, PromptPayQR, additional_data::AdditionalData, error::ErrorKind,
+    qr::QrMatrix,
+};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashSet,
+    fmt, fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    thread,
+};
+
+/// File name of the manifest written by [`BatchGenerator::run`].
+pub const MANIFEST_FILE: &str = "manifest.csv";
+
+/// One valid CSV row, ready to generate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchRecord {
+    /// Line number in the CSV file (the header is line 1)
+    pub line: usize,
+    /// File name stem of the image
+    pub id: String,
+    /// QR definition built from the row
+    pub qr: PromptPayQR,
+}
+
+/// A row that could not be read or generated.
+#[derive(Debug)]
+pub struct RowError {
+    /// Line number in the CSV file (the header is line 1)
+    pub line: usize,
+    /// `id` of the row
+    pub id: String,
+    /// Cause of the failure
+    pub error: PromptPayError,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} ({}): {}", self.line, self.id, self.error)
+    }
+}
+
+/// Image written for each row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchFormat {
+    /// `<id>.png`
+    #[default]
+    Png,
+    /// `<id>.svg`
+    Svg,
+    /// Payloads and manifest only
+    None,
+}
+
+/// Result of a row that was generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchEntry {
+    /// Line number in the CSV file
+    pub line: usize,
+    /// File name stem of the image
+    pub id: String,
+    /// Generated payload
+    pub payload: String,
+    /// Lowercase hex SHA-256 of the payload
+    pub sha256: String,
+    /// Image file name inside the output directory, if one was written
+    pub file: Option<String>,
+}
+
+/// Outcome of [`BatchGenerator::run`], in CSV order.
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    /// Rows that were generated
+    pub entries: Vec<BatchEntry>,
+    /// Rows that failed, including those rejected by [`read_csv`]
+    pub errors: Vec<RowError>,
+}
+
+impl BatchReport {
+    /// Returns the manifest as CSV: `line,id,status,file,sha256,payload,error`.
+    pub fn manifest_csv(&self) -> String {
+        let mut rows: Vec<(usize, [String; 7])> = self
+            .entries
+            .iter()
+            .map(|e| {
+                let file = e.file.clone().unwrap_or_default();
+                let row = [
+                    &e.line.to_string(),
+                    &e.id,
+                    "ok",
+                    &file,
+                    &e.sha256,
+                    &e.payload,
+                    "",
+                ];
+                (e.line, row.map(csv_field))
+            })
+            .chain(self.errors.iter().map(|e| {
+                let error = e.error.to_string();
+                let row = [&e.line.to_string(), &e.id, "error", "", "", "", &error];
+                (e.line, row.map(csv_field))
+            }))
+            .collect();
+        rows.sort_by_key(|(line, _)| *line);
+
+        let mut csv = String::from("line,id,status,file,sha256,payload,error\n");
+        for (_, row) in rows {
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Reads batch rows from CSV text.
+///
+/// # Returns
+/// * `Ok((records, errors))` - Valid rows, and rows with a bad amount, missing
+///   proxy, duplicate or unsafe `id`, or wrong number of columns
+/// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if the header is missing,
+///   has no `proxy` column or an unknown column, or a quoted field is not closed
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::batch::read_csv;
+///
+/// let (records, errors) = read_csv("id,proxy,amount\nbkk-01,0812345678,50\nbkk-02,,x\n").unwrap();
+/// assert_eq!(records[0].qr.amount(), Some(50.0));
+/// assert_eq!(errors[0].line, 3);
+/// ```
+pub fn read_csv(text: &str) -> Result<(Vec<BatchRecord>, Vec<RowError>), PromptPayError> {
+    let mut rows = parse_csv(text)?.into_iter();
+    let (_, header) = rows
+        .next()
+        .ok_or_else(|| invalid_input("CSV has no header row"))?;
+    let columns: Vec<String> = header
+        .iter()
+        .map(|h| h.trim().to_ascii_lowercase())
+        .collect();
+    if let Some(unknown) = columns.iter().find(|c| !COLUMNS.contains(&c.as_str())) {
+        return Err(invalid_input(&format!("Unknown CSV column: {}", unknown)));
+    }
+    if !columns.iter().any(|c| c == "proxy") {
+        return Err(invalid_input("CSV must have a \"proxy\" column"));
+    }
+
+    let mut records = Vec::new();
+    let mut errors = Vec::new();
+    let mut ids = HashSet::new();
+    for (line, fields) in rows {
+        // ข้ามบรรทัดว่าง
+        if fields.iter().all(|f| f.trim().is_empty()) {
+            continue;
+        }
+        let get = |name: &str| {
+            columns
+                .iter()
+                .position(|c| c == name)
+                .and_then(|i| fields.get(i))
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+        };
+        let id = get("id").map_or_else(|| format!("row-{}", line), |id| id.to_string());
+        let result = if fields.len() != columns.len() {
+            Err(invalid_input(&format!(
+                "Expected {} columns, found {}",
+                columns.len(),
+                fields.len()
+            )))
+        } else if !is_safe_id(&id) {
+            Err(invalid_input(&format!(
+                "id must be letters, digits, '.', '_' or '-': {}",
+                id
+            )))
+        } else if !ids.insert(id.clone()) {
+            Err(invalid_input(&format!("Duplicate id: {}", id)))
+        } else {
+            record_qr(&get)
+        };
+        match result {
+            Ok(qr) => records.push(BatchRecord { line, id, qr }),
+            Err(error) => errors.push(RowError { line, id, error }),
+        }
+    }
+    Ok((records, errors))
+}
+
+const COLUMNS: [&str; 15] = [
+    "id",
+    "proxy",
+    "amount",
+    "merchant_name",
+    "merchant_city",
+    "ref1",
+    "ref2",
+    "bill_number",
+    "mobile_number",
+    "store_label",
+    "loyalty_number",
+    "reference_label",
+    "customer_label",
+    "terminal_label",
+    "purpose",
+];
+
+// สร้าง PromptPayQR จากค่าในแถว (ยังไม่ตรวจ payload ซึ่งทำตอน create)
+fn record_qr<'a>(get: &impl Fn(&str) -> Option<&'a str>) -> Result<PromptPayQR, PromptPayError> {
+    let proxy = get("proxy").ok_or_else(|| invalid_input("proxy is required"))?;
+    let mut qr = match get("ref1") {
+        Some(ref1) => PromptPayQR::bill_payment(proxy, ref1, get("ref2")),
+        None if get("ref2").is_some() => return Err(invalid_input("ref2 requires ref1")),
+        None => PromptPayQR::new(proxy),
+    };
+    if let Some(amount) = get("amount") {
+        match amount.replace(',', "").parse::<f64>() {
+            Ok(value) if value.is_finite() && value >= 0.0 => qr.set_amount(value),
+            _ => return Err(invalid_input(&format!("Invalid amount: {}", amount))),
+        };
+    }
+    if let Some(name) = get("merchant_name") {
+        qr.set_merchant_name(name);
+    }
+    if let Some(city) = get("merchant_city") {
+        qr.set_merchant_city(city);
+    }
+    let label = |name: &str| get(name).map(|v| v.to_string());
+    qr.set_additional_data(AdditionalData {
+        bill_number: label("bill_number"),
+        mobile_number: label("mobile_number"),
+        store_label: label("store_label"),
+        loyalty_number: label("loyalty_number"),
+        reference_label: label("reference_label"),
+        customer_label: label("customer_label"),
+        terminal_label: label("terminal_label"),
+        purpose: label("purpose"),
+    });
+    Ok(qr)
+}
+
+/// Generates payloads and images for [`BatchRecord`]s in parallel.
+///
+/// # Example
+/// ```rust,no_run
+/// use promptpay_rs::batch::{BatchFormat, BatchGenerator, read_csv};
+///
+/// let (records, errors) = read_csv(&std::fs::read_to_string("branches.csv").unwrap()).unwrap();
+/// let mut generator = BatchGenerator::new("stickers");
+/// generator.set_format(BatchFormat::Png).set_module_size(10);
+/// let report = generator.run(&records, errors).unwrap();
+/// for error in &report.errors {
+///     eprintln!("{}", error);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchGenerator {
+    output_dir: PathBuf,
+    format: BatchFormat,
+    ec_level: EcLevel,
+    module_size: u32,
+    threads: Option<NonZeroUsize>, // None = ตามจำนวน CPU
+}
+
+impl BatchGenerator {
+    /// Creates a generator writing PNG files with 8 px modules at level `M`.
+    pub fn new(output_dir: impl AsRef<Path>) -> Self {
+        BatchGenerator {
+            output_dir: output_dir.as_ref().to_path_buf(),
+            format: BatchFormat::default(),
+            ec_level: EcLevel::M,
+            module_size: 8,
+            threads: None,
+        }
+    }
+
+    /// Sets the image format.
+    pub fn set_format(&mut self, format: BatchFormat) -> &mut Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the error correction level of the images.
+    pub fn set_ec_level(&mut self, ec_level: EcLevel) -> &mut Self {
+        self.ec_level = ec_level;
+        self
+    }
+
+    /// Sets the size of one module in pixels (PNG) or user units (SVG), at least 1.
+    pub fn set_module_size(&mut self, module_size: u32) -> &mut Self {
+        self.module_size = module_size.max(1);
+        self
+    }
+
+    /// Sets the number of worker threads (defaults to the number of CPUs).
+    pub fn set_threads(&mut self, threads: NonZeroUsize) -> &mut Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Generates every record, writes the images and `manifest.csv`.
+    ///
+    /// # Arguments
+    /// * `records` - Rows from [`read_csv`]
+    /// * `errors` - Rows already rejected by [`read_csv`], reported in the manifest
+    ///
+    /// # Returns
+    /// * `Ok(BatchReport)` - Per-row results; failed rows do not stop the batch
+    /// * `Err(PromptPayError)` - [`ErrorKind::Other`] if the output directory or
+    ///   manifest cannot be written
+    pub fn run(
+        &self,
+        records: &[BatchRecord],
+        errors: Vec<RowError>,
+    ) -> Result<BatchReport, PromptPayError> {
+        fs::create_dir_all(&self.output_dir).map_err(|e| io_error(&self.output_dir, e))?;
+
+        let threads = self
+            .threads
+            .or_else(|| thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get);
+        let chunk_size = records.len().div_ceil(threads).max(1);
+        // แบ่งแถวเป็นช่วงต่อเนื่องให้แต่ละ thread แล้วต่อผลกลับตามลำดับเดิม
+        let results: Vec<Result<BatchEntry, RowError>> = thread::scope(|scope| {
+            let workers: Vec<_> = records
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || chunk.iter().map(|r| self.generate(r)).collect::<Vec<_>>())
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("batch worker panicked"))
+                .collect()
+        });
+
+        let mut report = BatchReport {
+            entries: Vec::new(),
+            errors,
+        };
+        for result in results {
+            match result {
+                Ok(entry) => report.entries.push(entry),
+                Err(error) => report.errors.push(error),
+            }
+        }
+        report.errors.sort_by_key(|e| e.line);
+
+        let manifest = self.output_dir.join(MANIFEST_FILE);
+        fs::write(&manifest, report.manifest_csv()).map_err(|e| io_error(&manifest, e))?;
+        Ok(report)
+    }
+
+    fn generate(&self, record: &BatchRecord) -> Result<BatchEntry, RowError> {
+        let row_error = |error| RowError {
+            line: record.line,
+            id: record.id.clone(),
+            error,
+        };
+        let payload = record.qr.create().map_err(row_error)?;
+        let matrix = || QrMatrix::encode(&payload, self.ec_level).map_err(row_error);
+        let image = match self.format {
+            BatchFormat::Png => Some((
+                "png",
+                matrix()?.to_png(self.module_size).map_err(row_error)?,
+            )),
+            BatchFormat::Svg => {
+                let svg = format!("{}\n", matrix()?.to_svg(Some(self.module_size)));
+                Some(("svg", svg.into_bytes()))
+            }
+            BatchFormat::None => None,
+        };
+        let file = match image {
+            Some((extension, bytes)) => {
+                let name = format!("{}.{}", record.id, extension);
+                let path = self.output_dir.join(&name);
+                fs::write(&path, bytes).map_err(|e| row_error(io_error(&path, e)))?;
+                Some(name)
+            }
+            None => None,
+        };
+        Ok(BatchEntry {
+            line: record.line,
+            id: record.id.clone(),
+            sha256: Sha256::digest(payload.as_bytes())
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect(),
+            payload,
+            file,
+        })
+    }
+}
+
+// แยก CSV ตาม RFC 4180 (รองรับ "..." ที่มี , "" และขึ้นบรรทัดใหม่) คืน (เลขบรรทัด, ฟิลด์)
+fn parse_csv(text: &str) -> Result<Vec<(usize, Vec<String>)>, PromptPayError> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text); // BOM จาก Excel
+    let mut rows = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let (mut line, mut row_line) = (1, 1);
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                fields.push(std::mem::take(&mut field));
+                rows.push((row_line, std::mem::take(&mut fields)));
+                line += 1;
+                row_line = line;
+            }
+            ('\n', true) => {
+                field.push(c);
+                line += 1;
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(invalid_input(&format!(
+            "Unterminated quoted field starting on line {}",
+            row_line
+        )));
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        rows.push((row_line, fields));
+    }
+    Ok(rows)
+}
+
+// ใส่ quote ให้ฟิลด์ที่มี , " หรือขึ้นบรรทัดใหม่
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// id ใช้เป็นชื่อไฟล์ จึงห้ามมี path separator หรืออักขระพิเศษ
+fn is_safe_id(id: &str) -> bool {
+    !id.starts_with('.')
+        && id.len() <= 100
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-'))
+}
+
+fn invalid_input(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidInput, msg)
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> PromptPayError {
+    PromptPayError::new(&format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // โฟลเดอร์ชั่วคราวที่ไม่ซ้ำกันระหว่าง test
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("promptpay-batch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// ทดสอบการอ่าน CSV ที่มี quote, BOM, บรรทัดว่าง และแถวที่ผิดพลาด
+    #[test]
+    fn test_read_csv() {
+        let csv = "\u{feff}ID,Proxy,Amount,Merchant_Name,Terminal_Label\r\n\
+                   bkk-01,0812345678,\"1,250.50\",\"COFFEE \"\"HOUSE\"\"\",TABLE-07\r\n\
+                   \r\n\
+                   bkk-02,0812345678,abc,,\n\
+                   bkk-01,0812345678,,,\n\
+                   ../x,0812345678,,,\n\
+                   ,1234567890123,,\"SHOP\nTWO\"\n";
+        let (records, errors) = read_csv(csv).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].line, 2);
+        assert_eq!(records[0].qr.amount(), Some(1250.5));
+        assert_eq!(records[0].qr.merchant_name(), Some("COFFEE \"HOUSE\""));
+        let label = records[0]
+            .qr
+            .additional_data()
+            .unwrap()
+            .terminal_label
+            .as_deref();
+        assert_eq!(label, Some("TABLE-07"));
+
+        let lines: Vec<_> = errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, [4, 5, 6, 7]);
+        assert_eq!(
+            errors[0].to_string(),
+            "line 4 (bkk-02): Invalid amount: abc"
+        );
+        assert!(errors[1].error.to_string().starts_with("Duplicate id"));
+        assert!(
+            errors[3]
+                .error
+                .to_string()
+                .starts_with("Expected 5 columns")
+        );
+
+        assert!(read_csv("id,amount\n1,2\n").is_err());
+        assert!(read_csv("proxy,colour\n").is_err());
+        assert!(read_csv("proxy\n\"0812345678\n").is_err());
+    }
+
+    /// ทดสอบการสร้างแบบขนาน: ลำดับผลลัพธ์, ไฟล์ภาพ, hash และ manifest
+    #[test]
+    fn test_run_parallel() {
+        let mut csv = String::from("id,proxy,amount,merchant_name\n");
+        for i in 0..20 {
+            csv.push_str(&format!("b{:02},08123456{:02},{},\n", i, i, i + 1));
+        }
+        csv.push_str("bad,0812345678,,,\n"); // คอลัมน์เกิน
+        csv.push_str("thai,0812345678,,ร้านกาแฟ\n"); // ไม่ผ่าน create (tag 59 ต้องเป็นภาษาอังกฤษ)
+
+        let (records, errors) = read_csv(&csv).unwrap();
+        let dir = temp_dir("parallel");
+        let mut generator = BatchGenerator::new(&dir);
+        generator
+            .set_format(BatchFormat::Svg)
+            .set_threads(NonZeroUsize::new(3).unwrap());
+        let report = generator.run(&records, errors).unwrap();
+
+        assert_eq!(report.entries.len(), 20);
+        let ids: Vec<_> = report
+            .entries
+            .iter()
+            .map(|e| e.id.as_str())
+            .take(3)
+            .collect();
+        assert_eq!(ids, ["b00", "b01", "b02"]);
+        let first = &report.entries[0];
+        let mut qr = PromptPayQR::new("0812345600");
+        qr.set_amount(1.0);
+        assert_eq!(first.payload, qr.create().unwrap());
+        let hash: String = Sha256::digest(first.payload.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        assert_eq!(first.sha256, hash);
+        let svg = fs::read_to_string(dir.join("b00.svg")).unwrap();
+        assert!(svg.starts_with("<svg"));
+
+        let lines: Vec<_> = report.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, [22, 23]);
+        let manifest = fs::read_to_string(dir.join(MANIFEST_FILE)).unwrap();
+        let lines: Vec<_> = manifest.lines().collect();
+        assert_eq!(lines.len(), 23);
+        assert!(lines[1].starts_with(&format!("2,b00,ok,b00.svg,{},", hash)));
+        assert!(lines[21].starts_with("22,bad,error,,,,\"Expected 4 columns, found 5\""));
+        assert!(lines[22].starts_with("23,thai,error,,,,\"Merchant name must be Latin text"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// ทดสอบการสร้างภาพ PNG และโหมดไม่สร้างภาพ
+    #[test]
+    fn test_run_png_and_none() {
+        let (records, _) = read_csv("proxy,amount\n0812345678,10\n").unwrap();
+        let dir = temp_dir("png");
+        let report = BatchGenerator::new(&dir).run(&records, Vec::new()).unwrap();
+        assert_eq!(report.entries[0].file.as_deref(), Some("row-2.png"));
+        let png = image::open(dir.join("row-2.png")).unwrap().to_luma8();
+        let matrix = QrMatrix::encode(&report.entries[0].payload, EcLevel::M).unwrap();
+        assert_eq!(png.width() as usize, (matrix.size() + 8) * 8);
+
+        fs::remove_dir_all(&dir).unwrap();
+        let mut generator = BatchGenerator::new(&dir);
+        generator.set_format(BatchFormat::None);
+        let report = generator.run(&records, Vec::new()).unwrap();
+        assert!(report.entries[0].file.is_none());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1); // manifest เท่านั้น
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
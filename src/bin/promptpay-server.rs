@@ -0,0 +1,51 @@
+//! `promptpay-server` HTTP service (requires the `server` feature).
+//!
+//! Configured with environment variables:
+//! - `PROMPTPAY_ADDR` — listen address, default `127.0.0.1:8080`
+//! - `PROMPTPAY_CACHE_SIZE` — number of rendered images kept in memory, default 1024
+//!
+//! See [`promptpay_rs::server`] for the endpoints; the OpenAPI description is
+//! served at `/openapi.json`.
+
+use promptpay_rs::server::{DEFAULT_CACHE_CAPACITY, router};
+use std::{env, process::ExitCode};
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let addr = env::var("PROMPTPAY_ADDR").unwrap_or_else(|_| "127.0.0.1:8080".to_string());
+    let cache_size = match env::var("PROMPTPAY_CACHE_SIZE") {
+        Ok(value) => match value.parse() {
+            Ok(size) => size,
+            Err(_) => {
+                eprintln!("error: PROMPTPAY_CACHE_SIZE must be a number: {}", value);
+                return ExitCode::FAILURE;
+            }
+        },
+        Err(_) => DEFAULT_CACHE_CAPACITY,
+    };
+
+    let listener = match TcpListener::bind(&addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("error: cannot listen on {}: {}", addr, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    eprintln!("promptpay-server listening on http://{}", addr);
+
+    // หยุดรับ request ใหม่เมื่อกด Ctrl+C แล้วรอ request ที่ค้างอยู่ให้เสร็จ
+    let shutdown = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+    match axum::serve(listener, router(cache_size))
+        .with_graceful_shutdown(shutdown)
+        .await
+    {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,772 @@
+//! `promptpay` command-line tool (requires the `cli` feature).
+//!
+//! ```text
+//! promptpay generate 0812345678 --amount 150 --format png --output qr.png
+//! promptpay decode "$PAYLOAD"
+//! promptpay decode --image screenshot.png --json
+//! promptpay verify "$PAYLOAD" --strict
+//! promptpay render "$PAYLOAD" --format svg --output qr.svg
+//! promptpay batch branches.csv --output-dir stickers --threads 8
+//! ```
+//!
+//! Payload arguments may be `-` or omitted to read from stdin.
+//!
+//! Exit codes: `0` success, `1` invalid payload or failed command, `2` usage
+//! error, `3` payload with warnings only (`verify --strict`). `batch` exits with
+//! `1` if any row failed, after generating all the others.
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use promptpay_rs::{
+    EcLevel, PromptPayQR,
+    additional_data::AdditionalData,
+    batch::{BatchFormat, BatchGenerator, MANIFEST_FILE, read_csv},
+    crc::verify_crc,
+    language::MerchantLanguage,
+    lint::{LintIssue, lint},
+    qr::{QUIET_ZONE, QrMatrix},
+    scan::scan_image,
+    tlv,
+};
+use serde_json::{Value, json};
+use std::{
+    error::Error,
+    fmt::Write as _,
+    fs,
+    io::{self, IsTerminal, Read, Write},
+    num::NonZeroUsize,
+    path::PathBuf,
+    process::ExitCode,
+};
+
+const EXIT_INVALID: u8 = 1;
+const EXIT_WARNINGS: u8 = 3;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "promptpay",
+    version,
+    about = "Generate, decode, verify and render PromptPay QR codes",
+    after_help = "Exit codes: 0 success, 1 invalid payload or failed command, 2 usage error, \
+                  3 warnings only (verify --strict)"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Build a payload from a PromptPay ID and merchant fields
+    Generate(Box<GenerateArgs>),
+    /// Explain a payload, or the QR codes found in an image
+    Decode(DecodeArgs),
+    /// Check the CRC and lint a payload
+    Verify(VerifyArgs),
+    /// Draw an existing payload as a QR code
+    Render(RenderArgs),
+    /// Generate payloads and images for every row of a CSV file
+    Batch(BatchArgs),
+}
+
+#[derive(Debug, Args)]
+struct GenerateArgs {
+    /// Mobile number, national / tax ID or e-wallet ID (Biller ID with --ref1)
+    proxy: String,
+    /// Amount in THB (makes a dynamic QR)
+    #[arg(short, long)]
+    amount: Option<f64>,
+    /// Ref1 of a bill payment QR; the proxy is then a 15-digit Biller ID
+    #[arg(long)]
+    ref1: Option<String>,
+    /// Ref2 of a bill payment QR
+    #[arg(long, requires = "ref1")]
+    ref2: Option<String>,
+    /// Merchant name in Latin script (tag 59)
+    #[arg(long)]
+    merchant_name: Option<String>,
+    /// Merchant city in Latin script (tag 60)
+    #[arg(long)]
+    merchant_city: Option<String>,
+    /// Merchant name in Thai (tag 64)
+    #[arg(long)]
+    thai_name: Option<String>,
+    #[command(flatten)]
+    labels: LabelArgs,
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+/// Additional data labels (tag 62)
+#[derive(Debug, Args)]
+struct LabelArgs {
+    /// Bill or invoice number (tag 62-01)
+    #[arg(long)]
+    bill_number: Option<String>,
+    /// Store label (tag 62-03)
+    #[arg(long)]
+    store_label: Option<String>,
+    /// Reference label (tag 62-05)
+    #[arg(long)]
+    reference_label: Option<String>,
+    /// Terminal label, e.g. table number (tag 62-07)
+    #[arg(long)]
+    terminal_label: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct DecodeArgs {
+    /// Payload text; read from stdin when omitted or `-`
+    #[arg(conflicts_with = "image")]
+    payload: Option<String>,
+    /// PNG or JPEG image to scan instead of a payload
+    #[arg(short, long)]
+    image: Option<PathBuf>,
+    /// Print JSON instead of a field-by-field explanation
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Payload text; read from stdin when omitted or `-`
+    payload: Option<String>,
+    /// Exit with code 3 when the payload has warnings
+    #[arg(long)]
+    strict: bool,
+    /// Print the issues as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Debug, Args)]
+struct RenderArgs {
+    /// Payload text; read from stdin when omitted or `-`
+    payload: Option<String>,
+    /// Render even if the payload does not verify
+    #[arg(long)]
+    force: bool,
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+#[derive(Debug, Args)]
+struct BatchArgs {
+    /// CSV file with a header row (proxy, amount, merchant_name, tag 62 labels, ...);
+    /// stdin when `-`
+    input: PathBuf,
+    /// Directory for the images and manifest.csv
+    #[arg(short = 'd', long)]
+    output_dir: PathBuf,
+    /// Image written for each row
+    #[arg(short, long, value_enum, default_value_t = ImageFormat::Png)]
+    format: ImageFormat,
+    /// Error correction level
+    #[arg(long, value_enum, ignore_case = true, default_value_t = Level::M)]
+    ec_level: Level,
+    /// Pixels (PNG) or user units (SVG) per module
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..=64))]
+    module_size: u32,
+    /// Worker threads [default: number of CPUs]
+    #[arg(short = 'j', long)]
+    threads: Option<NonZeroUsize>,
+}
+
+#[derive(Debug, Args)]
+struct OutputArgs {
+    /// Output format [default: payload for generate, terminal for render]
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+    /// File to write; stdout when omitted
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Error correction level
+    #[arg(long, value_enum, ignore_case = true, default_value_t = Level::M)]
+    ec_level: Level,
+    /// Pixels (PNG) or user units (SVG) per module
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..=64))]
+    module_size: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Payload,
+    Png,
+    Svg,
+    Terminal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ImageFormat {
+    Png,
+    Svg,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Level {
+    L,
+    M,
+    Q,
+    H,
+}
+
+impl From<Level> for EcLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::L => EcLevel::L,
+            Level::M => EcLevel::M,
+            Level::Q => EcLevel::Q,
+            Level::H => EcLevel::H,
+        }
+    }
+}
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Generate(args) => generate(&args),
+        Command::Decode(args) => decode(&args),
+        Command::Verify(args) => verify(&args),
+        Command::Render(args) => render(&args),
+        Command::Batch(args) => batch(&args),
+    };
+    match result {
+        Ok(code) => ExitCode::from(code),
+        // ผู้ใช้ปิด pipe ก่อนอ่านจบ (เช่น `| head`) ไม่ถือเป็นข้อผิดพลาด
+        Err(e)
+            if e.downcast_ref::<io::Error>()
+                .is_some_and(|e| e.kind() == io::ErrorKind::BrokenPipe) =>
+        {
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::from(EXIT_INVALID)
+        }
+    }
+}
+
+fn generate(args: &GenerateArgs) -> CliResult<u8> {
+    let payload = build_qr(args)?.create()?;
+    write_output(&payload, &args.output, Format::Payload)?;
+    Ok(0)
+}
+
+fn decode(args: &DecodeArgs) -> CliResult<u8> {
+    // (payload, ตำแหน่งในภาพ) ของทุก code ที่ต้องอธิบาย
+    let codes: Vec<(String, Option<Value>)> = match &args.image {
+        Some(path) => scan_image(&fs::read(path)?)?
+            .into_iter()
+            .map(|code| {
+                let b = code.bounds;
+                let bounds = json!({ "x": b.x, "y": b.y, "width": b.width, "height": b.height });
+                (code.text, Some(bounds))
+            })
+            .collect(),
+        None => vec![(read_payload(args.payload.as_deref())?, None)],
+    };
+    if codes.is_empty() {
+        return Err("No QR code found in image".into());
+    }
+
+    let mut stdout = io::stdout().lock();
+    let valid = codes
+        .iter()
+        .all(|(text, _)| PromptPayQR::parse(text).is_ok());
+    if args.json {
+        let mut entries: Vec<Value> = codes
+            .iter()
+            .map(|(text, bounds)| {
+                let mut entry = payload_json(text);
+                if let Some(bounds) = bounds {
+                    entry["bounds"] = bounds.clone();
+                }
+                entry
+            })
+            .collect();
+        // payload เดียวแสดงเป็น object, จากภาพแสดงเป็น array เสมอ
+        let value = match args.image {
+            Some(_) => Value::Array(entries),
+            None => entries.remove(0),
+        };
+        writeln!(stdout, "{}", serde_json::to_string_pretty(&value)?)?;
+    } else {
+        for (i, (text, bounds)) in codes.iter().enumerate() {
+            if let Some(b) = bounds {
+                if i > 0 {
+                    writeln!(stdout)?;
+                }
+                writeln!(
+                    stdout,
+                    "QR code {} at x={} y={} ({}x{})",
+                    i + 1,
+                    b["x"],
+                    b["y"],
+                    b["width"],
+                    b["height"]
+                )?;
+            }
+            write!(stdout, "{}", explain(text))?;
+        }
+    }
+    Ok(if valid { 0 } else { EXIT_INVALID })
+}
+
+fn verify(args: &VerifyArgs) -> CliResult<u8> {
+    let payload = read_payload(args.payload.as_deref())?;
+    let issues = lint(&payload);
+    let mut stdout = io::stdout().lock();
+    if args.json {
+        let value = json!({
+            "valid": !issues.iter().any(LintIssue::is_error),
+            "issues": issues.iter().map(issue_json).collect::<Vec<_>>(),
+        });
+        writeln!(stdout, "{}", serde_json::to_string_pretty(&value)?)?;
+    } else if issues.is_empty() {
+        writeln!(stdout, "OK")?;
+    } else {
+        for issue in &issues {
+            writeln!(stdout, "{}", issue)?;
+        }
+    }
+    Ok(verify_exit_code(&issues, args.strict))
+}
+
+fn render(args: &RenderArgs) -> CliResult<u8> {
+    let payload = read_payload(args.payload.as_deref())?;
+    if !args.force
+        && let Some(issue) = lint(&payload).into_iter().find(LintIssue::is_error)
+    {
+        return Err(format!("{} (use --force to render anyway)", issue.message).into());
+    }
+    write_output(&payload, &args.output, Format::Terminal)?;
+    Ok(0)
+}
+
+fn batch(args: &BatchArgs) -> CliResult<u8> {
+    let text = if args.input.as_os_str() == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        fs::read_to_string(&args.input)?
+    };
+    let (records, errors) = read_csv(&text)?;
+
+    let mut generator = BatchGenerator::new(&args.output_dir);
+    generator
+        .set_format(match args.format {
+            ImageFormat::Png => BatchFormat::Png,
+            ImageFormat::Svg => BatchFormat::Svg,
+            ImageFormat::None => BatchFormat::None,
+        })
+        .set_ec_level(args.ec_level.into())
+        .set_module_size(args.module_size);
+    if let Some(threads) = args.threads {
+        generator.set_threads(threads);
+    }
+    let report = generator.run(&records, errors)?;
+
+    for error in &report.errors {
+        eprintln!("error: {}", error);
+    }
+    let total = report.entries.len() + report.errors.len();
+    println!(
+        "Generated {} of {} rows, see {}",
+        report.entries.len(),
+        total,
+        args.output_dir.join(MANIFEST_FILE).display()
+    );
+    Ok(if report.errors.is_empty() {
+        0
+    } else {
+        EXIT_INVALID
+    })
+}
+
+fn build_qr(args: &GenerateArgs) -> CliResult<PromptPayQR> {
+    let mut qr = match &args.ref1 {
+        Some(ref1) => PromptPayQR::bill_payment(&args.proxy, ref1, args.ref2.as_deref()),
+        None => PromptPayQR::new(&args.proxy),
+    };
+    if let Some(amount) = args.amount {
+        qr.set_amount(amount);
+    }
+    if let Some(name) = &args.merchant_name {
+        qr.set_merchant_name(name);
+    }
+    if let Some(city) = &args.merchant_city {
+        qr.set_merchant_city(city);
+    }
+    if let Some(name) = &args.thai_name {
+        qr.set_merchant_language(MerchantLanguage::thai(name));
+    }
+    let labels = &args.labels;
+    qr.set_additional_data(AdditionalData {
+        bill_number: labels.bill_number.clone(),
+        store_label: labels.store_label.clone(),
+        reference_label: labels.reference_label.clone(),
+        terminal_label: labels.terminal_label.clone(),
+        ..AdditionalData::default()
+    });
+    Ok(qr)
+}
+
+// ออกรหัส 0 = ผ่าน, 1 = มี error, 3 = มีแต่ warning (เฉพาะ --strict)
+fn verify_exit_code(issues: &[LintIssue], strict: bool) -> u8 {
+    if issues.iter().any(LintIssue::is_error) {
+        EXIT_INVALID
+    } else if strict && !issues.is_empty() {
+        EXIT_WARNINGS
+    } else {
+        0
+    }
+}
+
+// อ่าน payload จาก argument หรือ stdin ("-" หรือไม่ระบุ)
+fn read_payload(arg: Option<&str>) -> CliResult<String> {
+    let payload = match arg {
+        Some(text) if text != "-" => text.to_string(),
+        _ => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            text
+        }
+    };
+    let payload = payload.trim();
+    if payload.is_empty() {
+        return Err("Payload is empty".into());
+    }
+    Ok(payload.to_string())
+}
+
+fn write_output(payload: &str, args: &OutputArgs, default: Format) -> CliResult<()> {
+    let format = args.format.unwrap_or(default);
+    let bytes = match format {
+        Format::Payload => format!("{}\n", payload).into_bytes(),
+        Format::Png => to_png(payload, args)?,
+        Format::Svg => to_svg(payload, args)?.into_bytes(),
+        Format::Terminal => to_terminal(payload, args)?.into_bytes(),
+    };
+    match &args.output {
+        Some(path) => fs::write(path, bytes)?,
+        None if format == Format::Png && io::stdout().is_terminal() => {
+            return Err("Refusing to write PNG to a terminal, use --output".into());
+        }
+        None => io::stdout().write_all(&bytes)?,
+    }
+    Ok(())
+}
+
+fn to_png(payload: &str, args: &OutputArgs) -> CliResult<Vec<u8>> {
+    let matrix = QrMatrix::encode(payload, args.ec_level.into())?;
+    Ok(matrix.to_png(args.module_size)?)
+}
+
+fn to_svg(payload: &str, args: &OutputArgs) -> CliResult<String> {
+    let matrix = QrMatrix::encode(payload, args.ec_level.into())?;
+    Ok(format!("{}\n", matrix.to_svg(Some(args.module_size))))
+}
+
+// ตัวอักษรครึ่งบล็อก 1 ตัว = 2 module (บน/ล่าง), ดำบนพื้นขาวเสมอไม่ขึ้นกับธีมของ terminal
+fn to_terminal(payload: &str, args: &OutputArgs) -> CliResult<String> {
+    let matrix = QrMatrix::encode(payload, args.ec_level.into())?;
+    let size = matrix.size() + QUIET_ZONE * 2;
+    let dark = |x: usize, y: usize| {
+        x >= QUIET_ZONE && y >= QUIET_ZONE && matrix.is_dark(x - QUIET_ZONE, y - QUIET_ZONE)
+    };
+    let mut out = String::new();
+    for y in (0..size).step_by(2) {
+        out.push_str("\x1b[30;47m");
+        for x in 0..size {
+            out.push(match (dark(x, y), dark(x, y + 1)) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => ' ',
+            });
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    Ok(out)
+}
+
+// อธิบาย payload ทีละฟิลด์ ตามด้วยผลการตรวจสอบ
+fn explain(payload: &str) -> String {
+    let mut out = String::new();
+    match tlv::parse(payload) {
+        Ok(fields) => explain_fields(&mut out, &fields, None, verify_crc(payload, "63")),
+        Err(e) => writeln!(out, "Malformed payload: {}", e).unwrap(),
+    }
+    match PromptPayQR::parse(payload) {
+        Ok(qr) => writeln!(out, "=> {}", summary(&qr)).unwrap(),
+        Err(e) => writeln!(out, "=> invalid: {}", e).unwrap(),
+    }
+    out
+}
+
+fn explain_fields(
+    out: &mut String,
+    fields: &[tlv::Field<'_>],
+    parent: Option<&str>,
+    crc_valid: bool,
+) {
+    for field in fields {
+        let indent = if parent.is_some() { "   " } else { "" };
+        let name = tag_name(parent, field.tag);
+        let nested = is_template(parent, field.tag)
+            .then(|| tlv::parse(field.value).ok())
+            .flatten();
+        match nested {
+            Some(sub_fields) => {
+                writeln!(out, "{}{} {}", indent, field.tag, name).unwrap();
+                explain_fields(out, &sub_fields, Some(field.tag), crc_valid);
+            }
+            None => {
+                let width = 32usize.saturating_sub(indent.len());
+                let note = match (parent, field.tag, field.value) {
+                    (None, "01", "11") => " (static)",
+                    (None, "01", "12") => " (dynamic)",
+                    (None, "53", "764") => " (THB)",
+                    (None, "63", _) if crc_valid => " (valid)",
+                    (None, "63", _) => " (mismatch)",
+                    _ => "",
+                };
+                writeln!(
+                    out,
+                    "{}{} {:<width$} {}{}",
+                    indent, field.tag, name, field.value, note
+                )
+                .unwrap();
+            }
+        }
+    }
+}
+
+fn is_template(parent: Option<&str>, tag: &str) -> bool {
+    // EMVCo: 26-51 merchant account, 62 additional data, 64 language, 80-99 unreserved
+    parent.is_none() && matches!(tag.parse::<u8>(), Ok(26..=51 | 62 | 64 | 80..=99))
+}
+
+fn tag_name(parent: Option<&str>, tag: &str) -> &'static str {
+    match (parent, tag) {
+        (None, "00") => "Payload format indicator",
+        (None, "01") => "Point of initiation method",
+        (None, "29") => "Merchant account (PromptPay)",
+        (None, "30") => "Merchant account (bill payment)",
+        (None, "53") => "Transaction currency",
+        (None, "54") => "Transaction amount",
+        (None, "58") => "Country code",
+        (None, "59") => "Merchant name",
+        (None, "60") => "Merchant city",
+        (None, "62") => "Additional data",
+        (None, "63") => "CRC",
+        (None, "64") => "Merchant language template",
+        (None, _) => "Unknown",
+        (Some("64"), "00") => "Language",
+        (Some("64"), "01") => "Merchant name",
+        (Some("64"), "02") => "Merchant city",
+        (Some(_), "00") => "Application ID",
+        (Some("29"), "01") => "Mobile number",
+        (Some("29"), "02") => "National ID / tax ID",
+        (Some("29"), "03") => "E-wallet ID",
+        (Some("29"), "04") => "Bank account",
+        (Some("30"), "01") => "Biller ID",
+        (Some("30"), "02") => "Ref1",
+        (Some("30"), "03") => "Ref2",
+        (Some("62"), "01") => "Bill number",
+        (Some("62"), "02") => "Mobile number",
+        (Some("62"), "03") => "Store label",
+        (Some("62"), "04") => "Loyalty number",
+        (Some("62"), "05") => "Reference label",
+        (Some("62"), "06") => "Customer label",
+        (Some("62"), "07") => "Terminal label",
+        (Some("62"), "08") => "Purpose of transaction",
+        (Some(_), _) => "Unknown",
+    }
+}
+
+fn summary(qr: &PromptPayQR) -> String {
+    let mut text = format!("{} {}", qr.merchant_type().name(), qr.merchant_id());
+    if let Some(ref1) = qr.reference1() {
+        write!(text, ", Ref1 {}", ref1).unwrap();
+    }
+    if let Some(ref2) = qr.reference2() {
+        write!(text, ", Ref2 {}", ref2).unwrap();
+    }
+    match qr.amount() {
+        Some(amount) => write!(
+            text,
+            ", {:.2} {}",
+            amount,
+            qr.currency_code().alphabetic_code()
+        ),
+        None => write!(text, ", any amount"),
+    }
+    .unwrap();
+    text
+}
+
+fn payload_json(payload: &str) -> Value {
+    let fields = tlv::parse(payload)
+        .map(|fields| fields_json(&fields, None))
+        .unwrap_or_default();
+    let mut value = json!({ "payload": payload, "fields": fields });
+    match PromptPayQR::parse(payload) {
+        Ok(qr) => value["qr"] = qr.to_json(),
+        Err(e) => value["error"] = json!(e.to_string()),
+    }
+    value
+}
+
+fn fields_json(fields: &[tlv::Field<'_>], parent: Option<&str>) -> Vec<Value> {
+    fields
+        .iter()
+        .map(|field| {
+            let mut value = json!({
+                "tag": field.tag,
+                "name": tag_name(parent, field.tag),
+                "value": field.value,
+            });
+            if is_template(parent, field.tag)
+                && let Ok(sub_fields) = tlv::parse(field.value)
+            {
+                value["fields"] = json!(fields_json(&sub_fields, Some(field.tag)));
+            }
+            value
+        })
+        .collect()
+}
+
+fn issue_json(issue: &LintIssue) -> Value {
+    json!({
+        "severity": issue.severity.to_string(),
+        "tag": issue.tag,
+        "message": issue.message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use promptpay_rs::constants::MerchantType;
+
+    fn parse_args(args: &[&str]) -> Command {
+        Cli::try_parse_from(std::iter::once("promptpay").chain(args.iter().copied()))
+            .unwrap()
+            .command
+    }
+
+    /// ทดสอบการสร้าง payload จาก argument รวมถึง bill payment และ label ใน tag 62
+    #[test]
+    fn test_generate_args() {
+        let Command::Generate(args) = parse_args(&[
+            "generate",
+            "0812345678",
+            "--amount",
+            "150",
+            "--merchant-name",
+            "COFFEE HOUSE",
+            "--terminal-label",
+            "TABLE-07",
+            "--format",
+            "svg",
+        ]) else {
+            panic!("expected generate");
+        };
+        assert_eq!(args.output.format, Some(Format::Svg));
+        let payload = build_qr(&args).unwrap().create().unwrap();
+        let mut expected = PromptPayQR::new("0812345678");
+        expected
+            .set_amount(150.0)
+            .set_merchant_name("COFFEE HOUSE")
+            .set_additional_data(AdditionalData {
+                terminal_label: Some("TABLE-07".to_string()),
+                ..AdditionalData::default()
+            });
+        assert_eq!(payload, expected.create().unwrap());
+        assert!(to_svg(&payload, &args.output).unwrap().starts_with("<svg"));
+
+        let Command::Generate(args) = parse_args(&[
+            "generate",
+            "010753600031508",
+            "--ref1",
+            "CUST001",
+            "--ref2",
+            "INV1",
+        ]) else {
+            panic!("expected generate");
+        };
+        let qr = build_qr(&args).unwrap();
+        assert_eq!(qr.merchant_type(), MerchantType::BillerId);
+        assert_eq!(qr.reference2(), Some("INV1"));
+
+        let Command::Batch(args) =
+            parse_args(&["batch", "in.csv", "-d", "out", "-f", "svg", "-j", "4"])
+        else {
+            panic!("expected batch");
+        };
+        assert_eq!(
+            (args.format, args.threads.map(NonZeroUsize::get)),
+            (ImageFormat::Svg, Some(4))
+        );
+
+        // --ref2 ต้องมี --ref1
+        assert!(
+            Cli::try_parse_from(["promptpay", "generate", "0812345678", "--ref2", "X"]).is_err()
+        );
+    }
+
+    /// ทดสอบคำอธิบายทีละฟิลด์และ JSON ของ payload
+    #[test]
+    fn test_decode_explain_and_json() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(99.5);
+        let payload = qr.create().unwrap();
+
+        let text = explain(&payload);
+        assert!(text.contains("01 Point of initiation method       12 (dynamic)"));
+        assert!(text.contains("29 Merchant account (PromptPay)\n   00 Application ID"));
+        assert!(text.contains("   01 Mobile number                 0066812345678"));
+        assert!(text.contains(" (valid)\n"));
+        assert!(text.ends_with("=> mobile_number 0812345678, 99.50 THB\n"));
+
+        let value = payload_json(&payload);
+        assert_eq!(value["qr"]["amount"], "99.50");
+        assert_eq!(value["qr"]["country_code"], "TH");
+        assert_eq!(value["fields"][2]["fields"][1]["name"], "Mobile number");
+
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        assert!(explain(&tampered).contains("0000 (mismatch)\n=> invalid: Payload CRC mismatch"));
+        assert!(payload_json(&tampered)["error"].is_string());
+    }
+
+    /// ทดสอบรหัสออกของ verify และการแสดงผลใน terminal
+    #[test]
+    fn test_verify_exit_codes_and_terminal() {
+        let payload = PromptPayQR::new("0812345678").create().unwrap();
+        assert_eq!(verify_exit_code(&lint(&payload), true), 0);
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        assert_eq!(verify_exit_code(&lint(&tampered), false), EXIT_INVALID);
+        let dynamic_without_amount = {
+            let data = payload[..payload.len() - 4].replacen("010211", "010212", 1);
+            format!("{}{:04X}", data, promptpay_rs::crc::calculate_crc(&data))
+        };
+        let issues = lint(&dynamic_without_amount);
+        assert_eq!(verify_exit_code(&issues, false), 0);
+        assert_eq!(verify_exit_code(&issues, true), EXIT_WARNINGS);
+
+        let Command::Render(args) = parse_args(&["render", &payload, "--ec-level", "H"]) else {
+            panic!("expected render");
+        };
+        let text = to_terminal(&payload, &args.output).unwrap();
+        let matrix = QrMatrix::encode(&payload, EcLevel::H).unwrap();
+        assert_eq!(
+            text.lines().count(),
+            (matrix.size() + QUIET_ZONE * 2).div_ceil(2)
+        );
+        assert!(text.lines().nth(2).unwrap().contains("█▀▀▀▀▀█"));
+    }
+}
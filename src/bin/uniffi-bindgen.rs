@@ -0,0 +1,6 @@
+//! `uniffi-bindgen` tool that generates the Kotlin / Swift sources for
+//! [`promptpay_rs::mobile`] (requires the `uniffi-cli` feature).
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
@@ -0,0 +1,362 @@
+//! **Thai QR Payment** branded card.
+//!
+//! Lays out the standard merchant display: a navy header, the PromptPay mark,
+//! the QR code, merchant name, masked proxy and amount line. The card is produced
+//! as SVG; PNG output requires the `raster` feature.
+//!
+//! Official artwork is not bundled. The header shows a text mark by default, and
+//! the licensed Thai QR Payment logo can be supplied with [`ThaiQrCard::set_header_logo`].
+//!
+//! # Example
+//! ```rust
+//! use promptpay_rs::{PromptPayQR, card::ThaiQrCard};
+//!
+//! let mut qr = PromptPayQR::new("0812345678");
+//! qr.set_amount(1250.75);
+//! let svg = ThaiQrCard::new(&qr).set_merchant_name("ร้านกาแฟ").to_svg().unwrap();
+//! assert!(svg.contains("฿1,250.75"));
+//! assert!(svg.contains("xxx-xxx-5678"));
+//! ```
+
+use crate::{
+    EcLevel, PromptPayError, PromptPayQR,
+    constants::MerchantType,
+    qr::LogoOverlay,
+    utils::{escape_xml, format_amount, mask_target},
+};
+use std::fmt::Write;
+
+const CARD_WIDTH: f64 = 400.0;
+const HEADER_HEIGHT: f64 = 64.0;
+const QR_TOP: f64 = 124.0;
+const QR_WIDTH: f64 = 280.0;
+const QUIET_ZONE: usize = 4;
+const NAVY: &str = "#113566";
+const TEXT_COLOR: &str = "#1f2937";
+const MUTED_COLOR: &str = "#6b7280";
+const FONT_FAMILY: &str = "Sarabun, Tahoma, 'Noto Sans Thai', 'DejaVu Sans', sans-serif";
+
+/// Builder for a **Thai QR Payment** card.
+#[derive(Debug, Clone)]
+pub struct ThaiQrCard<'a> {
+    qr: &'a PromptPayQR,           // payload ที่จะแสดง
+    merchant_name: Option<String>, // ชื่อร้านค้า (ไม่บังคับ)
+    header_logo: Option<String>,   // href ของโลโก้ส่วนหัว (URL หรือ data URI)
+    logo: Option<(String, f64)>,   // โลโก้ร้านกลาง QR: (href, สัดส่วนต่อความกว้าง QR)
+    ec_level: EcLevel,             // ระดับการแก้ไขข้อผิดพลาดของ QR
+}
+
+impl<'a> ThaiQrCard<'a> {
+    /// Creates a card for a payload, using error correction level `M`.
+    pub fn new(qr: &'a PromptPayQR) -> Self {
+        ThaiQrCard {
+            qr,
+            merchant_name: None,
+            header_logo: None,
+            logo: None,
+            ec_level: EcLevel::M,
+        }
+    }
+
+    /// Sets the merchant name shown below the QR code.
+    ///
+    /// Defaults to the payload's Thai name (tag `64`), then its merchant name (tag `59`).
+    pub fn set_merchant_name(&mut self, name: &str) -> &mut Self {
+        let name = name.trim();
+        self.merchant_name = (!name.is_empty()).then(|| name.to_string());
+        self
+    }
+
+    /// Replaces the header text mark with an image (URL or `data:` URI), drawn
+    /// inside the navy header.
+    pub fn set_header_logo(&mut self, href: &str) -> &mut Self {
+        self.header_logo = Some(href.to_string());
+        self
+    }
+
+    /// Places a shop logo (URL or `data:` URI) in the centre of the QR code.
+    ///
+    /// `ratio` is the logo width relative to the symbol (e.g. `0.2`). The error
+    /// correction level is raised as needed and the code is decoded before the
+    /// card is returned; see [`QrMatrix::encode_with_logo`](crate::qr::QrMatrix::encode_with_logo).
+    pub fn set_logo(&mut self, href: &str, ratio: f64) -> &mut Self {
+        self.logo = Some((href.to_string(), ratio));
+        self
+    }
+
+    /// Sets the error correction level of the QR code.
+    pub fn set_ec_level(&mut self, ec_level: EcLevel) -> &mut Self {
+        self.ec_level = ec_level;
+        self
+    }
+
+    /// Text shown under the merchant name, e.g. `"Mobile xxx-xxx-5678"`.
+    pub fn proxy_text(&self) -> String {
+        let label = match self.qr.merchant_type() {
+            MerchantType::MobileNumber => "Mobile",
+            MerchantType::TaxId => "Tax ID",
+            MerchantType::EWalletId => "e-Wallet",
+            MerchantType::BillerId => "Biller ID",
+        };
+        format!(
+            "{} {}",
+            label,
+            mask_target(self.qr.merchant_type(), self.qr.merchant_id())
+        )
+    }
+
+    /// Renders the card as an **SVG** document (400 user units wide).
+    ///
+    /// # Returns
+    /// * `Ok(String)` - SVG markup
+    /// * `Err(PromptPayError)` - If the payload cannot be created or encoded
+    pub fn to_svg(&self) -> Result<String, PromptPayError> {
+        let (matrix, logo_area) = match &self.logo {
+            Some((_, ratio)) => {
+                let (matrix, area) = self
+                    .qr
+                    .to_qr_matrix_with_logo(self.ec_level, LogoOverlay::square(*ratio))?;
+                (matrix, Some(area))
+            }
+            None => (self.qr.to_qr_matrix(self.ec_level)?, None),
+        };
+        let center = CARD_WIDTH / 2.0;
+
+        // บรรทัดข้อความใต้ QR: (ข้อความ, ขนาดตัวอักษร, น้ำหนัก, สี)
+        let mut lines: Vec<(String, f64, &str, &str)> = Vec::new();
+        // ชื่อร้าน: ค่าที่กำหนดให้การ์ด → ชื่อภาษาไทย (tag 64) → ชื่อร้าน (tag 59)
+        let merchant_name = self
+            .merchant_name
+            .as_deref()
+            .or(self
+                .qr
+                .merchant_language()
+                .map(|l| l.merchant_name.as_str()))
+            .or(self.qr.merchant_name());
+        if let Some(name) = merchant_name {
+            lines.push((name.to_string(), 20.0, "bold", TEXT_COLOR));
+        }
+        lines.push((self.proxy_text(), 14.0, "normal", MUTED_COLOR));
+        if let Some(ref1) = self.qr.reference1() {
+            lines.push((format!("Ref. {}", ref1), 14.0, "normal", MUTED_COLOR));
+        }
+        if let Some(amount) = self.qr.amount() {
+            lines.push((format!("฿{}", format_amount(amount)), 28.0, "bold", NAVY));
+        }
+
+        let mut y = QR_TOP + QR_WIDTH + 8.0;
+        let mut text = String::new();
+        for (content, font_size, weight, color) in &lines {
+            y += font_size * 1.5;
+            write!(
+                text,
+                r#"<text x="{center}" y="{y}" font-size="{font_size}" font-weight="{weight}" fill="{color}">{}</text>"#,
+                escape_xml(content)
+            )
+            .expect("writing to String cannot fail");
+        }
+        let height = y + 28.0;
+
+        let mut svg = format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" version="1.1" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+                r##"<rect x="0.5" y="0.5" width="{iw}" height="{ih}" rx="12" fill="#ffffff" stroke="#d0d5dd"/>"##,
+                // ส่วนหัวสีกรมท่า มุมบนโค้งตามขอบการ์ด
+                r#"<path d="M0 12a12 12 0 0 1 12-12h{hw}a12 12 0 0 1 12 12v{hh}h-{w}z" fill="{navy}"/>"#,
+            ),
+            w = CARD_WIDTH,
+            h = height,
+            iw = CARD_WIDTH - 1.0,
+            ih = height - 1.0,
+            hw = CARD_WIDTH - 24.0,
+            hh = HEADER_HEIGHT - 12.0,
+            navy = NAVY,
+        );
+
+        match &self.header_logo {
+            Some(href) => write!(
+                svg,
+                r#"<image x="{}" y="8" width="{}" height="{}" href="{}" preserveAspectRatio="xMidYMid meet"/>"#,
+                CARD_WIDTH / 4.0,
+                CARD_WIDTH / 2.0,
+                HEADER_HEIGHT - 16.0,
+                escape_xml(href)
+            ),
+            None => write!(
+                svg,
+                r##"<text x="{center}" y="41" font-family="{}" font-size="22" font-weight="bold" fill="#ffffff" text-anchor="middle" letter-spacing="1">THAI QR PAYMENT</text>"##,
+                FONT_FAMILY
+            ),
+        }
+        .expect("writing to String cannot fail");
+
+        // เครื่องหมาย PromptPay (กรอบมนพร้อมข้อความ)
+        write!(
+            svg,
+            concat!(
+                r#"<rect x="{}" y="80" width="140" height="30" rx="6" fill="none" stroke="{navy}" stroke-width="2"/>"#,
+                r#"<text x="{center}" y="101" font-family="{font}" font-size="18" font-weight="bold" fill="{navy}" text-anchor="middle">PromptPay</text>"#,
+            ),
+            center - 70.0,
+            center = center,
+            navy = NAVY,
+            font = FONT_FAMILY,
+        )
+        .expect("writing to String cannot fail");
+
+        // QR code พร้อม quiet zone ย่อให้พอดีกับพื้นที่ QR_WIDTH
+        let scale = QR_WIDTH / (matrix.size() + QUIET_ZONE * 2) as f64;
+        write!(
+            svg,
+            concat!(
+                r#"<g transform="translate({x} {y}) scale({scale})" shape-rendering="crispEdges">"#,
+                r##"<rect width="{n}" height="{n}" fill="#ffffff"/>"##,
+                r##"<path transform="translate({q} {q})" fill="#000000" d="{d}"/>"##,
+            ),
+            x = center - QR_WIDTH / 2.0,
+            y = QR_TOP,
+            scale = scale,
+            n = matrix.size() + QUIET_ZONE * 2,
+            q = QUIET_ZONE,
+            d = matrix.svg_path(),
+        )
+        .expect("writing to String cannot fail");
+        // โลโก้วางภายในพื้นที่ที่เว้นไว้ (หักขอบ 1 module)
+        if let (Some((href, _)), Some(area)) = (&self.logo, logo_area) {
+            write!(
+                svg,
+                r#"<image x="{}" y="{}" width="{}" height="{}" href="{}" preserveAspectRatio="xMidYMid meet"/>"#,
+                area.x + QUIET_ZONE + 1,
+                area.y + QUIET_ZONE + 1,
+                area.width - 2,
+                area.height - 2,
+                escape_xml(href)
+            )
+            .expect("writing to String cannot fail");
+        }
+        svg.push_str("</g>");
+
+        write!(
+            svg,
+            r#"<g font-family="{}" text-anchor="middle">{}</g></svg>"#,
+            FONT_FAMILY, text
+        )
+        .expect("writing to String cannot fail");
+        Ok(svg)
+    }
+
+    /// Renders the card as a **PNG** image (requires the `raster` feature).
+    ///
+    /// Text is drawn with the fonts installed on the system, so a Thai font
+    /// (e.g. Sarabun or Noto Sans Thai) should be available for Thai merchant names.
+    ///
+    /// # Arguments
+    /// * `scale` - Pixels per SVG user unit (`1.0` → 400 px wide, `2.0` → 800 px)
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - PNG file contents
+    /// * `Err(PromptPayError)` - If the payload cannot be encoded or the card cannot be rasterised
+    #[cfg(feature = "raster")]
+    pub fn to_png_bytes(&self, scale: f32) -> Result<Vec<u8>, PromptPayError> {
+        use crate::error::ErrorKind;
+        use resvg::{tiny_skia, usvg};
+
+        let render_error = |msg: String| PromptPayError::with_kind(ErrorKind::Render, &msg);
+        if !(scale.is_finite() && scale > 0.0) {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "Scale must be a positive number",
+            ));
+        }
+
+        let svg = self.to_svg()?;
+        let mut options = usvg::Options::default();
+        options.fontdb_mut().load_system_fonts();
+        let tree = usvg::Tree::from_str(&svg, &options)
+            .map_err(|e| render_error(format!("Failed to parse card SVG: {}", e)))?;
+
+        let size = tree
+            .size()
+            .to_int_size()
+            .scale_by(scale)
+            .ok_or_else(|| render_error("Card size is out of range".to_string()))?;
+        let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+            .ok_or_else(|| render_error("Failed to allocate card image".to_string()))?;
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::from_scale(scale, scale),
+            &mut pixmap.as_mut(),
+        );
+        pixmap
+            .encode_png()
+            .map_err(|e| render_error(format!("Failed to encode PNG: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบ layout ของการ์ด: ชื่อร้าน, proxy ที่ปิดบัง, ยอดเงิน และ escape ข้อความ
+    #[test]
+    fn test_card_svg_layout() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(1250.75);
+        let svg = ThaiQrCard::new(&qr)
+            .set_merchant_name("Tom & Jerry <Cafe>")
+            .to_svg()
+            .unwrap();
+
+        assert!(svg.contains("THAI QR PAYMENT"));
+        assert!(svg.contains(">PromptPay</text>"));
+        assert!(svg.contains("Tom &amp; Jerry &lt;Cafe&gt;"));
+        assert!(svg.contains(">Mobile xxx-xxx-5678</text>"));
+        assert!(svg.contains(">฿1,250.75</text>"));
+        // ชื่อร้านต้องอยู่ก่อนยอดเงิน
+        assert!(svg.find("Tom &amp;").unwrap() < svg.find("฿1,250.75").unwrap());
+    }
+
+    /// ทดสอบการ์ดแบบ static, bill payment และโลโก้ส่วนหัว
+    #[test]
+    fn test_card_variants() {
+        let qr = PromptPayQR::new("1101700230708");
+        let svg = ThaiQrCard::new(&qr).to_svg().unwrap();
+        assert!(svg.contains("Tax ID x-xxxx-xxxx0-70-8"));
+        assert!(!svg.contains('฿'));
+
+        let bill = PromptPayQR::bill_payment("010753600031508", "CUST001", None);
+        let svg = ThaiQrCard::new(&bill)
+            .set_header_logo("https://example.com/thaiqr.svg")
+            .to_svg()
+            .unwrap();
+        assert!(svg.contains("Biller ID 010753600031508"));
+        assert!(svg.contains("Ref. CUST001"));
+        assert!(svg.contains(r#"href="https://example.com/thaiqr.svg""#));
+        assert!(!svg.contains("THAI QR PAYMENT"));
+
+        assert!(ThaiQrCard::new(&PromptPayQR::new("")).to_svg().is_err());
+
+        let qr = PromptPayQR::new("0812345678");
+        let svg = ThaiQrCard::new(&qr)
+            .set_logo("data:image/png;base64,AAAA", 0.2)
+            .to_svg()
+            .unwrap();
+        assert!(svg.contains(r#"href="data:image/png;base64,AAAA""#));
+        assert!(
+            ThaiQrCard::new(&qr)
+                .set_logo("logo.png", 0.5)
+                .to_svg()
+                .is_err()
+        );
+    }
+
+    /// ทดสอบการแปลงการ์ดเป็น PNG
+    #[cfg(feature = "raster")]
+    #[test]
+    fn test_card_png() {
+        let qr = PromptPayQR::new("0812345678");
+        let png = ThaiQrCard::new(&qr).to_png_bytes(2.0).unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+        assert!(ThaiQrCard::new(&qr).to_png_bytes(0.0).is_err());
+    }
+}
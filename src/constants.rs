@@ -1,4 +1,14 @@
-use std::fmt;
+use crate::{PromptPayError, error::ErrorKind};
+use std::{fmt, str::FromStr};
+
+/// Application ID of **PromptPay credit transfer** (sub-tag `00` of tag `29`).
+pub const PROMPTPAY_AID: &str = "A000000677010111";
+
+/// Application ID of **PromptPay bill payment** (sub-tag `00` of tag `30`).
+pub const PROMPTPAY_BILL_AID: &str = "A000000677010112";
+
+/// Maximum length of an EMVCo payload, in characters.
+pub const MAX_PAYLOAD_LENGTH: usize = 512;
 
 /// Country code according to **ISO 3166-1 alpha-2** standard.
 ///
@@ -7,6 +17,7 @@ use std::fmt;
 /// # Variants
 /// * `Thailand` - Thailand (`"TH"`)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum CountryCode {
     /// Thailand - ISO 3166-1 alpha-2 code: `"TH"`
     Thailand,
@@ -37,6 +48,7 @@ impl CountryCode {
     /// use promptpay_rs::CountryCode;
     /// assert_eq!(CountryCode::from_str("th"), Some(CountryCode::Thailand));
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s.trim().to_uppercase().as_str() {
             "TH" | "THAILAND" => Some(CountryCode::Thailand),
@@ -58,6 +70,7 @@ impl fmt::Display for CountryCode {
 /// # Variants
 /// * `THB` - Thai Baht (numeric: `"764"`, alphabetic: `"THB"`)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum CurrencyCode {
     /// Thai Baht
     THB,
@@ -102,26 +115,104 @@ impl fmt::Display for CurrencyCode {
     }
 }
 
+/// QR code **error correction level**.
+///
+/// Higher levels can recover from more damage at the cost of a denser symbol:
+/// - `L` → ~7% of codewords
+/// - `M` → ~15% (default)
+/// - `Q` → ~25%
+/// - `H` → ~30%
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum EcLevel {
+    L,
+    #[default]
+    M,
+    Q,
+    H,
+}
+
+impl FromStr for EcLevel {
+    type Err = PromptPayError;
+
+    /// Parses `L`, `M`, `Q` or `H` (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "L" => Ok(EcLevel::L),
+            "M" => Ok(EcLevel::M),
+            "Q" => Ok(EcLevel::Q),
+            "H" => Ok(EcLevel::H),
+            _ => Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Error correction level must be L, M, Q or H: {}", s),
+            )),
+        }
+    }
+}
+
 /// Type of merchant identifier used in PromptPay.
 ///
 /// Determines the tag used in Merchant Account Information field:
 /// - `"01"` → Mobile Number
 /// - `"02"` → Tax ID
 /// - `"03"` → E-Wallet ID
+/// - `"01"` → Biller ID (inside the bill payment template, tag `30`)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum MerchantType {
     MobileNumber,
     TaxId,
     EWalletId,
+    BillerId,
 }
 
 impl MerchantType {
-    /// Returns the 2-digit tag used in the payload.
+    /// Returns the 2-digit sub-tag of the proxy inside the merchant account
+    /// template: tag `29` for credit transfers, tag `30` for bill payments.
+    ///
+    /// [`MerchantType::BillerId`] and [`MerchantType::MobileNumber`] both use
+    /// `"01"` because they live in different templates; use
+    /// [`MerchantType::name`] for a unique name.
     pub fn as_str(&self) -> &'static str {
         match self {
             MerchantType::MobileNumber => "01",
             MerchantType::TaxId => "02",
             MerchantType::EWalletId => "03",
+            MerchantType::BillerId => "01",
+        }
+    }
+
+    /// Returns the name used in JSON, the CLI and the bindings, e.g. `"mobile_number"`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::constants::MerchantType;
+    /// assert_eq!(MerchantType::EWalletId.name(), "ewallet_id");
+    /// assert_eq!("ewallet_id".parse().ok(), Some(MerchantType::EWalletId));
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            MerchantType::MobileNumber => "mobile_number",
+            MerchantType::TaxId => "tax_id",
+            MerchantType::EWalletId => "ewallet_id",
+            MerchantType::BillerId => "biller_id",
+        }
+    }
+
+    /// Parses the 2-digit tag used in the credit transfer template (tag `29`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::constants::MerchantType;
+    /// assert_eq!(MerchantType::from_tag("02"), Some(MerchantType::TaxId));
+    /// assert_eq!(MerchantType::from_tag("09"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "01" => Some(MerchantType::MobileNumber),
+            "02" => Some(MerchantType::TaxId),
+            "03" => Some(MerchantType::EWalletId),
+            _ => None,
         }
     }
 
@@ -142,7 +233,7 @@ impl MerchantType {
     /// assert_eq!(MerchantType::from_merchant_id("1234567890123"), MerchantType::TaxId);
     /// ```
     pub fn from_merchant_id(id: &str) -> Self {
-        let digits_only: String = id.chars().filter(|c| c.is_digit(10)).collect();
+        let digits_only: String = id.chars().filter(|c| c.is_ascii_digit()).collect();
         match digits_only.len() {
             len if len >= 15 => MerchantType::EWalletId,
             len if len >= 13 => MerchantType::TaxId,
@@ -151,6 +242,27 @@ impl MerchantType {
     }
 }
 
+impl FromStr for MerchantType {
+    type Err = PromptPayError;
+
+    /// Parses a name returned by [`MerchantType::name`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mobile_number" => Ok(MerchantType::MobileNumber),
+            "tax_id" => Ok(MerchantType::TaxId),
+            "ewallet_id" => Ok(MerchantType::EWalletId),
+            "biller_id" => Ok(MerchantType::BillerId),
+            _ => Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!(
+                    "Merchant type must be mobile_number, tax_id, ewallet_id or biller_id: {}",
+                    s
+                ),
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,4 +300,28 @@ mod tests {
         );
         assert_eq!(CurrencyCode::from_numeric("840"), None);
     }
+
+    #[test]
+    fn test_merchant_type_name() {
+        for merchant_type in [
+            MerchantType::MobileNumber,
+            MerchantType::TaxId,
+            MerchantType::EWalletId,
+            MerchantType::BillerId,
+        ] {
+            assert_eq!(merchant_type.name().parse().ok(), Some(merchant_type));
+        }
+        assert_eq!(MerchantType::TaxId.name(), "tax_id");
+        let error = "bank".parse::<MerchantType>().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_ec_level_from_str() {
+        assert_eq!("L".parse::<EcLevel>().ok(), Some(EcLevel::L));
+        assert_eq!("q".parse::<EcLevel>().ok(), Some(EcLevel::Q));
+        assert_eq!("H".parse::<EcLevel>().ok(), Some(EcLevel::H));
+        let error = "X".parse::<EcLevel>().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
 }
@@ -33,4 +33,55 @@ pub fn calculate_crc(data: &str) -> u16 {
         }
     }
     crc // คืนค่า CRC ดิบ (ไม่ XOR 0xFFFF)
-}
\ No newline at end of file
+}
+
+/// Verifies the trailing CRC field of a payload.
+///
+/// The payload must end with `tag` + `"04"` + 4 hex digits, where the CRC covers
+/// everything before the hex digits (as produced by [`calculate_crc`]).
+///
+/// # Arguments
+/// * `payload` - Complete payload including the CRC field
+/// * `tag` - Tag of the CRC field (`"63"` for EMVCo payloads, `"91"` for slip QRs)
+///
+/// # Returns
+/// `true` if the CRC field is present and matches
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{PromptPayQR, crc::verify_crc};
+/// let payload = PromptPayQR::new("0812345678").create().unwrap();
+/// assert!(verify_crc(&payload, "63"));
+/// ```
+pub fn verify_crc(payload: &str, tag: &str) -> bool {
+    // ตำแหน่งตัดต้องอยู่ที่ขอบตัวอักษร (payload อาจมีภาษาไทย)
+    let boundary = |i| payload.len() >= i && payload.is_char_boundary(payload.len() - i);
+    if !boundary(8) || !boundary(4) {
+        return false;
+    }
+    let (data, crc) = payload.split_at(payload.len() - 4);
+    if !data.ends_with(&format!("{}04", tag)) {
+        return false;
+    }
+    match u16::from_str_radix(crc, 16) {
+        Ok(expected) => crc.bytes().all(|b| b.is_ascii_hexdigit()) && calculate_crc(data) == expected,
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบ payload ที่ลงท้ายด้วยตัวอักษรหลาย byte ต้องคืน false ไม่ใช่ panic
+    #[test]
+    fn test_verify_crc_non_ascii_tail() {
+        for payload in ["0000กก", "00000000ก", "000000ก0", "ก6304ABCD", "กกก"] {
+            assert!(!verify_crc(payload, "63"), "{}", payload);
+        }
+        let data = "0002016304";
+        let payload = format!("{}{:04X}", data, calculate_crc(data));
+        assert!(verify_crc(&payload, "63"));
+        assert!(!verify_crc(&payload, "91"));
+    }
+}
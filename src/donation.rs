@@ -0,0 +1,216 @@
+use crate::{
+    PromptPayError, PromptPayQR,
+    constants::MerchantType,
+    error::ErrorKind,
+    utils::{is_valid_biller_id, is_valid_reference, is_valid_thai_id, sanitize_target},
+};
+
+/// Builder for **e-Donation** QR codes.
+///
+/// Charities registered with the Revenue Department's e-Donation system accept
+/// donations through bill payment QRs (tag `30`) so that the donation is reported
+/// for tax deduction automatically. The references are laid out as:
+///
+/// - Biller ID: organisation Tax ID (13 digits, checksummed) + 2-digit suffix
+/// - `Ref1`: donor's 13-digit national ID (checksummed)
+/// - `Ref2`: optional project / campaign code of the organisation
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::donation::EDonation;
+///
+/// let mut donation = EDonation::new("099400016550101", "1-1017-00230-70-8");
+/// donation.set_amount(500.0);
+/// let payload = donation.create().unwrap();
+///
+/// let decoded = EDonation::parse(&payload).unwrap();
+/// assert_eq!(decoded.organisation_tax_id(), "0994000165501");
+/// assert_eq!(decoded.donor_id(), "1101700230708");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EDonation {
+    biller_id: String,            // Biller ID ของหน่วยรับบริจาค
+    donor_id: String,             // เลขประจำตัวประชาชนผู้บริจาค
+    project_code: Option<String>, // รหัสโครงการ (ถ้ามี)
+    amount: Option<f64>,          // จำนวนเงินบริจาค (ถ้ามี)
+}
+
+impl EDonation {
+    /// Creates an e-Donation QR definition.
+    ///
+    /// # Arguments
+    /// * `biller_id` - 15-digit Biller ID of the receiving organisation
+    /// * `donor_id` - Donor's 13-digit national ID (dashes and spaces are ignored)
+    ///
+    /// # Returns
+    /// A new `EDonation`; the IDs are validated by [`EDonation::to_qr`] / [`EDonation::create`]
+    pub fn new(biller_id: &str, donor_id: &str) -> Self {
+        EDonation {
+            biller_id: sanitize_target(biller_id),
+            donor_id: sanitize_target(donor_id),
+            project_code: None,
+            amount: None,
+        }
+    }
+
+    /// Sets the organisation's project / campaign code (carried in `Ref2`).
+    ///
+    /// # Returns
+    /// `&mut self` for method chaining
+    pub fn set_project_code(&mut self, project_code: &str) -> &mut Self {
+        self.project_code = Some(project_code.to_string());
+        self
+    }
+
+    /// Sets the donation amount (enables **dynamic QR**).
+    ///
+    /// # Returns
+    /// `&mut self` for method chaining
+    pub fn set_amount(&mut self, amount: f64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Validates the IDs and builds the bill payment `PromptPayQR`.
+    ///
+    /// # Returns
+    /// * `Ok(PromptPayQR)` - Bill payment QR with the e-Donation reference layout
+    /// * `Err(PromptPayError)` - If the organisation Tax ID or donor ID fails its checksum,
+    ///   or the project code is not a valid reference
+    pub fn to_qr(&self) -> Result<PromptPayQR, PromptPayError> {
+        if !is_valid_biller_id(&self.biller_id) || !is_valid_thai_id(&self.biller_id[..13]) {
+            return Err(input_error(&format!(
+                "Invalid organisation Biller ID: {}",
+                self.biller_id
+            )));
+        }
+        if !is_valid_thai_id(&self.donor_id) {
+            return Err(input_error(&format!(
+                "Invalid donor national ID: {}",
+                self.donor_id
+            )));
+        }
+        if let Some(code) = &self.project_code
+            && !is_valid_reference(code)
+        {
+            return Err(input_error(&format!(
+                "Project code must be 1-20 digits or uppercase letters: {}",
+                code
+            )));
+        }
+
+        let mut qr = PromptPayQR::bill_payment(
+            &self.biller_id,
+            &self.donor_id,
+            self.project_code.as_deref(),
+        );
+        if let Some(amount) = self.amount {
+            qr.set_amount(amount);
+        }
+        Ok(qr)
+    }
+
+    /// Generates the e-Donation **EMVCo payload**.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Payload ready to be encoded in a QR code
+    /// * `Err(PromptPayError)` - See [`EDonation::to_qr`]
+    pub fn create(&self) -> Result<String, PromptPayError> {
+        self.to_qr()?.create()
+    }
+
+    /// Decodes an e-Donation payload back into donor and organisation info.
+    ///
+    /// # Returns
+    /// * `Ok(EDonation)` - Decoded donation
+    /// * `Err(PromptPayError)` - If the payload is invalid or not an e-Donation QR
+    pub fn parse(payload: &str) -> Result<Self, PromptPayError> {
+        EDonation::from_qr(&PromptPayQR::parse(payload)?)
+    }
+
+    /// Reads donor and organisation info from a decoded bill payment `PromptPayQR`.
+    ///
+    /// # Returns
+    /// * `Ok(EDonation)` - Decoded donation
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidPayload`] if the QR is not a bill payment
+    ///   or its IDs do not follow the e-Donation layout
+    pub fn from_qr(qr: &PromptPayQR) -> Result<Self, PromptPayError> {
+        if qr.merchant_type() != MerchantType::BillerId {
+            return Err(invalid("QR is not a bill payment QR"));
+        }
+        let donation = EDonation {
+            biller_id: sanitize_target(qr.merchant_id()),
+            donor_id: qr.reference1().unwrap_or_default().to_string(),
+            project_code: qr.reference2().map(|r| r.to_string()),
+            amount: qr.amount(),
+        };
+
+        // ตรวจสอบ layout ของ e-Donation (checksum ของทั้งสองฝั่ง)
+        donation
+            .to_qr()
+            .map_err(|e| invalid(&format!("Not an e-Donation QR: {}", e)))?;
+        Ok(donation)
+    }
+
+    // --- Getters ---
+    pub fn biller_id(&self) -> &str { &self.biller_id }
+    pub fn organisation_tax_id(&self) -> &str { &self.biller_id[..self.biller_id.len().min(13)] }
+    pub fn biller_suffix(&self) -> &str { &self.biller_id[self.biller_id.len().min(13)..] }
+    pub fn donor_id(&self) -> &str { &self.donor_id }
+    pub fn project_code(&self) -> Option<&str> { self.project_code.as_deref() }
+    pub fn amount(&self) -> Option<f64> { self.amount }
+}
+
+fn input_error(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidInput, msg)
+}
+
+fn invalid(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidPayload, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการสร้างและถอดรหัส e-Donation QR
+    #[test]
+    fn test_edonation_roundtrip() {
+        let mut donation = EDonation::new("010753600031508", "1101700230708");
+        donation.set_project_code("FLOOD2025").set_amount(1000.0);
+        let payload = donation.create().unwrap();
+        assert!(payload.contains("02131101700230708"));
+        assert!(payload.contains("0309FLOOD2025"));
+
+        let decoded = EDonation::parse(&payload).unwrap();
+        assert_eq!(decoded, donation);
+        assert_eq!(decoded.organisation_tax_id(), "0107536000315");
+        assert_eq!(decoded.biller_suffix(), "08");
+        assert_eq!(decoded.project_code(), Some("FLOOD2025"));
+        assert_eq!(decoded.amount(), Some(1000.0));
+    }
+
+    /// ทดสอบการตรวจสอบ checksum ของหน่วยรับบริจาคและผู้บริจาค
+    #[test]
+    fn test_edonation_invalid_ids() {
+        let err = EDonation::new("010753600031608", "1101700230708").create().unwrap_err();
+        assert_eq!(err.to_string(), "Invalid organisation Biller ID: 010753600031608");
+
+        let err = EDonation::new("010753600031508", "1101700230705").create().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "Invalid donor national ID: 1101700230705");
+    }
+
+    /// ทดสอบการปฏิเสธ bill payment QR ที่ไม่ใช่ e-Donation
+    #[test]
+    fn test_edonation_parse_plain_bill_payment() {
+        let payload = PromptPayQR::bill_payment("010753600031508", "CUST001", None)
+            .create()
+            .unwrap();
+        let err = EDonation::parse(&payload).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPayload);
+
+        let payload = PromptPayQR::new("0812345678").create().unwrap();
+        assert!(EDonation::parse(&payload).is_err());
+    }
+}
@@ -1,5 +1,24 @@
 use std::{error::Error, fmt};
 
+/// Broad category of a [`PromptPayError`].
+///
+/// Lets callers react to a failure without matching on the message text
+/// (e.g. to tell a corrupted payload apart from bad user input).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Input supplied by the caller is missing or malformed
+    InvalidInput,
+    /// A payload could not be decoded (bad TLV structure, missing tag, ...)
+    InvalidPayload,
+    /// The payload decoded but its CRC does not match
+    CrcMismatch,
+    /// The payload could not be rendered (e.g. too long for a QR symbol)
+    Render,
+    /// Any other failure
+    Other,
+}
+
 /// Custom error type for PromptPay QR generation failures.
 ///
 /// This error is used throughout the library to indicate issues such as:
@@ -8,12 +27,16 @@ use std::{error::Error, fmt};
 /// - QR code generation failure
 #[derive(Debug)]
 pub struct PromptPayError {
+    kind: ErrorKind, // หมวดหมู่ของข้อผิดพลาด
     details: String, // ข้อความอธิบายข้อผิดพลาด
 }
 
 impl PromptPayError {
     /// Creates a new `PromptPayError` with a custom message.
     ///
+    /// The error kind is [`ErrorKind::Other`]; use [`PromptPayError::with_kind`]
+    /// when the failure belongs to a known category.
+    ///
     /// # Arguments
     /// * `msg` - A descriptive error message
     ///
@@ -26,10 +49,28 @@ impl PromptPayError {
     /// let err = PromptPayError::new("Invalid phone number");
     /// ```
     pub fn new(msg: &str) -> PromptPayError {
+        PromptPayError::with_kind(ErrorKind::Other, msg)
+    }
+
+    /// Creates a new `PromptPayError` of the given kind.
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::{ErrorKind, PromptPayError};
+    /// let err = PromptPayError::with_kind(ErrorKind::CrcMismatch, "CRC mismatch");
+    /// assert_eq!(err.kind(), ErrorKind::CrcMismatch);
+    /// ```
+    pub fn with_kind(kind: ErrorKind, msg: &str) -> PromptPayError {
         PromptPayError {
+            kind,
             details: msg.to_string(),
         }
     }
+
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
 }
 
 impl fmt::Display for PromptPayError {
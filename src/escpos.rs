@@ -0,0 +1,287 @@
+//! **ESC/POS** output for thermal receipt printers.
+//!
+//! Two ways to print the QR code:
+//! - [`EscPosMode::Native`] → the printer's own QR generator (`GS ( k`), smallest data
+//! - [`EscPosMode::Raster`] → a bit image (`GS v 0`) drawn by the built-in encoder,
+//!   for printers without QR support
+//!
+//! The output is a plain byte sequence, so it can be sent to a serial / USB / network
+//! printer or compared in tests without a printer.
+//!
+//! # Example
+//! ```rust
+//! use promptpay_rs::{PromptPayQR, escpos::EscPosOptions};
+//!
+//! let mut qr = PromptPayQR::new("0812345678");
+//! qr.set_amount(1250.75);
+//! let bytes = qr.to_escpos(&EscPosOptions::default()).unwrap();
+//! assert_eq!(&bytes[..2], b"\x1b@"); // ESC @ (initialize)
+//! ```
+
+use crate::{EcLevel, PromptPayError, PromptPayQR, error::ErrorKind, utils::format_amount};
+
+const ESC: u8 = 0x1B;
+const GS: u8 = 0x1D;
+const LF: u8 = 0x0A;
+// quiet zone ของภาพ raster (module ต่อด้าน)
+const RASTER_QUIET_ZONE: usize = 4;
+
+/// How the QR code is sent to the printer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EscPosMode {
+    /// Printer-generated QR code (`GS ( k`)
+    #[default]
+    Native,
+    /// Bit image drawn by this library (`GS v 0`)
+    Raster,
+}
+
+/// QR model for [`EscPosMode::Native`] (`GS ( k` function 165).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrModel {
+    /// Original QR code model (rarely needed)
+    Model1,
+    /// Standard QR code (all phone scanners)
+    #[default]
+    Model2,
+}
+
+/// Options for [`PromptPayQR::to_escpos`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EscPosOptions {
+    /// Native printer QR or raster image
+    pub mode: EscPosMode,
+    /// QR model (native mode only)
+    pub model: QrModel,
+    /// Module size in printer dots, 1-16
+    pub module_size: u8,
+    /// Error correction level
+    pub ec_level: EcLevel,
+    /// Print the merchant name above the QR code (if the payload has one)
+    pub print_merchant: bool,
+    /// Print the amount below the QR code (if the payload has one)
+    pub print_amount: bool,
+    /// Character code table selected with `ESC t` for Thai text (TIS-620 / CP874),
+    /// e.g. `21` on Epson printers. `None` prints Latin text only.
+    pub code_table: Option<u8>,
+    /// Feed and partially cut the paper at the end
+    pub cut: bool,
+}
+
+impl Default for EscPosOptions {
+    /// Native model 2 QR, 6-dot modules, level `M`, merchant and amount lines, paper cut.
+    fn default() -> Self {
+        EscPosOptions {
+            mode: EscPosMode::Native,
+            model: QrModel::Model2,
+            module_size: 6,
+            ec_level: EcLevel::M,
+            print_merchant: true,
+            print_amount: true,
+            code_table: None,
+            cut: true,
+        }
+    }
+}
+
+impl PromptPayQR {
+    /// Builds the **ESC/POS** byte sequence that prints this payload as a QR code.
+    ///
+    /// Layout (centred): merchant name in bold, QR code, amount in double size.
+    /// The Thai merchant name (tag `64`) is used when `code_table` is set,
+    /// otherwise the Latin name (tag `59`).
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - Bytes to send to the printer
+    /// * `Err(PromptPayError)` - If the payload cannot be created, or `module_size` is not 1-16
+    pub fn to_escpos(&self, options: &EscPosOptions) -> Result<Vec<u8>, PromptPayError> {
+        if !(1..=16).contains(&options.module_size) {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "Module size must be between 1 and 16 dots",
+            ));
+        }
+        let payload = self.create()?;
+
+        let mut out = vec![ESC, b'@']; // initialize
+        if let Some(table) = options.code_table {
+            out.extend([ESC, b't', table]);
+        }
+        out.extend([ESC, b'a', 1]); // จัดกึ่งกลาง
+
+        if options.print_merchant {
+            let thai_name = options
+                .code_table
+                .and(self.merchant_language())
+                .map(|l| l.merchant_name.as_str());
+            if let Some(name) = thai_name.or(self.merchant_name()) {
+                out.extend([ESC, b'E', 1]);
+                out.extend(encode_text(name, options.code_table.is_some()));
+                out.extend([ESC, b'E', 0, LF]);
+            }
+        }
+
+        match options.mode {
+            EscPosMode::Native => native_qr(&mut out, &payload, options),
+            EscPosMode::Raster => raster_qr(&mut out, &payload, options)?,
+        }
+
+        if options.print_amount
+            && let Some(amount) = self.amount()
+        {
+            out.extend([GS, b'!', 0x11]); // ตัวอักษรขนาดสองเท่า
+            out.extend(format!("{} THB", format_amount(amount)).bytes());
+            out.extend([GS, b'!', 0x00, LF]);
+        }
+
+        if options.cut {
+            out.extend([GS, b'V', 66, 3]); // feed 3 แถว แล้วตัดกระดาษบางส่วน
+        }
+        Ok(out)
+    }
+}
+
+// GS ( k: เลือก model, ขนาด module, ระดับ EC, เก็บข้อมูล แล้วสั่งพิมพ์
+fn native_qr(out: &mut Vec<u8>, payload: &str, options: &EscPosOptions) {
+    let model = match options.model {
+        QrModel::Model1 => 49,
+        QrModel::Model2 => 50,
+    };
+    let ec = match options.ec_level {
+        EcLevel::L => 48,
+        EcLevel::M => 49,
+        EcLevel::Q => 50,
+        EcLevel::H => 51,
+    };
+    out.extend([GS, b'(', b'k', 4, 0, 49, 65, model, 0]);
+    out.extend([GS, b'(', b'k', 3, 0, 49, 67, options.module_size]);
+    out.extend([GS, b'(', b'k', 3, 0, 49, 69, ec]);
+
+    // ความยาว = ข้อมูล + 3 byte (cn, fn, m) แบบ little-endian
+    let [len_low, len_high] = ((payload.len() + 3) as u16).to_le_bytes();
+    out.extend([GS, b'(', b'k', len_low, len_high, 49, 80, 48]);
+    out.extend(payload.bytes());
+    out.extend([GS, b'(', b'k', 3, 0, 49, 81, 48]);
+    out.push(LF);
+}
+
+// GS v 0: ภาพ bit image 1 bit ต่อ dot (bit สูงสุด = dot ซ้ายสุด, 1 = ดำ)
+fn raster_qr(
+    out: &mut Vec<u8>,
+    payload: &str,
+    options: &EscPosOptions,
+) -> Result<(), PromptPayError> {
+    let matrix = crate::qr::QrMatrix::encode(payload, options.ec_level)?;
+    let scale = options.module_size as usize;
+    let dots = (matrix.size() + RASTER_QUIET_ZONE * 2) * scale;
+    let row_bytes = dots.div_ceil(8);
+
+    out.extend([GS, b'v', b'0', 0]);
+    out.extend((row_bytes as u16).to_le_bytes());
+    out.extend((dots as u16).to_le_bytes());
+    for py in 0..dots {
+        let mut row = vec![0u8; row_bytes];
+        for px in 0..dots {
+            let (mx, my) = (px / scale, py / scale);
+            let dark = mx >= RASTER_QUIET_ZONE
+                && my >= RASTER_QUIET_ZONE
+                && matrix.is_dark(mx - RASTER_QUIET_ZONE, my - RASTER_QUIET_ZONE);
+            if dark {
+                row[px / 8] |= 0x80 >> (px % 8);
+            }
+        }
+        out.extend(row);
+    }
+    out.push(LF);
+    Ok(())
+}
+
+// แปลงข้อความเป็น byte: ASCII ตามเดิม, ภาษาไทยเป็น TIS-620 (U+0E01-U+0E5B → 0xA1-0xFB)
+fn encode_text(text: &str, thai: bool) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x20..=0x7E => c as u8,
+            0x0E01..=0x0E5B if thai => (c as u32 - 0x0E01 + 0xA1) as u8,
+            _ => b'?',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::MerchantLanguage;
+
+    /// ทดสอบคำสั่ง GS ( k ของ QR ในตัวเครื่องพิมพ์ พร้อมชื่อร้านและยอดเงิน
+    #[test]
+    fn test_to_escpos_native() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(1250.75).set_merchant_name("COFFEE HOUSE");
+        let payload = qr.create().unwrap();
+        let options = EscPosOptions {
+            ec_level: EcLevel::Q,
+            module_size: 8,
+            ..EscPosOptions::default()
+        };
+        let bytes = qr.to_escpos(&options).unwrap();
+
+        let mut expected = vec![ESC, b'@', ESC, b'a', 1, ESC, b'E', 1];
+        expected.extend(b"COFFEE HOUSE");
+        expected.extend([ESC, b'E', 0, LF]);
+        expected.extend([GS, b'(', b'k', 4, 0, 49, 65, 50, 0]);
+        expected.extend([GS, b'(', b'k', 3, 0, 49, 67, 8]);
+        expected.extend([GS, b'(', b'k', 3, 0, 49, 69, 50]);
+        expected.extend([GS, b'(', b'k', payload.len() as u8 + 3, 0, 49, 80, 48]);
+        expected.extend(payload.bytes());
+        expected.extend([GS, b'(', b'k', 3, 0, 49, 81, 48, LF]);
+        expected.extend([GS, b'!', 0x11]);
+        expected.extend(b"1,250.75 THB");
+        expected.extend([GS, b'!', 0, LF, GS, b'V', 66, 3]);
+        assert_eq!(bytes, expected);
+    }
+
+    /// ทดสอบภาพ raster (GS v 0) และการพิมพ์ชื่อภาษาไทยด้วย code table
+    #[test]
+    fn test_to_escpos_raster_and_thai() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_merchant_language(MerchantLanguage::thai("กาแฟ"));
+        let options = EscPosOptions {
+            mode: EscPosMode::Raster,
+            module_size: 2,
+            code_table: Some(21),
+            cut: false,
+            ..EscPosOptions::default()
+        };
+        let bytes = qr.to_escpos(&options).unwrap();
+
+        assert_eq!(&bytes[..5], &[ESC, b'@', ESC, b't', 21]);
+        let name = [0xA1, 0xD2, 0xE1, 0xBF]; // "กาแฟ" ใน TIS-620
+        assert!(bytes.windows(4).any(|w| w == name));
+
+        let matrix = qr.to_qr_matrix(EcLevel::M).unwrap();
+        let dots = (matrix.size() + 8) * 2;
+        let start = bytes
+            .windows(4)
+            .position(|w| w == [GS, b'v', b'0', 0])
+            .unwrap();
+        let header = &bytes[start + 4..start + 8];
+        assert_eq!(header, [dots.div_ceil(8) as u8, 0, dots as u8, 0]);
+        // แถวแรกของ finder pattern: dot ที่ 8 ถึง 21 (quiet zone 4 module × 2 dot) เป็นสีดำ
+        let row = start + 8 + 8 * dots.div_ceil(8);
+        assert_eq!(bytes[row], 0x00);
+        assert_eq!(bytes[row + 1], 0xFF);
+        assert_eq!(bytes.len(), row + dots.div_ceil(8) * (dots - 8) + 1);
+    }
+
+    /// ทดสอบขนาด module ที่ไม่ถูกต้อง
+    #[test]
+    fn test_to_escpos_invalid_module_size() {
+        let qr = PromptPayQR::new("0812345678");
+        let options = EscPosOptions {
+            module_size: 17,
+            ..EscPosOptions::default()
+        };
+        let err = qr.to_escpos(&options).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,124 @@
+//! actix-web implementation of the extractors.
+
+use super::{
+    PromptPayRejection, QUERY_PARAM, StrictPromptPay, ValidPromptPay, payload_from_body, validate,
+};
+use crate::PromptPayQR;
+use actix_web::{
+    FromRequest, HttpRequest, HttpResponse, ResponseError,
+    dev::Payload,
+    http::{StatusCode, header},
+    web::{Bytes, Query},
+};
+use std::{collections::HashMap, future::Future, pin::Pin};
+
+type ExtractFuture<T> = Pin<Box<dyn Future<Output = Result<T, PromptPayRejection>>>>;
+
+impl FromRequest for ValidPromptPay {
+    type Error = PromptPayRejection;
+    type Future = ExtractFuture<Self>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let qr = extract(req, payload, false);
+        Box::pin(async move { qr.await.map(ValidPromptPay) })
+    }
+}
+
+impl FromRequest for StrictPromptPay {
+    type Error = PromptPayRejection;
+    type Future = ExtractFuture<Self>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let qr = extract(req, payload, true);
+        Box::pin(async move { qr.await.map(StrictPromptPay) })
+    }
+}
+
+impl ResponseError for PromptPayRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::BAD_REQUEST)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(self.to_json())
+    }
+}
+
+fn extract(req: &HttpRequest, payload: &mut Payload, strict: bool) -> ExtractFuture<PromptPayQR> {
+    let query = Query::<HashMap<String, String>>::from_query(req.query_string())
+        .map(|Query(mut query)| query.remove(QUERY_PARAM))
+        .map_err(|e| PromptPayRejection::request(400, "invalid_request", e.to_string()));
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+
+    // query parameter มาก่อน จึงไม่ต้องอ่าน body ของ GET request
+    let body = match &query {
+        Ok(None) => Some(Bytes::from_request(req, payload)),
+        _ => None,
+    };
+    Box::pin(async move {
+        let payload = match (query?, body) {
+            (Some(payload), _) => payload,
+            (None, Some(body)) => {
+                let body = body.await.map_err(|e| {
+                    PromptPayRejection::request(400, "invalid_request", e.to_string())
+                })?;
+                payload_from_body(content_type.as_deref(), &body)?
+            }
+            (None, None) => unreachable!("body is read when the query has no payload"),
+        };
+        validate(&payload, strict)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{App, test, web};
+    use serde_json::Value;
+
+    /// ทดสอบ extractor ใน actix-web: query, JSON body และ error response
+    #[actix_web::test]
+    async fn test_actix_extractor() {
+        async fn pay(ValidPromptPay(qr): ValidPromptPay) -> String {
+            qr.merchant_id().to_string()
+        }
+        let app = test::init_service(App::new().route("/pay", web::to(pay))).await;
+        let payload = PromptPayQR::new("0812345678").create().unwrap();
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/pay?payload={}", payload))
+            .to_request();
+        let body = test::call_and_read_body(&app, request).await;
+        assert_eq!(body, "0812345678");
+
+        let request = test::TestRequest::post()
+            .uri("/pay")
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .set_payload(format!(r#"{{"payload": "{}"}}"#, payload))
+            .to_request();
+        assert_eq!(
+            test::call_service(&app, request).await.status(),
+            StatusCode::OK
+        );
+
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        let request = test::TestRequest::post()
+            .uri("/pay")
+            .set_payload(tampered)
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["error"]["kind"], "crc_mismatch");
+
+        let request = test::TestRequest::post().uri("/pay").to_request();
+        assert_eq!(
+            test::call_service(&app, request).await.status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+}
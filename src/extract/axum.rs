@@ -0,0 +1,117 @@
+//! axum implementation of the extractors.
+
+use super::{
+    MAX_BODY_LENGTH, PromptPayRejection, QUERY_PARAM, StrictPromptPay, ValidPromptPay,
+    payload_from_body, validate,
+};
+use crate::PromptPayQR;
+use ::axum::{
+    Json,
+    body::to_bytes,
+    extract::{FromRequest, Query, Request},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+
+impl<S: Send + Sync> FromRequest<S> for ValidPromptPay {
+    type Rejection = PromptPayRejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        extract(req, false).await.map(ValidPromptPay)
+    }
+}
+
+impl<S: Send + Sync> FromRequest<S> for StrictPromptPay {
+    type Rejection = PromptPayRejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        extract(req, true).await.map(StrictPromptPay)
+    }
+}
+
+impl IntoResponse for PromptPayRejection {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::BAD_REQUEST);
+        (status, Json(self.to_json())).into_response()
+    }
+}
+
+async fn extract(req: Request, strict: bool) -> Result<PromptPayQR, PromptPayRejection> {
+    let (parts, body) = req.into_parts();
+    let Query(mut query) = Query::<HashMap<String, String>>::try_from_uri(&parts.uri)
+        .map_err(|e| PromptPayRejection::request(400, "invalid_request", e.body_text()))?;
+
+    // query parameter มาก่อน จึงไม่ต้องอ่าน body ของ GET request
+    let payload = match query.remove(QUERY_PARAM) {
+        Some(payload) => payload,
+        None => {
+            let body = to_bytes(body, MAX_BODY_LENGTH)
+                .await
+                .map_err(|_| PromptPayRejection::too_large())?;
+            let content_type = parts
+                .headers
+                .get(header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok());
+            payload_from_body(content_type, &body)?
+        }
+    };
+    validate(&payload, strict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::axum::{Router, body::Body, routing::post};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    /// ทดสอบ extractor ใน axum: query, JSON body, ข้อความ และ error response
+    #[tokio::test]
+    async fn test_axum_extractor() {
+        async fn pay(ValidPromptPay(qr): ValidPromptPay) -> String {
+            qr.merchant_id().to_string()
+        }
+        async fn strict(StrictPromptPay(qr): StrictPromptPay) -> String {
+            qr.merchant_id().to_string()
+        }
+        let app = Router::new()
+            .route("/pay", post(pay).get(pay))
+            .route("/strict", post(strict));
+        let send = |request: Request| async {
+            let response = app.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        };
+        let payload = PromptPayQR::new("0812345678").create().unwrap();
+
+        let uri = format!("/pay?payload={}", payload);
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        assert_eq!(
+            send(request).await,
+            (StatusCode::OK, "0812345678".to_string())
+        );
+
+        let request = Request::post("/strict")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(format!(r#"{{"payload": "{}"}}"#, payload)))
+            .unwrap();
+        assert_eq!(send(request).await.0, StatusCode::OK);
+
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        let request = Request::post("/pay").body(Body::from(tampered)).unwrap();
+        let (status, body) = send(request).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let body: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["error"]["kind"], "crc_mismatch");
+        assert_eq!(body["error"]["issues"][0]["tag"], "63");
+
+        let request = Request::post("/pay").body(Body::empty()).unwrap();
+        assert_eq!(send(request).await.0, StatusCode::BAD_REQUEST);
+        let request = Request::post("/pay")
+            .body(Body::from(vec![b'0'; MAX_BODY_LENGTH * 2]))
+            .unwrap();
+        assert_eq!(send(request).await.0, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -0,0 +1,306 @@
+//! **Request extractors** that only hand validated payloads to web handlers
+//! (requires the `axum` or `actix-web` feature).
+//!
+//! [`ValidPromptPay`] reads a payload from the `payload` query parameter, a
+//! JSON body (`{"payload": "..."}`) or a plain-text body, then parses,
+//! CRC-checks and [lints](crate::lint) it. Requests with a missing, malformed
+//! or erroneous payload are rejected before the handler runs, with a
+//! [`PromptPayRejection`] rendered as:
+//!
+//! ```json
+//! {"error": {"kind": "crc_mismatch", "message": "...", "issues": [
+//!     {"severity": "error", "tag": "63", "message": "CRC is 0000, expected 1D3A"}
+//! ]}}
+//! ```
+//!
+//! [`StrictPromptPay`] additionally rejects payloads with lint warnings.
+//!
+//! # Example
+//! ```rust
+//! # #[cfg(feature = "axum")] {
+//! use promptpay_rs::extract::ValidPromptPay;
+//!
+//! // axum
+//! async fn pay(ValidPromptPay(qr): ValidPromptPay) -> String {
+//!     format!("{:?} THB to {}", qr.amount(), qr.merchant_id())
+//! }
+//!
+//! let app: axum::Router = axum::Router::new().route("/pay", axum::routing::post(pay));
+//! # }
+//! # #[cfg(feature = "actix-web")] {
+//! use promptpay_rs::extract::StrictPromptPay;
+//!
+//! // actix-web
+//! async fn pay_strict(StrictPromptPay(qr): StrictPromptPay) -> String {
+//!     qr.merchant_id().to_string()
+//! }
+//!
+//! let app = actix_web::App::new().route("/pay", actix_web::web::post().to(pay_strict));
+//! # }
+//! ```
+
+#[cfg(feature = "actix-web")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
+
+use crate::{
+    PromptPayQR,
+    error::ErrorKind,
+    lint::{LintIssue, lint},
+};
+use serde_json::{Value, json};
+use std::fmt;
+
+/// Name of the query parameter the extractors read the payload from.
+pub const QUERY_PARAM: &str = "payload";
+
+/// Largest request body the extractors read, in bytes.
+pub const MAX_BODY_LENGTH: usize = 4096;
+
+/// A payload that parsed, passed the CRC check and has no lint errors.
+///
+/// Lint warnings are tolerated; use [`StrictPromptPay`] to reject them too.
+#[derive(Debug, Clone)]
+pub struct ValidPromptPay(pub PromptPayQR);
+
+/// A payload that parsed, passed the CRC check and has no lint issues at all.
+#[derive(Debug, Clone)]
+pub struct StrictPromptPay(pub PromptPayQR);
+
+/// Error response of [`ValidPromptPay`] and [`StrictPromptPay`].
+///
+/// Rendered as a JSON body `{"error": {"kind", "message", "issues"}}` with the
+/// [status](PromptPayRejection::status) code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptPayRejection {
+    status: u16,            // HTTP status code
+    kind: &'static str,     // ชื่อประเภทข้อผิดพลาดใน JSON
+    message: String,        // ข้อความอธิบายข้อผิดพลาด
+    issues: Vec<LintIssue>, // ผลจาก lint (ว่างถ้าไม่ได้ตรวจ payload)
+}
+
+impl PromptPayRejection {
+    fn too_large() -> Self {
+        PromptPayRejection::request(
+            413,
+            "payload_too_large",
+            format!("Request body must not exceed {} bytes", MAX_BODY_LENGTH),
+        )
+    }
+
+    fn request(status: u16, kind: &'static str, message: String) -> Self {
+        PromptPayRejection {
+            status,
+            kind,
+            message,
+            issues: Vec::new(),
+        }
+    }
+
+    /// HTTP status code: `400` for a missing or malformed request, `413` for an
+    /// over-long body and `422` for a payload that fails validation.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Machine-readable error kind, e.g. `"missing_payload"`, `"crc_mismatch"` or `"lint"`.
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    /// Human-readable description.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Lint issues that caused the rejection, errors first.
+    pub fn issues(&self) -> &[LintIssue] {
+        &self.issues
+    }
+
+    /// Returns the JSON response body.
+    pub fn to_json(&self) -> Value {
+        let issues: Vec<Value> = self
+            .issues
+            .iter()
+            .map(|issue| {
+                json!({
+                    "severity": issue.severity.to_string(),
+                    "tag": issue.tag,
+                    "message": issue.message,
+                })
+            })
+            .collect();
+        json!({ "error": { "kind": self.kind, "message": self.message, "issues": issues } })
+    }
+}
+
+impl fmt::Display for PromptPayRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for PromptPayRejection {}
+
+/// Parses, CRC-checks and lints a payload the way the extractors do.
+///
+/// Useful for frameworks without a built-in extractor, or for payloads nested
+/// in a larger request body.
+///
+/// # Arguments
+/// * `payload` - Payload text (surrounding whitespace is ignored)
+/// * `strict` - Reject lint warnings as well as errors
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{PromptPayQR, extract::validate};
+///
+/// let payload = PromptPayQR::new("0812345678").create().unwrap();
+/// assert!(validate(&payload, true).is_ok());
+///
+/// let rejection = validate("000201", false).unwrap_err();
+/// assert_eq!(rejection.status(), 422);
+/// ```
+pub fn validate(payload: &str, strict: bool) -> Result<PromptPayQR, PromptPayRejection> {
+    let payload = payload.trim();
+    if payload.is_empty() {
+        return Err(PromptPayRejection::request(
+            400,
+            "missing_payload",
+            "Payload is empty".to_string(),
+        ));
+    }
+
+    let issues = lint(payload);
+    let qr = match PromptPayQR::parse(payload) {
+        Ok(qr) => qr,
+        // ใช้ข้อความจาก parse เพราะเป็นสาเหตุที่ทำให้ lint แจ้ง error
+        Err(e) => {
+            let kind = match e.kind() {
+                ErrorKind::CrcMismatch => "crc_mismatch",
+                _ => "invalid_payload",
+            };
+            return Err(PromptPayRejection {
+                status: 422,
+                kind,
+                message: e.to_string(),
+                issues,
+            });
+        }
+    };
+    match issues.first() {
+        Some(issue) if strict || issue.is_error() => Err(PromptPayRejection {
+            status: 422,
+            kind: "lint",
+            message: issue.to_string(),
+            issues,
+        }),
+        _ => Ok(qr),
+    }
+}
+
+// อ่าน payload จาก body แบบ JSON ({"payload": ...}) หรือข้อความล้วน
+fn payload_from_body(
+    content_type: Option<&str>,
+    body: &[u8],
+) -> Result<String, PromptPayRejection> {
+    let invalid = |message: String| PromptPayRejection::request(400, "invalid_request", message);
+    if body.len() > MAX_BODY_LENGTH {
+        return Err(PromptPayRejection::too_large());
+    }
+
+    let media_type = content_type
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase());
+    let is_json = media_type
+        .as_deref()
+        .is_some_and(|t| t == "application/json" || t.ends_with("+json"));
+    let payload = if is_json {
+        let value: Value = serde_json::from_slice(body)
+            .map_err(|e| invalid(format!("Request body is not valid JSON: {}", e)))?;
+        match value.get(QUERY_PARAM) {
+            Some(Value::String(payload)) => payload.clone(),
+            Some(_) => return Err(invalid("\"payload\" must be a string".to_string())),
+            None => String::new(),
+        }
+    } else {
+        String::from_utf8(body.to_vec())
+            .map_err(|_| invalid("Request body is not valid UTF-8".to_string()))?
+    };
+
+    if payload.trim().is_empty() {
+        return Err(PromptPayRejection::request(
+            400,
+            "missing_payload",
+            format!(
+                "Send the payload in the \"{}\" query parameter, a JSON body or a text body",
+                QUERY_PARAM
+            ),
+        ));
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tlv;
+
+    /// ทดสอบ validate: payload ถูกต้อง, CRC ผิด, คำเตือนในโหมด strict
+    #[test]
+    fn test_validate() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(10.0);
+        let payload = qr.create().unwrap();
+        let valid = validate(&format!(" {}\n", payload), false).unwrap();
+        assert_eq!(valid.amount(), Some(10.0));
+
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        let rejection = validate(&tampered, false).unwrap_err();
+        assert_eq!(rejection.status(), 422);
+        assert_eq!(rejection.kind(), "crc_mismatch");
+        assert_eq!(rejection.issues()[0].tag.as_deref(), Some("63"));
+        let body = rejection.to_json();
+        assert_eq!(body["error"]["issues"][0]["severity"], "error");
+
+        // static QR (11) ที่มีจำนวนเงิน: parse ได้แต่ lint เตือน
+        let account = tlv::encode("29", "0016A00000067701011101130066812345678").unwrap();
+        let data = format!("000201010211{}53037645406100.005802TH6304", account);
+        let payload = format!("{}{:04X}", data, crate::crc::calculate_crc(&data));
+        assert!(validate(&payload, false).is_ok());
+        let rejection = validate(&payload, true).unwrap_err();
+        assert_eq!(rejection.kind(), "lint");
+        assert!(rejection.message().starts_with("warning (tag 01)"));
+
+        assert_eq!(validate("  ", false).unwrap_err().status(), 400);
+    }
+
+    /// ทดสอบการอ่าน payload จาก body แบบ JSON และข้อความ
+    #[test]
+    fn test_payload_from_body() {
+        let json = Some("application/json; charset=utf-8");
+        assert_eq!(
+            payload_from_body(json, br#"{"payload": "000201"}"#).unwrap(),
+            "000201"
+        );
+        assert_eq!(
+            payload_from_body(Some("text/plain"), b"000201").unwrap(),
+            "000201"
+        );
+        assert_eq!(payload_from_body(None, b"000201").unwrap(), "000201");
+
+        let rejection = payload_from_body(json, b"{").unwrap_err();
+        assert_eq!(
+            (rejection.status(), rejection.kind()),
+            (400, "invalid_request")
+        );
+        let rejection = payload_from_body(json, br#"{"payload": 5}"#).unwrap_err();
+        assert_eq!(rejection.kind(), "invalid_request");
+        let rejection = payload_from_body(json, b"{}").unwrap_err();
+        assert_eq!(rejection.kind(), "missing_payload");
+        let rejection = payload_from_body(None, &[b'0'; MAX_BODY_LENGTH + 1]).unwrap_err();
+        assert_eq!(rejection.status(), 413);
+    }
+}
@@ -0,0 +1,714 @@
+//! **C API** for point-of-sale software written in C (requires the `ffi` feature).
+//!
+//! The crate builds as an `rlib` by default; build the static
+//! (`libpromptpay_rs.a`) and dynamic (`libpromptpay_rs.so` / `.dylib` / `.dll`)
+//! libraries explicitly:
+//!
+//! ```sh
+//! cargo rustc --lib --release --features ffi --crate-type staticlib,cdylib
+//! ```
+//!
+//! The header `include/promptpay.h` is generated by cbindgen into `OUT_DIR`
+//! during the build; a test fails when the committed copy is out of date.
+//!
+//! Conventions:
+//! - `PromptPayQR` is an opaque handle from `promptpay_new`, `promptpay_bill_payment`
+//!   or `promptpay_parse`, released with `promptpay_free`.
+//! - Functions return a [`PromptPayStatus`]; on failure `promptpay_last_error`
+//!   returns a message for the calling thread.
+//! - Strings are NUL-terminated UTF-8. Output strings are written to a
+//!   caller-provided buffer: the length without the NUL is stored in `out_len`,
+//!   and `PROMPT_PAY_STATUS_BUFFER_TOO_SMALL` is returned if the buffer cannot hold it,
+//!   so passing `NULL` / `0` queries the required size.
+//! - A panic inside the library never unwinds into C: the function returns
+//!   `PROMPT_PAY_STATUS_OTHER` (or `NULL` / `-1`) and sets `promptpay_last_error`.
+//!
+//! ```c
+//! #include "promptpay.h"
+//!
+//! PromptPayQR *qr = promptpay_new("0812345678");
+//! promptpay_set_amount(qr, 150.0);
+//! char payload[513];
+//! size_t len;
+//! if (promptpay_create(qr, payload, sizeof payload, &len) != PROMPT_PAY_STATUS_OK) {
+//!     fprintf(stderr, "%s\n", promptpay_last_error());
+//! }
+//! promptpay_free(qr);
+//! ```
+
+use crate::{
+    PromptPayError, PromptPayQR, constants::MerchantType, error::ErrorKind,
+    language::MerchantLanguage,
+};
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString, c_char},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+/// Opaque handle to a payload, `PromptPayQR` in C.
+pub struct FfiPromptPayQR(PromptPayQR);
+
+/// Result code of the C API functions.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptPayStatus {
+    /// Success
+    Ok = 0,
+    /// A required pointer argument is `NULL`
+    NullPointer = 1,
+    /// A string argument is not valid UTF-8
+    InvalidUtf8 = 2,
+    /// Input supplied by the caller is missing or malformed
+    InvalidInput = 3,
+    /// The payload could not be decoded
+    InvalidPayload = 4,
+    /// The payload CRC does not match its content
+    CrcMismatch = 5,
+    /// The output buffer is too small, `out_len` holds the required length
+    BufferTooSmall = 6,
+    /// The requested field is not present in the payload
+    NotFound = 7,
+    /// Any other error
+    Other = 99,
+}
+
+/// Kind of proxy a payload pays to.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptPayMerchantType {
+    MobileNumber = 0,
+    TaxId = 1,
+    EWalletId = 2,
+    BillerId = 3,
+}
+
+/// Text field read with `promptpay_get_field`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptPayField {
+    /// Proxy (mobile number, tax ID, e-wallet ID or biller ID)
+    MerchantId = 0,
+    /// Merchant name (tag 59)
+    MerchantName = 1,
+    /// Merchant city (tag 60)
+    MerchantCity = 2,
+    /// Ref1 of a bill payment QR
+    Reference1 = 3,
+    /// Ref2 of a bill payment QR
+    Reference2 = 4,
+    /// Country code, `"TH"`
+    CountryCode = 5,
+    /// Numeric currency code, `"764"`
+    CurrencyCode = 6,
+}
+
+/// Additional data label (tag 62) set with `promptpay_set_label`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptPayLabel {
+    /// Bill number (62-01)
+    BillNumber = 1,
+    /// Store label (62-03)
+    StoreLabel = 3,
+    /// Reference label (62-05)
+    ReferenceLabel = 5,
+    /// Customer label (62-06)
+    CustomerLabel = 6,
+    /// Terminal label (62-07)
+    TerminalLabel = 7,
+}
+
+thread_local! {
+    // ข้อความของข้อผิดพลาดล่าสุดใน thread นี้ (สำหรับ promptpay_last_error)
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+fn fail(status: PromptPayStatus, message: &str) -> PromptPayStatus {
+    // ตัด NUL ออกเพราะ CString ใส่ไม่ได้
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+    status
+}
+
+fn fail_with(e: PromptPayError) -> PromptPayStatus {
+    let status = match e.kind() {
+        ErrorKind::InvalidInput => PromptPayStatus::InvalidInput,
+        ErrorKind::InvalidPayload => PromptPayStatus::InvalidPayload,
+        ErrorKind::CrcMismatch => PromptPayStatus::CrcMismatch,
+        _ => PromptPayStatus::Other,
+    };
+    fail(status, &e.to_string())
+}
+
+// อ่าน string จาก C (NULL หรือ UTF-8 ไม่ถูกต้องคืนเป็น status)
+unsafe fn read_str<'a>(value: *const c_char, name: &str) -> Result<&'a str, PromptPayStatus> {
+    if value.is_null() {
+        return Err(fail(
+            PromptPayStatus::NullPointer,
+            &format!("{} is NULL", name),
+        ));
+    }
+    // SAFETY: ผู้เรียกรับประกันว่าเป็น string ที่ปิดท้ายด้วย NUL
+    unsafe { CStr::from_ptr(value) }.to_str().map_err(|_| {
+        fail(
+            PromptPayStatus::InvalidUtf8,
+            &format!("{} is not valid UTF-8", name),
+        )
+    })
+}
+
+// เขียน string ลง buffer ของผู้เรียก พร้อม NUL ปิดท้าย
+unsafe fn write_str(
+    value: &str,
+    buf: *mut c_char,
+    buf_len: usize,
+    out_len: *mut usize,
+) -> PromptPayStatus {
+    if !out_len.is_null() {
+        // SAFETY: ผู้เรียกรับประกันว่า out_len ชี้ไปยัง size_t ที่เขียนได้
+        unsafe { *out_len = value.len() };
+    }
+    if buf.is_null() || buf_len <= value.len() {
+        if !buf.is_null() && buf_len > 0 {
+            // SAFETY: buf มีอย่างน้อย 1 byte
+            unsafe { *buf = 0 };
+        }
+        return fail(
+            PromptPayStatus::BufferTooSmall,
+            &format!("Buffer must hold {} bytes", value.len() + 1),
+        );
+    }
+    // SAFETY: buf มีขนาด buf_len > value.len() byte
+    unsafe {
+        ptr::copy_nonoverlapping(value.as_ptr(), buf.cast::<u8>(), value.len());
+        *buf.add(value.len()) = 0;
+    }
+    PromptPayStatus::Ok
+}
+
+// แปลง handle เป็น reference (NULL คืนเป็น status)
+unsafe fn handle<'a>(qr: *mut FfiPromptPayQR) -> Result<&'a mut PromptPayQR, PromptPayStatus> {
+    // SAFETY: ผู้เรียกรับประกันว่า qr มาจาก promptpay_new / promptpay_parse และยังไม่ถูก free
+    match unsafe { qr.as_mut() } {
+        Some(qr) => Ok(&mut qr.0),
+        None => Err(fail(PromptPayStatus::NullPointer, "qr is NULL")),
+    }
+}
+
+// เรียก body โดยดักจับ panic ไว้ เพราะ panic ที่ข้ามขอบเขต C จะ abort ทั้งโปรแกรม
+fn catch_panic<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown panic");
+        fail(
+            PromptPayStatus::Other,
+            &format!("Internal error: {}", message),
+        );
+        on_panic
+    })
+}
+
+macro_rules! try_status {
+    ($e:expr) => {
+        match $e {
+            Ok(value) => value,
+            Err(status) => return status,
+        }
+    };
+}
+
+/// Returns the library version, e.g. `"0.6.0"`.
+#[unsafe(no_mangle)]
+pub extern "C" fn promptpay_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
+}
+
+/// Returns the message of the last failed call on this thread (empty if none).
+///
+/// The pointer stays valid until the next failing call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn promptpay_last_error() -> *const c_char {
+    catch_panic(ptr::null(), || {
+        LAST_ERROR.with(|last| last.borrow().as_ptr())
+    })
+}
+
+/// Creates a credit transfer QR for a mobile number, national / tax ID or e-wallet ID.
+///
+/// Returns `NULL` if `proxy` is `NULL` or not UTF-8. The proxy itself is
+/// validated by `promptpay_create`.
+///
+/// # Safety
+/// `proxy` must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_new(proxy: *const c_char) -> *mut FfiPromptPayQR {
+    catch_panic(ptr::null_mut(), || {
+        match unsafe { read_str(proxy, "proxy") } {
+            Ok(proxy) => Box::into_raw(Box::new(FfiPromptPayQR(PromptPayQR::new(proxy)))),
+            Err(_) => ptr::null_mut(),
+        }
+    })
+}
+
+/// Creates a bill payment QR (tag 30). `ref2` may be `NULL`.
+///
+/// Returns `NULL` if `biller_id` or `ref1` is `NULL`, or a string is not UTF-8.
+///
+/// # Safety
+/// Each argument must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_bill_payment(
+    biller_id: *const c_char,
+    ref1: *const c_char,
+    ref2: *const c_char,
+) -> *mut FfiPromptPayQR {
+    catch_panic(ptr::null_mut(), || {
+        let strings = unsafe {
+            let ref2 = match ref2.is_null() {
+                true => Ok(None),
+                false => read_str(ref2, "ref2").map(Some),
+            };
+            (
+                read_str(biller_id, "biller_id"),
+                read_str(ref1, "ref1"),
+                ref2,
+            )
+        };
+        match strings {
+            (Ok(biller_id), Ok(ref1), Ok(ref2)) => Box::into_raw(Box::new(FfiPromptPayQR(
+                PromptPayQR::bill_payment(biller_id, ref1, ref2),
+            ))),
+            _ => ptr::null_mut(),
+        }
+    })
+}
+
+/// Decodes and CRC-checks a payload into a new handle stored in `out`.
+///
+/// # Safety
+/// `payload` must be `NULL` or a NUL-terminated string, `out` must be `NULL`
+/// or point to writable storage for a pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_parse(
+    payload: *const c_char,
+    out: *mut *mut FfiPromptPayQR,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        if out.is_null() {
+            return fail(PromptPayStatus::NullPointer, "out is NULL");
+        }
+        let payload = try_status!(unsafe { read_str(payload, "payload") });
+        match PromptPayQR::parse(payload) {
+            Ok(qr) => {
+                unsafe { *out = Box::into_raw(Box::new(FfiPromptPayQR(qr))) };
+                PromptPayStatus::Ok
+            }
+            Err(e) => {
+                unsafe { *out = ptr::null_mut() };
+                fail_with(e)
+            }
+        }
+    })
+}
+
+/// Checks a payload without keeping the decoded handle.
+///
+/// # Safety
+/// `payload` must be `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_verify(payload: *const c_char) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let payload = try_status!(unsafe { read_str(payload, "payload") });
+        match PromptPayQR::parse(payload) {
+            Ok(_) => PromptPayStatus::Ok,
+            Err(e) => fail_with(e),
+        }
+    })
+}
+
+/// Releases a handle. Passing `NULL` does nothing.
+///
+/// # Safety
+/// `qr` must be `NULL` or a handle that has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_free(qr: *mut FfiPromptPayQR) {
+    catch_panic((), || {
+        if !qr.is_null() {
+            drop(unsafe { Box::from_raw(qr) });
+        }
+    })
+}
+
+/// Sets the amount in THB, making the QR dynamic.
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_set_amount(
+    qr: *mut FfiPromptPayQR,
+    amount: f64,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let qr = try_status!(unsafe { handle(qr) });
+        if !amount.is_finite() || amount < 0.0 {
+            return fail(
+                PromptPayStatus::InvalidInput,
+                "Amount must be a non-negative number",
+            );
+        }
+        qr.set_amount(amount);
+        PromptPayStatus::Ok
+    })
+}
+
+/// Sets the merchant name in Latin script (tag 59).
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle, `name` `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_set_merchant_name(
+    qr: *mut FfiPromptPayQR,
+    name: *const c_char,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let qr = try_status!(unsafe { handle(qr) });
+        qr.set_merchant_name(try_status!(unsafe { read_str(name, "name") }));
+        PromptPayStatus::Ok
+    })
+}
+
+/// Sets the merchant city in Latin script (tag 60).
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle, `city` `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_set_merchant_city(
+    qr: *mut FfiPromptPayQR,
+    city: *const c_char,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let qr = try_status!(unsafe { handle(qr) });
+        qr.set_merchant_city(try_status!(unsafe { read_str(city, "city") }));
+        PromptPayStatus::Ok
+    })
+}
+
+/// Sets the merchant name in Thai (tag 64).
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle, `name` `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_set_thai_name(
+    qr: *mut FfiPromptPayQR,
+    name: *const c_char,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let qr = try_status!(unsafe { handle(qr) });
+        qr.set_merchant_language(MerchantLanguage::thai(try_status!(unsafe {
+            read_str(name, "name")
+        })));
+        PromptPayStatus::Ok
+    })
+}
+
+/// Sets an additional data label (tag 62), keeping the other labels.
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle, `value` `NULL` or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_set_label(
+    qr: *mut FfiPromptPayQR,
+    label: PromptPayLabel,
+    value: *const c_char,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let qr = try_status!(unsafe { handle(qr) });
+        let value = Some(try_status!(unsafe { read_str(value, "value") }).to_string());
+        let mut data = qr.additional_data().cloned().unwrap_or_default();
+        match label {
+            PromptPayLabel::BillNumber => data.bill_number = value,
+            PromptPayLabel::StoreLabel => data.store_label = value,
+            PromptPayLabel::ReferenceLabel => data.reference_label = value,
+            PromptPayLabel::CustomerLabel => data.customer_label = value,
+            PromptPayLabel::TerminalLabel => data.terminal_label = value,
+        }
+        qr.set_additional_data(data);
+        PromptPayStatus::Ok
+    })
+}
+
+/// Builds the payload into `buf` (at most 512 characters plus the NUL).
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle, `buf` `NULL` or writable for `buf_len`
+/// bytes, `out_len` `NULL` or writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_create(
+    qr: *const FfiPromptPayQR,
+    buf: *mut c_char,
+    buf_len: usize,
+    out_len: *mut usize,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let qr = try_status!(unsafe { handle(qr.cast_mut()) });
+        match qr.create() {
+            Ok(payload) => unsafe { write_str(&payload, buf, buf_len, out_len) },
+            Err(e) => fail_with(e),
+        }
+    })
+}
+
+/// Returns the kind of proxy, or `-1` if `qr` is `NULL`.
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_get_merchant_type(qr: *const FfiPromptPayQR) -> i32 {
+    catch_panic(-1, || {
+        let Ok(qr) = (unsafe { handle(qr.cast_mut()) }) else {
+            return -1;
+        };
+        let merchant_type = match qr.merchant_type() {
+            MerchantType::MobileNumber => PromptPayMerchantType::MobileNumber,
+            MerchantType::TaxId => PromptPayMerchantType::TaxId,
+            MerchantType::EWalletId => PromptPayMerchantType::EWalletId,
+            MerchantType::BillerId => PromptPayMerchantType::BillerId,
+        };
+        merchant_type as i32
+    })
+}
+
+/// Stores the amount in `out`, or returns `PROMPT_PAY_STATUS_NOT_FOUND` for a static QR.
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle, `out` `NULL` or writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_get_amount(
+    qr: *const FfiPromptPayQR,
+    out: *mut f64,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let qr = try_status!(unsafe { handle(qr.cast_mut()) });
+        if out.is_null() {
+            return fail(PromptPayStatus::NullPointer, "out is NULL");
+        }
+        match qr.amount() {
+            Some(amount) => {
+                unsafe { *out = amount };
+                PromptPayStatus::Ok
+            }
+            None => fail(PromptPayStatus::NotFound, "QR has no amount"),
+        }
+    })
+}
+
+/// Writes a text field into `buf`, or returns `PROMPT_PAY_STATUS_NOT_FOUND` if absent.
+///
+/// # Safety
+/// `qr` must be `NULL` or a live handle, `buf` `NULL` or writable for `buf_len`
+/// bytes, `out_len` `NULL` or writable.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn promptpay_get_field(
+    qr: *const FfiPromptPayQR,
+    field: PromptPayField,
+    buf: *mut c_char,
+    buf_len: usize,
+    out_len: *mut usize,
+) -> PromptPayStatus {
+    catch_panic(PromptPayStatus::Other, || {
+        let qr = try_status!(unsafe { handle(qr.cast_mut()) });
+        let value = match field {
+            PromptPayField::MerchantId => Some(qr.merchant_id()),
+            PromptPayField::MerchantName => qr.merchant_name(),
+            PromptPayField::MerchantCity => qr.merchant_city(),
+            PromptPayField::Reference1 => qr.reference1(),
+            PromptPayField::Reference2 => qr.reference2(),
+            PromptPayField::CountryCode => Some(qr.country_code().as_str()),
+            PromptPayField::CurrencyCode => Some(qr.currency_code().numeric_code()),
+        };
+        match value {
+            Some(value) => unsafe { write_str(value, buf, buf_len, out_len) },
+            None => fail(
+                PromptPayStatus::NotFound,
+                &format!("{:?} is not set", field),
+            ),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::additional_data::AdditionalData;
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(promptpay_last_error()) }
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// ทดสอบการสร้าง payload ผ่าน C API และการใช้ buffer ของผู้เรียก
+    #[test]
+    fn test_ffi_create() {
+        let proxy = CString::new("0812345678").unwrap();
+        let name = CString::new("COFFEE HOUSE").unwrap();
+        let table = CString::new("TABLE-07").unwrap();
+        unsafe {
+            let qr = promptpay_new(proxy.as_ptr());
+            assert!(!qr.is_null());
+            assert_eq!(promptpay_set_amount(qr, 150.0), PromptPayStatus::Ok);
+            assert_eq!(
+                promptpay_set_merchant_name(qr, name.as_ptr()),
+                PromptPayStatus::Ok
+            );
+            let status = promptpay_set_label(qr, PromptPayLabel::TerminalLabel, table.as_ptr());
+            assert_eq!(status, PromptPayStatus::Ok);
+
+            let mut expected = PromptPayQR::new("0812345678");
+            expected.set_amount(150.0).set_merchant_name("COFFEE HOUSE");
+            expected.set_additional_data(AdditionalData {
+                terminal_label: Some("TABLE-07".to_string()),
+                ..AdditionalData::default()
+            });
+            let expected = expected.create().unwrap();
+
+            // ถามขนาดก่อน แล้วจึงเขียนลง buffer
+            let mut len = 0;
+            let status = promptpay_create(qr, ptr::null_mut(), 0, &mut len);
+            assert_eq!(status, PromptPayStatus::BufferTooSmall);
+            assert_eq!(len, expected.len());
+            let mut small = [1 as c_char; 8];
+            let status = promptpay_create(qr, small.as_mut_ptr(), small.len(), &mut len);
+            assert_eq!(status, PromptPayStatus::BufferTooSmall);
+            assert_eq!(small[0], 0);
+
+            let mut buf = vec![0 as c_char; len + 1];
+            let status = promptpay_create(qr, buf.as_mut_ptr(), buf.len(), &mut len);
+            assert_eq!(status, PromptPayStatus::Ok);
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str().unwrap(), expected);
+            promptpay_free(qr);
+
+            let empty = CString::new("").unwrap();
+            let qr = promptpay_new(empty.as_ptr());
+            let status = promptpay_create(qr, buf.as_mut_ptr(), buf.len(), &mut len);
+            assert_eq!(status, PromptPayStatus::InvalidInput);
+            assert!(!last_error().is_empty());
+            assert_eq!(
+                promptpay_set_amount(qr, -1.0),
+                PromptPayStatus::InvalidInput
+            );
+            promptpay_free(qr);
+
+            assert!(promptpay_new(ptr::null()).is_null());
+            assert_eq!(
+                promptpay_set_amount(ptr::null_mut(), 1.0),
+                PromptPayStatus::NullPointer
+            );
+            assert_eq!(last_error(), "qr is NULL");
+            promptpay_free(ptr::null_mut());
+        }
+    }
+
+    /// ทดสอบการ decode ผ่าน C API: ฟิลด์, จำนวนเงิน และรหัสข้อผิดพลาด
+    #[test]
+    fn test_ffi_parse() {
+        let payload = PromptPayQR::bill_payment("099400016550100", "INV001", Some("A1"))
+            .set_amount(99.5)
+            .create()
+            .unwrap();
+        let c_payload = CString::new(payload.clone()).unwrap();
+        unsafe {
+            assert_eq!(promptpay_verify(c_payload.as_ptr()), PromptPayStatus::Ok);
+            let mut qr = ptr::null_mut();
+            assert_eq!(
+                promptpay_parse(c_payload.as_ptr(), &mut qr),
+                PromptPayStatus::Ok
+            );
+            assert_eq!(
+                promptpay_get_merchant_type(qr),
+                PromptPayMerchantType::BillerId as i32
+            );
+            let mut amount = 0.0;
+            assert_eq!(promptpay_get_amount(qr, &mut amount), PromptPayStatus::Ok);
+            assert_eq!(amount, 99.5);
+
+            let mut buf = [0 as c_char; 32];
+            let mut len = 0;
+            let field = |field, buf: &mut [c_char], len: &mut usize| {
+                promptpay_get_field(qr, field, buf.as_mut_ptr(), buf.len(), len)
+            };
+            assert_eq!(
+                field(PromptPayField::Reference1, &mut buf, &mut len),
+                PromptPayStatus::Ok
+            );
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str().unwrap(), "INV001");
+            assert_eq!(len, 6);
+            field(PromptPayField::CurrencyCode, &mut buf, &mut len);
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str().unwrap(), "764");
+            let status = field(PromptPayField::MerchantName, &mut buf, &mut len);
+            assert_eq!(status, PromptPayStatus::NotFound);
+            promptpay_free(qr);
+
+            let tampered = CString::new(format!("{}0000", &payload[..payload.len() - 4])).unwrap();
+            assert_eq!(
+                promptpay_verify(tampered.as_ptr()),
+                PromptPayStatus::CrcMismatch
+            );
+            let mut qr = ptr::null_mut();
+            assert_eq!(
+                promptpay_parse(tampered.as_ptr(), &mut qr),
+                PromptPayStatus::CrcMismatch
+            );
+            assert!(qr.is_null());
+
+            // payload ที่ลงท้ายด้วยตัวอักษรหลาย byte ต้องได้ status ไม่ใช่ abort
+            let thai = CString::new("0000กก").unwrap();
+            assert_eq!(
+                promptpay_verify(thai.as_ptr()),
+                PromptPayStatus::CrcMismatch
+            );
+            assert_eq!(
+                promptpay_parse(thai.as_ptr(), &mut qr),
+                PromptPayStatus::CrcMismatch
+            );
+            assert!(qr.is_null());
+            let invalid = [0xFFu8, 0];
+            assert_eq!(
+                promptpay_verify(invalid.as_ptr().cast()),
+                PromptPayStatus::InvalidUtf8
+            );
+            assert_eq!(
+                CStr::from_ptr(promptpay_version()).to_str().unwrap(),
+                env!("CARGO_PKG_VERSION")
+            );
+        }
+    }
+
+    /// ทดสอบว่า panic ภายใน library ถูกแปลงเป็น PromptPayStatus::Other
+    #[test]
+    fn test_ffi_catch_panic() {
+        let status = catch_panic(PromptPayStatus::Other, || panic!("boom"));
+        assert_eq!(status, PromptPayStatus::Other);
+        assert_eq!(last_error(), "Internal error: boom");
+        let qr: *mut FfiPromptPayQR = catch_panic(ptr::null_mut(), || unreachable!());
+        assert!(qr.is_null());
+    }
+
+    /// ทดสอบว่า include/promptpay.h ใน repository ตรงกับ header ที่ cbindgen สร้างตอน build
+    #[test]
+    fn test_ffi_header_up_to_date() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/promptpay.h"));
+        let committed = include_str!("../include/promptpay.h");
+        assert!(
+            generated == committed,
+            "include/promptpay.h is out of date, copy {}/promptpay.h over it",
+            env!("OUT_DIR")
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! **HTML** checkout snippet and `data:` URI output for web pages.
+//!
+//! Everything is inlined (QR image as a base64 `data:` URI, scoped CSS and a small
+//! script), so a server-rendered page can embed the output without serving
+//! extra assets.
+//!
+//! # Example
+//! ```rust
+//! use promptpay_rs::{PromptPayQR, html::CheckoutPage};
+//! use std::time::{Duration, SystemTime};
+//!
+//! let mut qr = PromptPayQR::new("0812345678");
+//! qr.set_amount(1250.75).set_merchant_name("COFFEE HOUSE");
+//! let html = CheckoutPage::new(&qr)
+//!     .set_expires_at(SystemTime::now() + Duration::from_secs(15 * 60))
+//!     .to_html()
+//!     .unwrap();
+//! assert!(html.contains("data:image/svg+xml;base64,"));
+//! assert!(html.contains("฿1,250.75"));
+//! ```
+
+use crate::{
+    EcLevel, PromptPayError, PromptPayQR,
+    card::ThaiQrCard,
+    utils::{escape_xml, format_amount},
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const CHECKOUT_STYLE: &str = concat!(
+    ".promptpay-checkout{max-width:320px;margin:0 auto;padding:16px;text-align:center;",
+    "font-family:Sarabun,Tahoma,'Noto Sans Thai',sans-serif;color:#1f2937;",
+    "border:1px solid #e5e7eb;border-radius:12px}",
+    ".promptpay-checkout .pp-merchant{font-size:18px;font-weight:bold}",
+    ".promptpay-checkout .pp-proxy,.promptpay-checkout .pp-expiry{font-size:14px;color:#6b7280}",
+    ".promptpay-checkout .pp-qr{display:block;width:240px;height:240px;margin:8px auto}",
+    ".promptpay-checkout .pp-amount{font-size:28px;font-weight:bold;color:#113566}",
+    ".promptpay-checkout .pp-payload{display:flex;gap:4px;margin-top:12px}",
+    ".promptpay-checkout .pp-payload input{flex:1;min-width:0;font:12px monospace}",
+    ".promptpay-checkout.pp-expired .pp-qr{opacity:.15}",
+    ".promptpay-checkout.pp-expired .pp-payload{display:none}",
+);
+
+// ปุ่มคัดลอก payload และนับถอยหลังจนหมดอายุ (ทำงานกับ div ที่ครอบ script นี้เท่านั้น)
+const CHECKOUT_SCRIPT: &str = concat!(
+    "(function(root){",
+    "var input=root.querySelector('.pp-payload input');",
+    "root.querySelector('.pp-payload button').onclick=function(){",
+    "input.select();",
+    "if(navigator.clipboard){navigator.clipboard.writeText(input.value);}",
+    "else{document.execCommand('copy');}",
+    "this.textContent='Copied';};",
+    "var expires=+root.getAttribute('data-expires-at');",
+    "if(!expires)return;",
+    "var label=root.querySelector('.pp-countdown');",
+    "(function tick(){",
+    "var left=Math.max(0,Math.round((expires-Date.now())/1000));",
+    "label.textContent=Math.floor(left/60)+':'+('0'+left%60).slice(-2);",
+    "if(left>0){setTimeout(tick,1000);return;}",
+    "root.classList.add('pp-expired');",
+    "root.querySelector('.pp-expiry').textContent='This QR code has expired';",
+    "})();",
+    "})(document.currentScript.parentElement);",
+);
+
+/// Encodes bytes as a base64 `data:` URI.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::html::data_uri;
+/// assert_eq!(data_uri("text/plain", b"Man"), "data:text/plain;base64,TWFu");
+/// ```
+pub fn data_uri(mime_type: &str, data: &[u8]) -> String {
+    let mut uri = format!("data:{};base64,", mime_type);
+    uri.reserve(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        // รวม 3 byte เป็น 24 bit แล้วแบ่งเป็น 4 ตัวอักษร (เติม '=' เมื่อข้อมูลไม่ครบ)
+        let bits = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (bits >> (18 - 6 * i)) & 0x3F;
+                uri.push(BASE64_ALPHABET[index as usize] as char);
+            } else {
+                uri.push('=');
+            }
+        }
+    }
+    uri
+}
+
+impl PromptPayQR {
+    /// Renders the payload with the built-in encoder as an SVG `data:` URI, for use
+    /// in `<img src>` or CSS.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - `data:image/svg+xml;base64,...`
+    /// * `Err(PromptPayError)` - If the payload cannot be created or encoded
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::{EcLevel, PromptPayQR};
+    /// let uri = PromptPayQR::new("0812345678").to_svg_data_uri(EcLevel::M).unwrap();
+    /// assert!(uri.starts_with("data:image/svg+xml;base64,"));
+    /// ```
+    pub fn to_svg_data_uri(&self, ec_level: EcLevel) -> Result<String, PromptPayError> {
+        let svg = self.to_qr_matrix(ec_level)?.to_svg(None);
+        Ok(data_uri("image/svg+xml", svg.as_bytes()))
+    }
+
+    /// Renders the payload as a PNG `data:` URI (requires the `qrcode` feature).
+    ///
+    /// # Returns
+    /// * `Ok(String)` - `data:image/png;base64,...`
+    /// * `Err(PromptPayError)` - If the payload cannot be created, encoded or written
+    #[cfg(feature = "qrcode")]
+    pub fn to_png_data_uri(
+        &self,
+        options: &crate::render::RenderOptions,
+    ) -> Result<String, PromptPayError> {
+        Ok(data_uri("image/png", &self.to_png_bytes(options)?))
+    }
+}
+
+/// Builder for a self-contained **HTML checkout** snippet.
+///
+/// The snippet is a single `<div class="promptpay-checkout">` showing the merchant
+/// name, QR code, masked proxy, amount, an optional expiry countdown and the raw
+/// payload with a copy button. Several snippets can be placed on one page.
+#[derive(Debug, Clone)]
+pub struct CheckoutPage<'a> {
+    qr: &'a PromptPayQR,            // payload ที่จะแสดง
+    merchant_name: Option<String>,  // ชื่อร้านค้า (ไม่บังคับ)
+    expires_at: Option<SystemTime>, // เวลาหมดอายุของ QR สำหรับนับถอยหลัง
+    ec_level: EcLevel,              // ระดับการแก้ไขข้อผิดพลาดของ QR
+}
+
+impl<'a> CheckoutPage<'a> {
+    /// Creates a checkout snippet for a payload, using error correction level `M`.
+    pub fn new(qr: &'a PromptPayQR) -> Self {
+        CheckoutPage {
+            qr,
+            merchant_name: None,
+            expires_at: None,
+            ec_level: EcLevel::M,
+        }
+    }
+
+    /// Sets the merchant name shown above the QR code.
+    ///
+    /// Defaults to the payload's Thai name (tag `64`), then its merchant name (tag `59`).
+    pub fn set_merchant_name(&mut self, name: &str) -> &mut Self {
+        let name = name.trim();
+        self.merchant_name = (!name.is_empty()).then(|| name.to_string());
+        self
+    }
+
+    /// Shows a countdown to `expires_at`; when it reaches zero the QR code is faded
+    /// and the copy button hidden.
+    pub fn set_expires_at(&mut self, expires_at: SystemTime) -> &mut Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Sets the error correction level of the QR code.
+    pub fn set_ec_level(&mut self, ec_level: EcLevel) -> &mut Self {
+        self.ec_level = ec_level;
+        self
+    }
+
+    /// Renders the snippet.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - HTML markup with inline CSS, image and script
+    /// * `Err(PromptPayError)` - If the payload cannot be created or encoded
+    pub fn to_html(&self) -> Result<String, PromptPayError> {
+        let payload = self.qr.create()?;
+        let image = self.qr.to_svg_data_uri(self.ec_level)?;
+
+        let mut html = String::from(r#"<div class="promptpay-checkout""#);
+        if let Some(expires_at) = self.expires_at {
+            // เวลาเป็นมิลลิวินาทีตั้งแต่ Unix epoch (เทียบกับ Date.now() ใน browser)
+            let millis = expires_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            html.push_str(&format!(r#" data-expires-at="{}""#, millis));
+        }
+        html.push_str(&format!(">\n<style>{}</style>\n", CHECKOUT_STYLE));
+
+        // ชื่อร้าน: ค่าที่กำหนด → ชื่อภาษาไทย (tag 64) → ชื่อร้าน (tag 59)
+        let merchant_name = self
+            .merchant_name
+            .as_deref()
+            .or(self
+                .qr
+                .merchant_language()
+                .map(|l| l.merchant_name.as_str()))
+            .or(self.qr.merchant_name());
+        if let Some(name) = merchant_name {
+            html.push_str(&format!(
+                "<div class=\"pp-merchant\">{}</div>\n",
+                escape_xml(name)
+            ));
+        }
+        html.push_str(&format!(
+            "<img class=\"pp-qr\" src=\"{}\" alt=\"PromptPay QR code\">\n",
+            image
+        ));
+        html.push_str(&format!(
+            "<div class=\"pp-proxy\">{}</div>\n",
+            escape_xml(&ThaiQrCard::new(self.qr).proxy_text())
+        ));
+        if let Some(amount) = self.qr.amount() {
+            html.push_str(&format!(
+                "<div class=\"pp-amount\">฿{}</div>\n",
+                format_amount(amount)
+            ));
+        }
+        if self.expires_at.is_some() {
+            html.push_str(
+                "<div class=\"pp-expiry\">Expires in <span class=\"pp-countdown\"></span></div>\n",
+            );
+        }
+        html.push_str(&format!(
+            concat!(
+                "<div class=\"pp-payload\">",
+                "<input type=\"text\" readonly value=\"{}\" aria-label=\"PromptPay payload\">",
+                "<button type=\"button\">Copy</button></div>\n"
+            ),
+            escape_xml(&payload)
+        ));
+        html.push_str(&format!("<script>{}</script>\n</div>", CHECKOUT_SCRIPT));
+        Ok(html)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    /// ทดสอบการเข้ารหัส base64 ทุกกรณีของ padding
+    #[test]
+    fn test_data_uri() {
+        assert_eq!(data_uri("text/plain", b""), "data:text/plain;base64,");
+        assert_eq!(data_uri("text/plain", b"M"), "data:text/plain;base64,TQ==");
+        assert_eq!(data_uri("text/plain", b"Ma"), "data:text/plain;base64,TWE=");
+        assert_eq!(
+            data_uri("application/octet-stream", &[0xFB, 0xFF, 0xBF, 0x00]),
+            "data:application/octet-stream;base64,+/+/AA=="
+        );
+    }
+
+    /// ทดสอบ SVG data URI: ขนาด viewBox รวม quiet zone
+    #[test]
+    fn test_svg_data_uri() {
+        let qr = PromptPayQR::new("0812345678");
+        let matrix = qr.to_qr_matrix(EcLevel::M).unwrap();
+        let svg = matrix.to_svg(None);
+        let size = matrix.size() + 8;
+        assert!(svg.contains(&format!(r#"viewBox="0 0 {} {}""#, size, size)));
+        assert_eq!(
+            qr.to_svg_data_uri(EcLevel::M).unwrap(),
+            data_uri("image/svg+xml", svg.as_bytes())
+        );
+    }
+
+    /// ทดสอบ HTML checkout: ชื่อร้าน (escape แล้ว), ยอดเงิน, payload และเวลาหมดอายุ
+    #[test]
+    fn test_checkout_html() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(99.5).set_merchant_name("TOM & JERRY");
+        let payload = qr.create().unwrap();
+
+        let html = CheckoutPage::new(&qr).to_html().unwrap();
+        assert!(html.contains(r#"<div class="pp-merchant">TOM &amp; JERRY</div>"#));
+        assert!(html.contains("฿99.50"));
+        assert!(html.contains("xxx-xxx-5678"));
+        assert!(html.contains(&format!(r#"value="{}""#, escape_xml(&payload))));
+        assert!(!html.contains("data-expires-at=\""));
+        assert!(!html.contains("pp-countdown\""));
+
+        let expires = UNIX_EPOCH + Duration::from_secs(1_735_689_600);
+        let html = CheckoutPage::new(&qr)
+            .set_merchant_name("<b>")
+            .set_expires_at(expires)
+            .to_html()
+            .unwrap();
+        assert!(
+            html.starts_with(r#"<div class="promptpay-checkout" data-expires-at="1735689600000">"#)
+        );
+        assert!(html.contains("&lt;b&gt;"));
+        assert!(html.contains(r#"<span class="pp-countdown">"#));
+    }
+}
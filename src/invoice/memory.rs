@@ -0,0 +1,81 @@
+//! In-memory [`InvoiceStore`].
+
+use super::{Invoice, InvoiceStatus, InvoiceStore};
+use crate::{PromptPayError, error::ErrorKind};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::SystemTime,
+};
+
+/// [`InvoiceStore`] that keeps invoices in a `HashMap`; they are lost when it is dropped.
+#[derive(Debug, Default)]
+pub struct MemoryInvoiceStore {
+    invoices: Mutex<HashMap<String, Invoice>>, // invoice ตาม id
+}
+
+impl MemoryInvoiceStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        MemoryInvoiceStore::default()
+    }
+
+    /// Number of invoices in the store.
+    pub fn len(&self) -> usize {
+        self.invoices().len()
+    }
+
+    /// Returns `true` if the store holds no invoices.
+    pub fn is_empty(&self) -> bool {
+        self.invoices().is_empty()
+    }
+
+    fn invoices(&self) -> MutexGuard<'_, HashMap<String, Invoice>> {
+        self.invoices.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl InvoiceStore for MemoryInvoiceStore {
+    fn insert(&self, invoice: &Invoice) -> Result<(), PromptPayError> {
+        let mut invoices = self.invoices();
+        if invoices.contains_key(&invoice.id) {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Invoice already exists: {}", invoice.id),
+            ));
+        }
+        invoices.insert(invoice.id.clone(), invoice.clone());
+        Ok(())
+    }
+
+    fn get(&self, id: &str) -> Result<Option<Invoice>, PromptPayError> {
+        Ok(self.invoices().get(id).cloned())
+    }
+
+    fn update_status(
+        &self,
+        id: &str,
+        from: InvoiceStatus,
+        to: InvoiceStatus,
+    ) -> Result<bool, PromptPayError> {
+        match self.invoices().get_mut(id) {
+            Some(invoice) if invoice.status == from => {
+                invoice.status = to;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn pending_expired(&self, now: SystemTime) -> Result<Vec<Invoice>, PromptPayError> {
+        let mut invoices: Vec<Invoice> = self
+            .invoices()
+            .values()
+            .filter(|invoice| invoice.is_overdue(now))
+            .cloned()
+            .collect();
+        // เรียงตามเวลาหมดอายุให้ผลลัพธ์คงที่
+        invoices.sort_by(|a, b| a.expires_at.cmp(&b.expires_at).then(a.id.cmp(&b.id)));
+        Ok(invoices)
+    }
+}
@@ -0,0 +1,524 @@
+//! **Invoice lifecycle**: issue dynamic QRs as payment requests, then mark
+//! them paid, cancel them or let them expire.
+//!
+//! An [`Invoice`] records the proxy, amount and reference of a dynamic QR; the
+//! reference is written to the reference label (sub-tag `05`) of tag `62`, so
+//! a payment notification can be matched back to its invoice. Invoices start
+//! as [`InvoiceStatus::Pending`] and move once to `Paid`, `Cancelled` or
+//! `Expired`.
+//!
+//! [`InvoiceManager`] applies the transitions on top of an [`InvoiceStore`]:
+//! [`MemoryInvoiceStore`] for tests and single-process services, or
+//! `SqliteInvoiceStore` with the `sqlite` feature. Time comes from a [`Clock`],
+//! so expiry can be tested with a [`ManualClock`].
+//!
+//! # Example
+//! ```rust
+//! use promptpay_rs::invoice::{InvoiceManager, InvoiceStatus, ManualClock, MemoryInvoiceStore};
+//! use std::time::{Duration, UNIX_EPOCH};
+//!
+//! let clock = ManualClock::new(UNIX_EPOCH + Duration::from_secs(1_735_689_600));
+//! let mut invoices = InvoiceManager::with_clock(MemoryInvoiceStore::new(), clock);
+//! invoices.set_ttl(Duration::from_secs(15 * 60));
+//!
+//! let invoice = invoices.issue("ORDER-1001", "0812345678", 150.0).unwrap();
+//! let payload = invoice.payload().unwrap(); // แสดงเป็น QR ให้ลูกค้าสแกน
+//! assert!(payload.contains("0510ORDER-1001"));
+//!
+//! // ลูกค้าจ่ายไม่ทันภายใน 15 นาที
+//! invoices.clock().advance(Duration::from_secs(16 * 60));
+//! let expired = invoices.expire_due().unwrap();
+//! assert_eq!(expired[0].status, InvoiceStatus::Expired);
+//! assert!(invoices.mark_paid("ORDER-1001").is_err());
+//! ```
+
+mod memory;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+pub use memory::MemoryInvoiceStore;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteInvoiceStore;
+
+use crate::{PromptPayError, PromptPayQR, additional_data::AdditionalData, error::ErrorKind};
+use std::{
+    fmt,
+    str::FromStr,
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+/// Time an invoice stays payable when no TTL is set: 15 minutes.
+pub const DEFAULT_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Where an [`Invoice`] is in its lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvoiceStatus {
+    /// Issued and waiting for payment
+    Pending,
+    /// Payment received
+    Paid,
+    /// Not paid before `expires_at`
+    Expired,
+    /// Withdrawn by the merchant
+    Cancelled,
+}
+
+impl InvoiceStatus {
+    /// Returns the lowercase name used by the stores (e.g. `"pending"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvoiceStatus::Pending => "pending",
+            InvoiceStatus::Paid => "paid",
+            InvoiceStatus::Expired => "expired",
+            InvoiceStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl FromStr for InvoiceStatus {
+    type Err = PromptPayError;
+
+    /// Parses the name returned by [`InvoiceStatus::as_str`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(InvoiceStatus::Pending),
+            "paid" => Ok(InvoiceStatus::Paid),
+            "expired" => Ok(InvoiceStatus::Expired),
+            "cancelled" => Ok(InvoiceStatus::Cancelled),
+            _ => Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Unknown invoice status: {}", s),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for InvoiceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A payment request backed by a dynamic QR.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invoice {
+    /// Caller-assigned identifier, unique within a store
+    pub id: String,
+    /// Proxy that receives the payment (mobile number, tax ID or e-wallet ID)
+    pub proxy: String,
+    /// Amount in THB, with at most 2 decimals
+    pub amount: f64,
+    /// Reference label written to tag `62` (sub-tag `05`)
+    pub reference: String,
+    /// When the invoice was issued
+    pub created_at: SystemTime,
+    /// When a pending invoice expires
+    pub expires_at: SystemTime,
+    /// Current status
+    pub status: InvoiceStatus,
+}
+
+impl Invoice {
+    /// Builds the dynamic QR of this invoice, with the amount and reference label.
+    pub fn qr(&self) -> PromptPayQR {
+        let mut qr = PromptPayQR::new(&self.proxy);
+        qr.set_amount(self.amount)
+            .set_additional_data(AdditionalData {
+                reference_label: Some(self.reference.clone()),
+                ..AdditionalData::default()
+            });
+        qr
+    }
+
+    /// Generates the payload of [`Invoice::qr`].
+    pub fn payload(&self) -> Result<String, PromptPayError> {
+        self.qr().create()
+    }
+
+    /// Returns `true` if the invoice is pending and `now` is past `expires_at`.
+    pub fn is_overdue(&self, now: SystemTime) -> bool {
+        self.status == InvoiceStatus::Pending && now >= self.expires_at
+    }
+}
+
+/// Source of the current time for an [`InvoiceManager`].
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// [`Clock`] that reads the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// [`Clock`] that only moves when told to, for tests.
+#[derive(Debug)]
+pub struct ManualClock(Mutex<SystemTime>);
+
+impl ManualClock {
+    /// Creates a clock stopped at `now`.
+    pub fn new(now: SystemTime) -> Self {
+        ManualClock(Mutex::new(now))
+    }
+
+    /// Moves the clock forward.
+    pub fn advance(&self, duration: Duration) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) += duration;
+    }
+
+    /// Sets the clock to `now`.
+    pub fn set(&self, now: SystemTime) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = now;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Persistence of [`Invoice`]s.
+///
+/// Methods take `&self` so a store can be shared between request handlers;
+/// implementations synchronise internally.
+pub trait InvoiceStore {
+    /// Saves a new invoice; fails with [`ErrorKind::InvalidInput`] if the ID exists.
+    fn insert(&self, invoice: &Invoice) -> Result<(), PromptPayError>;
+
+    /// Looks up an invoice by ID.
+    fn get(&self, id: &str) -> Result<Option<Invoice>, PromptPayError>;
+
+    /// Sets the status of an invoice if it is currently `from`.
+    ///
+    /// Returns `false` without changing anything if the invoice does not exist
+    /// or has another status, so concurrent transitions cannot both succeed.
+    fn update_status(
+        &self,
+        id: &str,
+        from: InvoiceStatus,
+        to: InvoiceStatus,
+    ) -> Result<bool, PromptPayError>;
+
+    /// Returns the pending invoices whose `expires_at` is at or before `now`.
+    fn pending_expired(&self, now: SystemTime) -> Result<Vec<Invoice>, PromptPayError>;
+}
+
+/// Issues invoices and applies their status transitions.
+#[derive(Debug)]
+pub struct InvoiceManager<S, C = SystemClock> {
+    store: S,      // ที่เก็บ invoice
+    clock: C,      // แหล่งเวลาปัจจุบัน
+    ttl: Duration, // อายุของ invoice ที่ออกใหม่
+}
+
+impl<S: InvoiceStore> InvoiceManager<S> {
+    /// Creates a manager that uses the system time.
+    pub fn new(store: S) -> Self {
+        InvoiceManager::with_clock(store, SystemClock)
+    }
+}
+
+impl<S: InvoiceStore, C: Clock> InvoiceManager<S, C> {
+    /// Creates a manager with an injected clock.
+    pub fn with_clock(store: S, clock: C) -> Self {
+        InvoiceManager {
+            store,
+            clock,
+            ttl: DEFAULT_TTL,
+        }
+    }
+
+    /// Sets how long newly issued invoices stay payable (default [`DEFAULT_TTL`]).
+    pub fn set_ttl(&mut self, ttl: Duration) -> &mut Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// The underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// The clock used for `created_at` and expiry.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Issues a pending invoice whose reference is its ID.
+    ///
+    /// # Returns
+    /// * `Ok(Invoice)` - Saved invoice
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if the amount is not
+    ///   positive or has more than 2 decimals, the TTL overflows `expires_at`, the QR cannot be generated
+    ///   (e.g. the ID is longer than the 25 characters of a reference label) or the ID already exists
+    pub fn issue(&self, id: &str, proxy: &str, amount: f64) -> Result<Invoice, PromptPayError> {
+        if !(amount.is_finite() && amount > 0.0) {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Invoice amount must be positive: {}", amount),
+            ));
+        }
+        // สตางค์เป็นหน่วยเล็กที่สุด ทุก store จึงเก็บจำนวนเงินได้ตรงกัน
+        if (amount * 100.0).round() / 100.0 != amount {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Invoice amount must have at most 2 decimals: {}", amount),
+            ));
+        }
+        let now = self.clock.now();
+        let expires_at = now.checked_add(self.ttl).ok_or_else(|| {
+            PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Invoice TTL is too large: {:?}", self.ttl),
+            )
+        })?;
+        let invoice = Invoice {
+            id: id.to_string(),
+            proxy: proxy.to_string(),
+            amount,
+            reference: id.to_string(),
+            created_at: now,
+            expires_at,
+            status: InvoiceStatus::Pending,
+        };
+        // ตรวจสอบว่าสร้าง QR ได้ก่อนบันทึก
+        invoice.payload()?;
+        self.store.insert(&invoice)?;
+        Ok(invoice)
+    }
+
+    /// Looks up an invoice, expiring it first if it is overdue.
+    pub fn get(&self, id: &str) -> Result<Option<Invoice>, PromptPayError> {
+        let Some(mut invoice) = self.store.get(id)? else {
+            return Ok(None);
+        };
+        if !invoice.is_overdue(self.clock.now()) {
+            return Ok(Some(invoice));
+        }
+        if self
+            .store
+            .update_status(id, InvoiceStatus::Pending, InvoiceStatus::Expired)?
+        {
+            invoice.status = InvoiceStatus::Expired;
+            return Ok(Some(invoice));
+        }
+        // ถูกเปลี่ยนสถานะไประหว่าง get กับ update_status จึงอ่านค่าล่าสุดอีกครั้ง
+        self.store.get(id)
+    }
+
+    /// Marks a pending invoice as paid.
+    ///
+    /// Fails with [`ErrorKind::InvalidInput`] if the invoice does not exist, is
+    /// past `expires_at`, or was already paid, cancelled or expired.
+    pub fn mark_paid(&self, id: &str) -> Result<Invoice, PromptPayError> {
+        self.transition(id, InvoiceStatus::Paid)
+    }
+
+    /// Cancels a pending invoice.
+    ///
+    /// Fails with [`ErrorKind::InvalidInput`] under the same conditions as
+    /// [`InvoiceManager::mark_paid`].
+    pub fn cancel(&self, id: &str) -> Result<Invoice, PromptPayError> {
+        self.transition(id, InvoiceStatus::Cancelled)
+    }
+
+    /// Expires every overdue pending invoice and returns them.
+    pub fn expire_due(&self) -> Result<Vec<Invoice>, PromptPayError> {
+        let mut expired = Vec::new();
+        for mut invoice in self.store.pending_expired(self.clock.now())? {
+            // ข้าม invoice ที่ถูกเปลี่ยนสถานะไปแล้วระหว่างนี้
+            if self.store.update_status(
+                &invoice.id,
+                InvoiceStatus::Pending,
+                InvoiceStatus::Expired,
+            )? {
+                invoice.status = InvoiceStatus::Expired;
+                expired.push(invoice);
+            }
+        }
+        Ok(expired)
+    }
+
+    fn transition(&self, id: &str, to: InvoiceStatus) -> Result<Invoice, PromptPayError> {
+        let mut invoice = self.get(id)?.ok_or_else(|| {
+            PromptPayError::with_kind(ErrorKind::InvalidInput, &format!("Unknown invoice: {}", id))
+        })?;
+        if invoice.status == InvoiceStatus::Pending
+            && self.store.update_status(id, InvoiceStatus::Pending, to)?
+        {
+            invoice.status = to;
+            return Ok(invoice);
+        }
+        // อ่านสถานะอีกครั้ง เพราะอาจถูกเปลี่ยนไประหว่าง get กับ update_status
+        let status = match self.store.get(id)? {
+            Some(current) if invoice.status == InvoiceStatus::Pending => current.status,
+            _ => invoice.status,
+        };
+        Err(PromptPayError::with_kind(
+            ErrorKind::InvalidInput,
+            &format!("Invoice {} is {}, not pending", id, status),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    fn manager() -> InvoiceManager<MemoryInvoiceStore, ManualClock> {
+        let clock = ManualClock::new(UNIX_EPOCH + Duration::from_secs(1_735_689_600));
+        let mut invoices = InvoiceManager::with_clock(MemoryInvoiceStore::new(), clock);
+        invoices.set_ttl(Duration::from_secs(600));
+        invoices
+    }
+
+    /// ทดสอบการออก invoice, QR ที่มี reference และการจ่าย / ยกเลิก
+    #[test]
+    fn test_invoice_lifecycle() {
+        let invoices = manager();
+        let invoice = invoices.issue("INV-001", "0812345678", 150.0).unwrap();
+        assert_eq!(invoice.status, InvoiceStatus::Pending);
+        assert_eq!(
+            invoice.expires_at,
+            invoice.created_at + Duration::from_secs(600)
+        );
+
+        let decoded = PromptPayQR::parse(&invoice.payload().unwrap()).unwrap();
+        assert_eq!(decoded.amount(), Some(150.0));
+        let data = decoded.additional_data().unwrap();
+        assert_eq!(data.reference_label.as_deref(), Some("INV-001"));
+
+        assert_eq!(
+            invoices.mark_paid("INV-001").unwrap().status,
+            InvoiceStatus::Paid
+        );
+        assert_eq!(
+            invoices.get("INV-001").unwrap().unwrap().status,
+            InvoiceStatus::Paid
+        );
+        assert!(invoices.cancel("INV-001").is_err());
+        assert!(invoices.mark_paid("INV-001").is_err());
+
+        invoices.issue("INV-002", "0812345678", 99.5).unwrap();
+        assert_eq!(
+            invoices.cancel("INV-002").unwrap().status,
+            InvoiceStatus::Cancelled
+        );
+
+        // ID ซ้ำ, จำนวนเงินไม่ถูกต้อง และ invoice ที่ไม่มีอยู่
+        let error = invoices.issue("INV-001", "0812345678", 10.0).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(invoices.issue("INV-003", "0812345678", 0.0).is_err());
+        assert!(invoices.issue("INV-003", "0812345678", 10.005).is_err());
+        assert_eq!(
+            invoices.issue("INV-004", "0812345678", 0.29).unwrap(),
+            invoices.store().get("INV-004").unwrap().unwrap()
+        );
+        assert!(invoices.issue("INV-003", "", 10.0).is_err());
+        assert!(invoices.store().get("INV-003").unwrap().is_none());
+        assert!(invoices.mark_paid("INV-404").is_err());
+
+        // TTL ที่ทำให้ expires_at ล้น
+        let mut invoices = manager();
+        invoices.set_ttl(Duration::MAX);
+        let error = invoices.issue("INV-005", "0812345678", 10.0).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(invoices.store().get("INV-005").unwrap().is_none());
+
+        for status in [InvoiceStatus::Pending, InvoiceStatus::Cancelled] {
+            assert_eq!(status.as_str().parse::<InvoiceStatus>().ok(), Some(status));
+        }
+        assert!("unpaid".parse::<InvoiceStatus>().is_err());
+    }
+
+    // store ที่จำลองการจ่ายเงินจากอีก process หนึ่งก่อนทุก update_status
+    struct RacingStore(MemoryInvoiceStore);
+
+    impl InvoiceStore for RacingStore {
+        fn insert(&self, invoice: &Invoice) -> Result<(), PromptPayError> {
+            self.0.insert(invoice)
+        }
+
+        fn get(&self, id: &str) -> Result<Option<Invoice>, PromptPayError> {
+            self.0.get(id)
+        }
+
+        fn update_status(
+            &self,
+            id: &str,
+            from: InvoiceStatus,
+            to: InvoiceStatus,
+        ) -> Result<bool, PromptPayError> {
+            self.0
+                .update_status(id, InvoiceStatus::Pending, InvoiceStatus::Paid)?;
+            self.0.update_status(id, from, to)
+        }
+
+        fn pending_expired(&self, now: SystemTime) -> Result<Vec<Invoice>, PromptPayError> {
+            self.0.pending_expired(now)
+        }
+    }
+
+    /// ทดสอบข้อความผิดพลาดเมื่อ invoice ถูกเปลี่ยนสถานะไปก่อนระหว่างการ cancel
+    #[test]
+    fn test_invoice_transition_race() {
+        let invoices = InvoiceManager::new(RacingStore(MemoryInvoiceStore::new()));
+        invoices.issue("INV-001", "0812345678", 150.0).unwrap();
+        let error = invoices.cancel("INV-001").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(
+            error
+                .to_string()
+                .contains("Invoice INV-001 is paid, not pending"),
+            "{}",
+            error
+        );
+        assert_eq!(
+            invoices.get("INV-001").unwrap().unwrap().status,
+            InvoiceStatus::Paid
+        );
+
+        // invoice ที่เลยเวลาแต่ถูกจ่ายไปก่อนระหว่าง get ต้องไม่คืนสถานะ pending เดิม
+        let clock = ManualClock::new(UNIX_EPOCH);
+        let invoices = InvoiceManager::with_clock(RacingStore(MemoryInvoiceStore::new()), clock);
+        invoices.issue("INV-002", "0812345678", 150.0).unwrap();
+        invoices.clock().advance(Duration::from_secs(3600));
+        assert_eq!(
+            invoices.get("INV-002").unwrap().unwrap().status,
+            InvoiceStatus::Paid
+        );
+    }
+
+    /// ทดสอบการหมดอายุด้วยนาฬิกาที่ควบคุมได้
+    #[test]
+    fn test_invoice_expiry() {
+        let invoices = manager();
+        invoices.issue("INV-001", "0812345678", 150.0).unwrap();
+        invoices.clock().advance(Duration::from_secs(300));
+        invoices.issue("INV-002", "0812345678", 200.0).unwrap();
+
+        invoices.clock().advance(Duration::from_secs(300));
+        let expired = invoices.expire_due().unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, "INV-001");
+        assert_eq!(expired[0].status, InvoiceStatus::Expired);
+        assert!(invoices.mark_paid("INV-001").is_err());
+
+        // invoice ที่เลยเวลาแล้วหมดอายุเมื่ออ่าน แม้ยังไม่ได้เรียก expire_due
+        invoices.clock().advance(Duration::from_secs(300));
+        assert_eq!(
+            invoices.get("INV-002").unwrap().unwrap().status,
+            InvoiceStatus::Expired
+        );
+        assert!(invoices.expire_due().unwrap().is_empty());
+    }
+}
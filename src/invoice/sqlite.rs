@@ -0,0 +1,234 @@
+//! SQLite [`InvoiceStore`] (requires the `sqlite` feature).
+
+use super::{Invoice, InvoiceStatus, InvoiceStore};
+use crate::{PromptPayError, error::ErrorKind};
+use rusqlite::{Connection, ErrorCode, OptionalExtension, Row, params};
+use std::{
+    path::Path,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS promptpay_invoices (
+    id TEXT PRIMARY KEY NOT NULL,
+    proxy TEXT NOT NULL,
+    amount_satang INTEGER NOT NULL,
+    reference TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    expires_at INTEGER NOT NULL,
+    status TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS promptpay_invoices_status_expires_at
+    ON promptpay_invoices (status, expires_at);
+";
+
+const COLUMNS: &str = "id, proxy, amount_satang, reference, created_at, expires_at, status";
+
+/// [`InvoiceStore`] backed by a SQLite database.
+///
+/// Invoices are kept in the `promptpay_invoices` table, created on open, with
+/// the amount in satang and times as Unix milliseconds.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::invoice::{InvoiceManager, SqliteInvoiceStore};
+///
+/// let store = SqliteInvoiceStore::open_in_memory().unwrap();
+/// let invoices = InvoiceManager::new(store);
+/// invoices.issue("ORDER-1001", "0812345678", 150.0).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct SqliteInvoiceStore {
+    conn: Mutex<Connection>, // rusqlite::Connection ใช้ข้าม thread พร้อมกันไม่ได้
+}
+
+impl SqliteInvoiceStore {
+    /// Opens (or creates) a database file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, PromptPayError> {
+        SqliteInvoiceStore::from_connection(Connection::open(path).map_err(sqlite_error)?)
+    }
+
+    /// Opens a private in-memory database.
+    pub fn open_in_memory() -> Result<Self, PromptPayError> {
+        SqliteInvoiceStore::from_connection(Connection::open_in_memory().map_err(sqlite_error)?)
+    }
+
+    /// Uses an existing connection, creating the invoice table if needed.
+    pub fn from_connection(conn: Connection) -> Result<Self, PromptPayError> {
+        conn.execute_batch(SCHEMA).map_err(sqlite_error)?;
+        Ok(SqliteInvoiceStore {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl InvoiceStore for SqliteInvoiceStore {
+    fn insert(&self, invoice: &Invoice) -> Result<(), PromptPayError> {
+        let result = self.conn().execute(
+            &format!(
+                "INSERT INTO promptpay_invoices ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                COLUMNS
+            ),
+            params![
+                invoice.id,
+                invoice.proxy,
+                (invoice.amount * 100.0).round() as i64,
+                invoice.reference,
+                to_millis(invoice.created_at)?,
+                to_millis(invoice.expires_at)?,
+                invoice.status.as_str(),
+            ],
+        );
+        match result {
+            Ok(_) => Ok(()),
+            Err(rusqlite::Error::SqliteFailure(e, _))
+                if e.code == ErrorCode::ConstraintViolation =>
+            {
+                Err(PromptPayError::with_kind(
+                    ErrorKind::InvalidInput,
+                    &format!("Invoice already exists: {}", invoice.id),
+                ))
+            }
+            Err(e) => Err(sqlite_error(e)),
+        }
+    }
+
+    fn get(&self, id: &str) -> Result<Option<Invoice>, PromptPayError> {
+        self.conn()
+            .query_row(
+                &format!("SELECT {} FROM promptpay_invoices WHERE id = ?1", COLUMNS),
+                [id],
+                from_row,
+            )
+            .optional()
+            .map_err(sqlite_error)
+    }
+
+    fn update_status(
+        &self,
+        id: &str,
+        from: InvoiceStatus,
+        to: InvoiceStatus,
+    ) -> Result<bool, PromptPayError> {
+        let updated = self
+            .conn()
+            .execute(
+                "UPDATE promptpay_invoices SET status = ?1 WHERE id = ?2 AND status = ?3",
+                params![to.as_str(), id, from.as_str()],
+            )
+            .map_err(sqlite_error)?;
+        Ok(updated == 1)
+    }
+
+    fn pending_expired(&self, now: SystemTime) -> Result<Vec<Invoice>, PromptPayError> {
+        let conn = self.conn();
+        let mut statement = conn
+            .prepare(&format!(
+                "SELECT {} FROM promptpay_invoices WHERE status = ?1 AND expires_at <= ?2 \
+                 ORDER BY expires_at, id",
+                COLUMNS
+            ))
+            .map_err(sqlite_error)?;
+        let rows = statement
+            .query_map(
+                params![InvoiceStatus::Pending.as_str(), to_millis(now)?],
+                from_row,
+            )
+            .map_err(sqlite_error)?;
+        rows.collect::<Result<_, _>>().map_err(sqlite_error)
+    }
+}
+
+fn from_row(row: &Row<'_>) -> rusqlite::Result<Invoice> {
+    let status = row
+        .get::<_, String>(6)?
+        .parse::<InvoiceStatus>()
+        .map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(6, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+    Ok(Invoice {
+        id: row.get(0)?,
+        proxy: row.get(1)?,
+        amount: row.get::<_, i64>(2)? as f64 / 100.0,
+        reference: row.get(3)?,
+        created_at: from_millis(row.get(4)?),
+        expires_at: from_millis(row.get(5)?),
+        status,
+    })
+}
+
+// เวลาเก็บเป็นมิลลิวินาทีนับจาก Unix epoch
+fn to_millis(time: SystemTime) -> Result<i64, PromptPayError> {
+    time.duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|duration| i64::try_from(duration.as_millis()).ok())
+        .ok_or_else(|| {
+            PromptPayError::with_kind(ErrorKind::InvalidInput, "Invoice time is before 1970")
+        })
+}
+
+fn from_millis(millis: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis.max(0) as u64)
+}
+
+fn sqlite_error(e: rusqlite::Error) -> PromptPayError {
+    PromptPayError::new(&format!("SQLite invoice store: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::invoice::{InvoiceManager, ManualClock};
+
+    /// ทดสอบ lifecycle บน SQLite รวมถึงการเปิดไฟล์ฐานข้อมูลซ้ำ
+    #[test]
+    fn test_sqlite_store() {
+        let path =
+            std::env::temp_dir().join(format!("promptpay-invoices-{}.sqlite", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let start = UNIX_EPOCH + Duration::from_millis(1_735_689_600_123);
+        {
+            let store = SqliteInvoiceStore::open(&path).unwrap();
+            let mut invoices = InvoiceManager::with_clock(store, ManualClock::new(start));
+            invoices.set_ttl(Duration::from_secs(600));
+            invoices.issue("INV-001", "0812345678", 150.25).unwrap();
+            invoices.issue("INV-002", "0812345678", 99.0).unwrap();
+            assert!(invoices.issue("INV-001", "0812345678", 1.0).is_err());
+            assert!(invoices.issue("INV-003", "0812345678", 10.005).is_err());
+            invoices.issue("INV-004", "0812345678", 0.29).unwrap();
+            invoices.cancel("INV-004").unwrap();
+            invoices.mark_paid("INV-002").unwrap();
+        }
+
+        let store = SqliteInvoiceStore::open(&path).unwrap();
+        let invoice = store.get("INV-001").unwrap().unwrap();
+        assert_eq!(invoice.amount, 150.25);
+        assert_eq!(store.get("INV-004").unwrap().unwrap().amount, 0.29);
+        assert!(store.get("INV-003").unwrap().is_none());
+        assert_eq!(invoice.reference, "INV-001");
+        assert_eq!(invoice.created_at, start);
+        assert_eq!(invoice.status, InvoiceStatus::Pending);
+        assert_eq!(
+            store.get("INV-002").unwrap().unwrap().status,
+            InvoiceStatus::Paid
+        );
+        assert!(store.get("INV-404").unwrap().is_none());
+
+        let invoices =
+            InvoiceManager::with_clock(store, ManualClock::new(start + Duration::from_secs(600)));
+        let expired = invoices.expire_due().unwrap();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, "INV-001");
+        assert_eq!(
+            invoices.get("INV-001").unwrap().unwrap().status,
+            InvoiceStatus::Expired
+        );
+        drop(invoices);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,100 @@
+//! **Merchant Information — Language Template** (tag `64`).
+//!
+//! Tags `59` / `60` only hold the merchant name and city in Latin script, so
+//! the Thai name and city are carried in this template.
+
+use crate::{PromptPayError, error::ErrorKind, tlv};
+
+/// Merchant name and city in an alternate language (tag `64`).
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{PromptPayQR, language::MerchantLanguage};
+///
+/// let mut qr = PromptPayQR::new("0812345678");
+/// qr.set_merchant_name("COFFEE HOUSE")
+///     .set_merchant_language(MerchantLanguage::thai("ร้านกาแฟ"));
+/// let decoded = PromptPayQR::parse(&qr.create().unwrap()).unwrap();
+/// assert_eq!(decoded.merchant_language().unwrap().merchant_name, "ร้านกาแฟ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct MerchantLanguage {
+    /// `00` Language preference (ISO 639-1, e.g. `"TH"`)
+    pub language: String,
+    /// `01` Merchant name in the alternate language (up to 25 characters)
+    pub merchant_name: String,
+    /// `02` Merchant city in the alternate language (up to 15 characters)
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub merchant_city: Option<String>,
+}
+
+impl MerchantLanguage {
+    /// Thai merchant name (language `"TH"`), without a city.
+    pub fn thai(merchant_name: &str) -> Self {
+        MerchantLanguage {
+            language: "TH".to_string(),
+            merchant_name: merchant_name.to_string(),
+            merchant_city: None,
+        }
+    }
+
+    /// Sets the merchant city in the alternate language.
+    pub fn with_city(mut self, city: &str) -> Self {
+        self.merchant_city = Some(city.to_string());
+        self
+    }
+
+    /// Encodes the template value (without the outer tag `64`).
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Concatenated sub-fields
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if the language is not two
+    ///   letters, or the name or city is empty or too long
+    pub fn encode(&self) -> Result<String, PromptPayError> {
+        if self.language.len() != 2 || !self.language.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!(
+                    "Language must be a 2-letter ISO 639-1 code: {}",
+                    self.language
+                ),
+            ));
+        }
+        let mut value = tlv::encode("00", &self.language.to_uppercase())?;
+        value.push_str(&tlv::encode_checked(
+            "01",
+            &self.merchant_name,
+            "Merchant name",
+            25,
+        )?);
+        if let Some(city) = &self.merchant_city {
+            value.push_str(&tlv::encode_checked("02", city, "Merchant city", 15)?);
+        }
+        Ok(value)
+    }
+
+    /// Decodes the template value of tag `64`.
+    pub fn parse(value: &str) -> Result<Self, PromptPayError> {
+        let fields = tlv::parse(value)?;
+        let missing = |name: &str| {
+            PromptPayError::with_kind(
+                ErrorKind::InvalidPayload,
+                &format!("{} is missing from the language template (tag 64)", name),
+            )
+        };
+        Ok(MerchantLanguage {
+            language: tlv::find(&fields, "00")
+                .ok_or_else(|| missing("Language"))?
+                .to_string(),
+            merchant_name: tlv::find(&fields, "01")
+                .ok_or_else(|| missing("Merchant name"))?
+                .to_string(),
+            merchant_city: tlv::find(&fields, "02").map(|v| v.to_string()),
+        })
+    }
+}
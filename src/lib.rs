@@ -7,7 +7,24 @@
 //! - Static and Dynamic QR (with/without amount)
 //! - Automatic target formatting (e.g. `0` → `66` for Thai mobile)
 //! - CRC-16/CCITT calculation
-//! - Output as `String` or `QrCode` image via `qrcode` crate
+//! - Output as `String`, or as SVG / PNG / terminal QR with the `qrcode` feature
+//! - Base64 `data:` URIs and a self-contained HTML checkout snippet
+//! - NFC NDEF messages for tap stickers
+//! - ESC/POS bytes for thermal receipt printers
+//! - Thai QR Payment branded card as SVG (PNG with the `raster` feature)
+//! - Signed Apple Wallet passes with the `pkpass` feature
+//! - Parallel batch generation from CSV with a hashed manifest (`batch` feature)
+//! - Printable A4 / A5 PDF invoices and QR grids with the `pdf` feature
+//! - Decoding of QR codes in PNG / JPEG images with the `scan` feature
+//! - Payload linting beyond what the decoder requires
+//! - HTTP service with OpenAPI description (`server` feature)
+//! - Validating request extractors for axum and actix-web (`axum` / `actix-web` features)
+//! - C API with a cbindgen-generated header (`ffi` feature)
+//! - Python bindings with type stubs (`python` feature)
+//! - WebAssembly bindings with TypeScript types (`wasm` feature)
+//! - Kotlin / Swift bindings for mobile apps generated with UniFFI (`uniffi` feature)
+//! - Serde support with stable representations and a JSON Schema (`serde` feature)
+//! - Invoice lifecycle with in-memory and SQLite (`sqlite` feature) stores
 //! - Full error handling with `PromptPayError`
 //!
 //! ## Example
@@ -20,12 +37,53 @@
 //! println!("{}", payload); // EMVCo-compliant payload
 //! ```
 
+pub mod additional_data;
+#[cfg(feature = "batch")]
+pub mod batch;
+pub mod barcode;
+pub mod card;
 pub mod constants;
 pub mod error;
+pub mod escpos;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+pub mod extract;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod html;
+pub mod invoice;
+#[cfg(feature = "pdf")]
+pub mod pdf;
+#[cfg(feature = "pkpass")]
+pub mod pkpass;
 pub mod promptpay;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod qr;
+#[cfg(feature = "qrcode")]
+pub mod render;
+#[cfg(feature = "scan")]
+pub mod scan;
+#[cfg(feature = "serde")]
+pub mod schema;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod crc;
+pub mod donation;
+pub mod language;
+pub mod lint;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+pub mod ndef;
+pub mod simulator;
+pub mod slip;
+pub mod tlv;
 pub mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use error::PromptPayError;
+pub use error::{ErrorKind, PromptPayError};
 pub use promptpay::PromptPayQR;
-pub use constants::{CountryCode, CurrencyCode};
+pub use constants::{CountryCode, CurrencyCode, EcLevel};
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!("promptpay");
@@ -0,0 +1,313 @@
+//! **Payload linting**: checks a scanned or received payload beyond what
+//! [`PromptPayQR::parse`] requires.
+//!
+//! `parse` is lenient so that codes printed by other generators still decode;
+//! [`lint`] reports everything a strict EMVCo / Thai QR validator would
+//! complain about, from a wrong CRC to a static QR that carries an amount.
+//!
+//! # Example
+//! ```rust
+//! use promptpay_rs::{PromptPayQR, lint::{Severity, lint}};
+//!
+//! let payload = PromptPayQR::new("0812345678").create().unwrap();
+//! assert!(lint(&payload).is_empty());
+//!
+//! // แก้ CRC ให้ผิด
+//! let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+//! let issues = lint(&tampered);
+//! assert_eq!(issues[0].severity, Severity::Error);
+//! assert_eq!(issues[0].tag.as_deref(), Some("63"));
+//! ```
+
+use crate::{
+    PromptPayQR,
+    constants::MAX_PAYLOAD_LENGTH,
+    crc::{calculate_crc, verify_crc},
+    error::ErrorKind,
+    tlv,
+};
+use std::fmt;
+
+/// How serious a [`LintIssue`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum Severity {
+    /// Banking apps may accept the payload, but it does not follow the specification
+    Warning,
+    /// The payload is rejected by [`PromptPayQR::parse`] or by banking apps
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// A single problem found by [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LintIssue {
+    /// How serious the problem is
+    pub severity: Severity,
+    /// Top-level tag the problem belongs to, if any (e.g. `"54"`)
+    pub tag: Option<String>,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl LintIssue {
+    fn new(severity: Severity, tag: Option<&str>, message: String) -> Self {
+        LintIssue {
+            severity,
+            tag: tag.map(|t| t.to_string()),
+            message,
+        }
+    }
+
+    /// Returns `true` for [`Severity::Error`].
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.tag {
+            Some(tag) => write!(f, "{} (tag {}): {}", self.severity, tag, self.message),
+            None => write!(f, "{}: {}", self.severity, self.message),
+        }
+    }
+}
+
+/// Checks a payload and returns every problem found, errors first.
+///
+/// Errors cover everything that makes [`PromptPayQR::parse`] fail (CRC, TLV
+/// structure, missing tags) plus duplicate tags and the 512-character limit.
+/// Warnings cover deviations banking apps usually tolerate: the point of
+/// initiation method (tag `01`) not matching the presence of an amount, amounts
+/// not written with two decimals, over-long or non-Latin merchant name / city,
+/// and a lowercase CRC.
+///
+/// # Returns
+/// An empty `Vec` if the payload is clean
+pub fn lint(payload: &str) -> Vec<LintIssue> {
+    let payload = payload.trim();
+    let mut issues = Vec::new();
+    let error = |tag, message: String| LintIssue::new(Severity::Error, tag, message);
+    let warning = |tag, message: String| LintIssue::new(Severity::Warning, tag, message);
+
+    let length = payload.chars().count();
+    if length > MAX_PAYLOAD_LENGTH {
+        issues.push(error(
+            None,
+            format!(
+                "Payload is {} characters, the maximum is {}",
+                length, MAX_PAYLOAD_LENGTH
+            ),
+        ));
+    }
+
+    // แจ้ง CRC ที่ถูกต้องด้วย เพื่อให้แยกออกได้ว่าข้อมูลเสียหรือแค่คำนวณ CRC ผิด
+    if let Err(e) = PromptPayQR::parse(payload) {
+        let message = match e.kind() {
+            ErrorKind::CrcMismatch => match payload.len().checked_sub(4) {
+                Some(end) if payload.is_char_boundary(end) && payload[..end].ends_with("6304") => {
+                    format!(
+                        "CRC is {}, expected {:04X}",
+                        &payload[end..],
+                        calculate_crc(&payload[..end])
+                    )
+                }
+                _ => "Payload does not end with a CRC field (6304 + 4 hex digits)".to_string(),
+            },
+            _ => e.to_string(),
+        };
+        let tag = (e.kind() == ErrorKind::CrcMismatch).then_some("63");
+        issues.push(error(tag, message));
+    }
+
+    // ถ้าโครงสร้าง TLV เสียก็ตรวจอย่างอื่นต่อไม่ได้
+    let Ok(fields) = tlv::parse(payload) else {
+        return issues;
+    };
+
+    for (i, field) in fields.iter().enumerate() {
+        if fields[..i].iter().any(|f| f.tag == field.tag) {
+            issues.push(error(
+                Some(field.tag),
+                format!("Tag {} appears more than once", field.tag),
+            ));
+        }
+    }
+
+    // ID 01: 11 = static (ไม่มีจำนวนเงิน), 12 = dynamic (มีจำนวนเงิน)
+    let amount = tlv::find(&fields, "54");
+    match (tlv::find(&fields, "01"), amount) {
+        (None, _) => issues.push(warning(
+            Some("01"),
+            "Point of initiation method is missing".to_string(),
+        )),
+        (Some("11"), Some(_)) => issues.push(warning(
+            Some("01"),
+            "Static QR (11) carries an amount, dynamic (12) expected".to_string(),
+        )),
+        (Some("12"), None) => issues.push(warning(
+            Some("01"),
+            "Dynamic QR (12) has no amount".to_string(),
+        )),
+        (Some("11" | "12"), _) => {}
+        (Some(value), _) => issues.push(warning(
+            Some("01"),
+            format!("Point of initiation method must be 11 or 12: {}", value),
+        )),
+    }
+
+    if let Some(value) = amount {
+        let decimals = value.split_once('.').map(|(_, d)| d.len());
+        if value.parse::<f64>().is_ok_and(|a| a == 0.0) {
+            issues.push(warning(Some("54"), "Amount is zero".to_string()));
+        } else if value.len() > 13 {
+            issues.push(warning(
+                Some("54"),
+                format!("Amount must not exceed 13 characters: {}", value),
+            ));
+        } else if decimals.is_some_and(|d| d > 2) || value.starts_with('.') || value.ends_with('.')
+        {
+            issues.push(warning(
+                Some("54"),
+                format!("Amount should have at most two decimals: {}", value),
+            ));
+        }
+    }
+
+    // ID 59, 60: ตัวอักษรละตินเท่านั้น (ภาษาไทยอยู่ใน tag 64)
+    for (tag, name, max) in [("59", "Merchant name", 25), ("60", "Merchant city", 15)] {
+        let Some(value) = tlv::find(&fields, tag) else {
+            continue;
+        };
+        if value.chars().count() > max {
+            issues.push(warning(
+                Some(tag),
+                format!("{} must not exceed {} characters: {}", name, max, value),
+            ));
+        }
+        if !value.is_ascii() {
+            issues.push(warning(
+                Some(tag),
+                format!(
+                    "{} should be Latin text, use tag 64 for Thai: {}",
+                    name, value
+                ),
+            ));
+        }
+    }
+
+    if verify_crc(payload, "63")
+        && payload
+            .bytes()
+            .rev()
+            .take(4)
+            .any(|b| b.is_ascii_lowercase())
+    {
+        issues.push(warning(
+            Some("63"),
+            "CRC should be written in uppercase hex".to_string(),
+        ));
+    }
+
+    issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crc::calculate_crc;
+
+    // ประกอบ payload จากฟิลด์ที่กำหนดเองพร้อม CRC ที่ถูกต้อง
+    fn with_crc(fields: &str) -> String {
+        let data = format!("{}6304", fields);
+        format!("{}{:04X}", data, calculate_crc(&data))
+    }
+
+    /// ทดสอบ payload ที่ถูกต้องและ CRC ที่ผิด (แจ้ง CRC ที่ควรเป็น)
+    #[test]
+    fn test_lint_clean_and_crc() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(50.0).set_merchant_name("COFFEE HOUSE");
+        let payload = qr.create().unwrap();
+        assert!(lint(&payload).is_empty());
+
+        let expected = &payload[payload.len() - 4..];
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        let issues = lint(&tampered);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].is_error());
+        assert_eq!(
+            issues[0].to_string(),
+            format!("error (tag 63): CRC is 0000, expected {}", expected)
+        );
+
+        let issues = lint("not a payload");
+        assert!(issues[0].message.contains("CRC field"));
+    }
+
+    /// ทดสอบคำเตือนที่ parse ยอมรับได้: tag 01 ไม่ตรงกับจำนวนเงิน, ทศนิยม, ชื่อร้าน, CRC ตัวพิมพ์เล็ก
+    #[test]
+    fn test_lint_warnings() {
+        let account = tlv::encode("29", "0016A00000067701011101130066812345678").unwrap();
+        let name = tlv::encode("59", "VERY LONG MERCHANT NAME COMPANY").unwrap();
+        let amount = tlv::encode("54", "100.505").unwrap();
+        let payload = with_crc(&format!(
+            "000201010211{}5303764{}5802TH{}",
+            account, amount, name
+        ));
+        let issues = lint(&payload);
+        assert!(issues.iter().all(|i| !i.is_error()), "{:?}", issues);
+        let tags: Vec<_> = issues.iter().map(|i| i.tag.as_deref().unwrap()).collect();
+        assert_eq!(tags, ["01", "54", "59"]);
+
+        // หา payload ที่ CRC มีตัวอักษร A-F แล้วเปลี่ยนเป็นตัวพิมพ์เล็ก
+        let payload = (1..)
+            .map(|amount| {
+                let mut qr = PromptPayQR::new("0812345678");
+                qr.set_amount(amount as f64);
+                qr.create().unwrap()
+            })
+            .find(|p| p[p.len() - 4..].bytes().any(|b| b.is_ascii_alphabetic()))
+            .unwrap();
+        let (data, crc) = payload.split_at(payload.len() - 4);
+        let issues = lint(&format!("{}{}", data, crc.to_lowercase()));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].tag.as_deref(), Some("63"));
+    }
+
+    /// ทดสอบ tag ซ้ำและ payload ที่ parse ไม่ได้
+    #[test]
+    fn test_lint_errors() {
+        let account = tlv::encode("29", "0016A00000067701011101130066812345678").unwrap();
+        let payload = with_crc(&format!("000201010211{}5303764{}5802TH", account, account));
+        let issues = lint(&payload);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].message, "Tag 29 appears more than once");
+
+        let payload = with_crc("0002010102115303764");
+        let issues = lint(&payload);
+        assert!(issues[0].is_error());
+        assert!(issues[0].tag.is_none());
+
+        // ตัวอักษรหลาย byte ท้าย payload
+        let issues = lint("0000กก");
+        assert!(issues.iter().any(LintIssue::is_error));
+
+        // ความยาวนับเป็นตัวอักษร ไม่ใช่ byte
+        let issues = lint(&"0".repeat(513));
+        assert_eq!(issues[0].message, "Payload is 513 characters, the maximum is 512");
+        let issues = lint(&"ก".repeat(512));
+        assert!(!issues.iter().any(|issue| issue.message.contains("maximum is 512")));
+    }
+}
@@ -0,0 +1,287 @@
+//! **Kotlin and Swift bindings** for Android and iOS apps, generated with
+//! [UniFFI](https://mozilla.github.io/uniffi-rs/) (requires the `uniffi` feature).
+//!
+//! Build the library for each target, then generate the sources from it with
+//! the bundled `uniffi-bindgen` tool (`uniffi-cli` feature); package names are
+//! set in `uniffi.toml`:
+//!
+//! ```sh
+//! cargo rustc --lib --release --features uniffi --crate-type cdylib
+//! cargo run --features uniffi-cli --bin uniffi-bindgen -- generate \
+//!     --library target/release/libpromptpay_rs.so --language kotlin --out-dir bindings/kotlin
+//! cargo run --features uniffi-cli --bin uniffi-bindgen -- generate \
+//!     --library target/release/libpromptpay_rs.so --language swift --out-dir bindings/swift
+//! ```
+//!
+//! The apps get the `PromptPayQr` class, [`verify`], [`lint_payload`] and
+//! [`parse_slip`], with [`SlipRef`], [`LintIssue`], [`MerchantType`],
+//! [`EcLevel`], [`CountryCode`] and [`CurrencyCode`] as native records and
+//! enums. Errors are thrown as `PromptPayException` (Kotlin) or
+//! `PromptPayError` (Swift), one case per [`ErrorKind`]:
+//!
+//! ```kotlin
+//! val qr = PromptPayQr("0812345678")
+//! qr.setAmount(150.0)
+//! val payload = qr.create()
+//!
+//! try {
+//!     val slip = parseSlip(scanned)
+//!     println("${slip.sendingBank} ${slip.transactionRef}")
+//! } catch (e: PromptPayException.CrcMismatch) { /* ... */ }
+//! ```
+
+use crate::{
+    CountryCode, CurrencyCode, EcLevel, constants::MerchantType, error::ErrorKind,
+    language::MerchantLanguage, lint::LintIssue, slip::SlipRef,
+};
+use std::{
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// Error thrown by the bindings, one case per [`ErrorKind`].
+///
+/// Mirrors [`crate::PromptPayError`] because UniFFI errors must be enums.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum PromptPayError {
+    /// Input supplied by the caller is missing or malformed
+    InvalidInput(String),
+    /// A payload could not be decoded
+    InvalidPayload(String),
+    /// The payload CRC does not match its content
+    CrcMismatch(String),
+    /// The QR code could not be rendered
+    Render(String),
+    /// Any other error
+    Other(String),
+}
+
+impl fmt::Display for PromptPayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PromptPayError::InvalidInput(message)
+            | PromptPayError::InvalidPayload(message)
+            | PromptPayError::CrcMismatch(message)
+            | PromptPayError::Render(message)
+            | PromptPayError::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for PromptPayError {}
+
+impl From<crate::PromptPayError> for PromptPayError {
+    fn from(e: crate::PromptPayError) -> Self {
+        let message = e.to_string();
+        match e.kind() {
+            ErrorKind::InvalidInput => PromptPayError::InvalidInput(message),
+            ErrorKind::InvalidPayload => PromptPayError::InvalidPayload(message),
+            ErrorKind::CrcMismatch => PromptPayError::CrcMismatch(message),
+            ErrorKind::Render => PromptPayError::Render(message),
+            _ => PromptPayError::Other(message),
+        }
+    }
+}
+
+/// A PromptPay payload, mirroring [`crate::PromptPayQR`].
+///
+/// UniFFI objects are shared between threads, so the setters lock the payload
+/// instead of taking `&mut self`.
+#[derive(Debug, uniffi::Object)]
+pub struct PromptPayQr(Mutex<crate::PromptPayQR>);
+
+impl PromptPayQr {
+    fn qr(&self) -> MutexGuard<'_, crate::PromptPayQR> {
+        // ข้อมูลไม่เสียหายแม้ thread อื่น panic ระหว่างถือ lock
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[uniffi::export]
+impl PromptPayQr {
+    /// Creates a credit transfer QR for a mobile number, national / tax ID or e-wallet ID.
+    #[uniffi::constructor]
+    pub fn new(proxy: String) -> Arc<Self> {
+        Arc::new(PromptPayQr(Mutex::new(crate::PromptPayQR::new(&proxy))))
+    }
+
+    /// Creates a bill payment QR (tag 30).
+    #[uniffi::constructor]
+    pub fn bill_payment(biller_id: String, ref1: String, ref2: Option<String>) -> Arc<Self> {
+        let qr = crate::PromptPayQR::bill_payment(&biller_id, &ref1, ref2.as_deref());
+        Arc::new(PromptPayQr(Mutex::new(qr)))
+    }
+
+    /// Decodes and CRC-checks a payload.
+    #[uniffi::constructor]
+    pub fn parse(payload: String) -> Result<Arc<Self>, PromptPayError> {
+        let qr = crate::PromptPayQR::parse(&payload)?;
+        Ok(Arc::new(PromptPayQr(Mutex::new(qr))))
+    }
+
+    /// Sets the amount in THB, making the QR dynamic.
+    pub fn set_amount(&self, amount: f64) {
+        self.qr().set_amount(amount);
+    }
+
+    /// Sets the merchant name in Latin script (tag 59).
+    pub fn set_merchant_name(&self, name: String) {
+        self.qr().set_merchant_name(&name);
+    }
+
+    /// Sets the merchant city in Latin script (tag 60).
+    pub fn set_merchant_city(&self, city: String) {
+        self.qr().set_merchant_city(&city);
+    }
+
+    /// Sets the merchant name in Thai (tag 64).
+    pub fn set_thai_name(&self, name: String) {
+        self.qr()
+            .set_merchant_language(MerchantLanguage::thai(&name));
+    }
+
+    /// Builds the payload string.
+    pub fn create(&self) -> Result<String, PromptPayError> {
+        Ok(self.qr().create()?)
+    }
+
+    /// Renders the payload as an SVG document with the built-in encoder.
+    pub fn to_svg(&self, ec_level: EcLevel) -> Result<String, PromptPayError> {
+        Ok(self.qr().to_qr_matrix(ec_level)?.to_svg(None))
+    }
+
+    /// Proxy as given, or as decoded from the payload.
+    pub fn merchant_id(&self) -> String {
+        self.qr().merchant_id().to_string()
+    }
+
+    /// Kind of proxy.
+    pub fn merchant_type(&self) -> MerchantType {
+        self.qr().merchant_type()
+    }
+
+    /// Amount in THB, `null` / `nil` for a static QR.
+    pub fn amount(&self) -> Option<f64> {
+        self.qr().amount()
+    }
+
+    /// Country code (tag 58).
+    pub fn country_code(&self) -> CountryCode {
+        self.qr().country_code()
+    }
+
+    /// Currency (tag 53).
+    pub fn currency_code(&self) -> CurrencyCode {
+        self.qr().currency_code()
+    }
+
+    /// Ref1 of a bill payment QR.
+    pub fn reference1(&self) -> Option<String> {
+        self.qr().reference1().map(str::to_string)
+    }
+
+    /// Ref2 of a bill payment QR.
+    pub fn reference2(&self) -> Option<String> {
+        self.qr().reference2().map(str::to_string)
+    }
+
+    /// Merchant name (tag 59).
+    pub fn merchant_name(&self) -> Option<String> {
+        self.qr().merchant_name().map(str::to_string)
+    }
+
+    /// Merchant city (tag 60).
+    pub fn merchant_city(&self) -> Option<String> {
+        self.qr().merchant_city().map(str::to_string)
+    }
+
+    /// Merchant name in Thai (tag 64).
+    pub fn thai_name(&self) -> Option<String> {
+        self.qr()
+            .merchant_language()
+            .map(|language| language.merchant_name.clone())
+    }
+}
+
+/// Returns `true` if the payload decodes, its CRC matches and
+/// [`lint`](crate::lint::lint) finds no errors.
+#[uniffi::export]
+pub fn verify(payload: String) -> bool {
+    crate::PromptPayQR::parse(&payload).is_ok()
+        && !lint_payload(payload).iter().any(LintIssue::is_error)
+}
+
+/// Lists every problem a strict validator reports for a payload (see [`crate::lint`]).
+#[uniffi::export]
+pub fn lint_payload(payload: String) -> Vec<LintIssue> {
+    crate::lint::lint(&payload)
+}
+
+/// Decodes and CRC-checks the verification QR printed on a bank transfer slip.
+#[uniffi::export]
+pub fn parse_slip(payload: String) -> Result<SlipRef, PromptPayError> {
+    Ok(SlipRef::parse(&payload)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::Severity;
+
+    /// ทดสอบการสร้าง, parse และ render SVG ผ่าน binding
+    #[test]
+    fn test_mobile_round_trip() {
+        let qr = PromptPayQr::new("0812345678".to_string());
+        qr.set_amount(150.0);
+        qr.set_merchant_name("COFFEE HOUSE".to_string());
+        qr.set_thai_name("ร้านกาแฟ".to_string());
+        let payload = qr.create().unwrap();
+        assert!(verify(payload.clone()));
+        assert!(lint_payload(payload.clone()).is_empty());
+
+        let parsed = PromptPayQr::parse(payload).unwrap();
+        assert_eq!(parsed.merchant_type(), MerchantType::MobileNumber);
+        assert_eq!(parsed.amount(), Some(150.0));
+        assert_eq!(parsed.country_code(), CountryCode::Thailand);
+        assert_eq!(parsed.currency_code(), CurrencyCode::THB);
+        assert_eq!(parsed.merchant_name().as_deref(), Some("COFFEE HOUSE"));
+        assert_eq!(parsed.thai_name().as_deref(), Some("ร้านกาแฟ"));
+        assert!(qr.to_svg(EcLevel::H).unwrap().starts_with("<svg"));
+
+        let bill =
+            PromptPayQr::bill_payment("010753600031508".to_string(), "INV001".to_string(), None);
+        let parsed = PromptPayQr::parse(bill.create().unwrap()).unwrap();
+        assert_eq!(parsed.merchant_type(), MerchantType::BillerId);
+        assert_eq!(parsed.reference1().as_deref(), Some("INV001"));
+    }
+
+    /// ทดสอบการอ่าน slip และการแปลงข้อผิดพลาดเป็น PromptPayError ของแอป
+    #[test]
+    fn test_mobile_slip_and_errors() {
+        let payload = SlipRef::new("004", "015021103612BPM01234")
+            .create()
+            .unwrap();
+        let slip = parse_slip(payload.clone()).unwrap();
+        assert_eq!(slip.sending_bank, "004");
+        assert_eq!(slip.transaction_ref, "015021103612BPM01234");
+
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        assert!(matches!(
+            parse_slip(tampered),
+            Err(PromptPayError::CrcMismatch(_))
+        ));
+
+        let payload = PromptPayQr::new("0812345678".to_string()).create().unwrap();
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        assert!(!verify(tampered.clone()));
+        assert_eq!(lint_payload(tampered.clone())[0].severity, Severity::Error);
+        assert!(matches!(
+            PromptPayQr::parse(tampered),
+            Err(PromptPayError::CrcMismatch(_))
+        ));
+        let error = PromptPayQr::new(String::new()).create().unwrap_err();
+        assert!(matches!(error, PromptPayError::InvalidInput(_)));
+        assert!(!error.to_string().is_empty());
+    }
+}
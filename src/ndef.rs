@@ -0,0 +1,387 @@
+//! **NFC NDEF** encoding of payloads for tap stickers.
+//!
+//! The payload from [`PromptPayQR::create`] is carried in a single NDEF record,
+//! either a well-known text record (`T`, readable by any NFC app) or an NFC Forum
+//! external type record. [`PromptPayQR::to_ntag_bytes`] wraps the message in the
+//! NDEF TLV used by NTAG213/215/216 user memory, ready to be written from page 4.
+//!
+//! # Example
+//! ```rust
+//! use promptpay_rs::{PromptPayQR, ndef::NdefRecordType};
+//!
+//! let mut qr = PromptPayQR::new("0812345678");
+//! qr.set_amount(50.0);
+//! let bytes = qr.to_ntag_bytes(&NdefRecordType::Text).unwrap();
+//! assert_eq!(bytes[0], 0x03); // NDEF message TLV
+//!
+//! let scanned = PromptPayQR::from_ndef(&bytes).unwrap();
+//! assert_eq!(scanned.amount(), Some(50.0));
+//! ```
+
+use crate::{PromptPayError, PromptPayQR, error::ErrorKind};
+
+/// Suggested type name for [`NdefRecordType::External`] (EMVCo Merchant Presented Mode payload).
+pub const DEFAULT_EXTERNAL_TYPE: &str = "emvco.com:mpm";
+
+// bit ใน header ของ record
+const FLAG_MB: u8 = 0x80; // record แรกของ message
+const FLAG_ME: u8 = 0x40; // record สุดท้ายของ message
+const FLAG_CF: u8 = 0x20; // record แบบแบ่งเป็นชิ้น (chunked)
+const FLAG_SR: u8 = 0x10; // short record (ความยาว payload 1 byte)
+const FLAG_IL: u8 = 0x08; // มีฟิลด์ ID
+const TNF_WELL_KNOWN: u8 = 0x01;
+const TNF_EXTERNAL: u8 = 0x04;
+
+// TLV ในหน่วยความจำของ NFC Forum Type 2 Tag (NTAG)
+const TLV_NULL: u8 = 0x00;
+const TLV_NDEF: u8 = 0x03;
+const TLV_TERMINATOR: u8 = 0xFE;
+const PAGE_SIZE: usize = 4;
+
+/// Kind of NDEF record that carries the payload.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum NdefRecordType {
+    /// Well-known text record (`T`, UTF-8, language `en`)
+    #[default]
+    Text,
+    /// NFC Forum external type record, e.g. [`DEFAULT_EXTERNAL_TYPE`]
+    /// (`"domain:type"`, ASCII, case-insensitive). [`PromptPayQR::from_ndef`] only
+    /// reads records of [`DEFAULT_EXTERNAL_TYPE`].
+    External(String),
+}
+
+impl PromptPayQR {
+    /// Encodes the payload as an **NDEF message** with a single record.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - NDEF message bytes
+    /// * `Err(PromptPayError)` - If the payload cannot be created, or the external
+    ///   type name is not 1-255 printable ASCII characters
+    pub fn to_ndef_message(&self, record_type: &NdefRecordType) -> Result<Vec<u8>, PromptPayError> {
+        let payload = self.create()?;
+        let (tnf, type_name, data) = match record_type {
+            NdefRecordType::Text => {
+                // status byte: bit 7 = 0 (UTF-8), bit 0-5 = ความยาวรหัสภาษา
+                let mut data = vec![2, b'e', b'n'];
+                data.extend(payload.bytes());
+                (TNF_WELL_KNOWN, "T".to_string(), data)
+            }
+            NdefRecordType::External(name) => {
+                let valid =
+                    (1..=255).contains(&name.len()) && name.bytes().all(|b| b.is_ascii_graphic());
+                if !valid {
+                    return Err(PromptPayError::with_kind(
+                        ErrorKind::InvalidInput,
+                        &format!("Invalid NDEF external type name: {:?}", name),
+                    ));
+                }
+                (
+                    TNF_EXTERNAL,
+                    name.to_ascii_lowercase(),
+                    payload.into_bytes(),
+                )
+            }
+        };
+
+        let mut message = Vec::with_capacity(data.len() + type_name.len() + 6);
+        let short = data.len() <= u8::MAX as usize;
+        let flags = FLAG_MB | FLAG_ME | if short { FLAG_SR } else { 0 };
+        message.push(flags | tnf);
+        message.push(type_name.len() as u8);
+        if short {
+            message.push(data.len() as u8);
+        } else {
+            message.extend((data.len() as u32).to_be_bytes());
+        }
+        message.extend(type_name.bytes());
+        message.extend(data);
+        Ok(message)
+    }
+
+    /// Encodes the payload for the user memory of an **NTAG** chip (NFC Forum Type 2 Tag).
+    ///
+    /// The NDEF message is wrapped in an NDEF TLV followed by a terminator TLV and
+    /// padded with zeros to whole 4-byte pages. Write the result from page 4.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - Bytes to write
+    /// * `Err(PromptPayError)` - Same as [`PromptPayQR::to_ndef_message`]
+    pub fn to_ntag_bytes(&self, record_type: &NdefRecordType) -> Result<Vec<u8>, PromptPayError> {
+        let message = self.to_ndef_message(record_type)?;
+        let mut bytes = vec![TLV_NDEF];
+        // ความยาว TLV: 1 byte ถ้าน้อยกว่า 255, มิฉะนั้น 0xFF ตามด้วย 2 byte
+        if message.len() < 0xFF {
+            bytes.push(message.len() as u8);
+        } else {
+            bytes.push(0xFF);
+            bytes.extend((message.len() as u16).to_be_bytes());
+        }
+        bytes.extend(message);
+        bytes.push(TLV_TERMINATOR);
+        bytes.resize(bytes.len().div_ceil(PAGE_SIZE) * PAGE_SIZE, 0);
+        Ok(bytes)
+    }
+
+    /// Decodes a payload read from an NFC tag.
+    ///
+    /// Accepts either a bare NDEF message or NTAG user memory (TLV-wrapped, as
+    /// written by [`PromptPayQR::to_ntag_bytes`]). The first text record or
+    /// [`DEFAULT_EXTERNAL_TYPE`] record is parsed with [`PromptPayQR::parse`]; other
+    /// records, such as an Android Application Record, are skipped.
+    ///
+    /// # Returns
+    /// * `Ok(PromptPayQR)` - Parsed payload
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidPayload`] if the data is not a
+    ///   valid NDEF message or has no text / PromptPay external record, or any error
+    ///   from `parse`
+    pub fn from_ndef(bytes: &[u8]) -> Result<Self, PromptPayError> {
+        let message = match bytes.first() {
+            // header ของ record แรกมี MB เสมอ (>= 0x80) จึงแยกจาก TLV ได้
+            Some(&first) if first & FLAG_MB != 0 => bytes,
+            _ => find_ndef_tlv(bytes)?,
+        };
+
+        let mut pos = 0;
+        while pos < message.len() {
+            let record = read_record(message, &mut pos)?;
+            let text = match (record.tnf, record.type_name) {
+                (TNF_WELL_KNOWN, b"T") => {
+                    let status = *record
+                        .payload
+                        .first()
+                        .ok_or_else(|| invalid("Empty NDEF text record"))?;
+                    if status & 0x80 != 0 {
+                        return Err(invalid("UTF-16 NDEF text records are not supported"));
+                    }
+                    let start = 1 + (status & 0x3F) as usize;
+                    record
+                        .payload
+                        .get(start..)
+                        .ok_or_else(|| invalid("Truncated NDEF text record"))?
+                }
+                (TNF_EXTERNAL, name)
+                    if name.eq_ignore_ascii_case(DEFAULT_EXTERNAL_TYPE.as_bytes()) =>
+                {
+                    record.payload
+                }
+                _ => {
+                    if record.last {
+                        break;
+                    }
+                    continue;
+                }
+            };
+            let text =
+                std::str::from_utf8(text).map_err(|_| invalid("NDEF record is not valid UTF-8"))?;
+            return PromptPayQR::parse(text);
+        }
+        Err(invalid(&format!(
+            "NDEF message has no text or {} record",
+            DEFAULT_EXTERNAL_TYPE
+        )))
+    }
+}
+
+// record ที่อ่านได้ (อ้างอิงข้อมูลเดิม ไม่คัดลอก)
+struct Record<'a> {
+    tnf: u8,
+    type_name: &'a [u8],
+    payload: &'a [u8],
+    last: bool,
+}
+
+fn read_record<'a>(message: &'a [u8], pos: &mut usize) -> Result<Record<'a>, PromptPayError> {
+    let mut take = |len: usize| -> Result<&'a [u8], PromptPayError> {
+        // ความยาวมาจากข้อมูลบน tag จึงอาจล้น usize บน target 32 bit
+        let slice = pos
+            .checked_add(len)
+            .and_then(|end| message.get(*pos..end))
+            .ok_or_else(|| invalid("Truncated NDEF record"))?;
+        *pos += len;
+        Ok(slice)
+    };
+    let header = take(1)?[0];
+    if header & FLAG_CF != 0 {
+        return Err(invalid("Chunked NDEF records are not supported"));
+    }
+    let type_len = take(1)?[0] as usize;
+    let payload_len = if header & FLAG_SR != 0 {
+        take(1)?[0] as usize
+    } else {
+        let b = take(4)?;
+        u32::from_be_bytes([b[0], b[1], b[2], b[3]]) as usize
+    };
+    let id_len = if header & FLAG_IL != 0 {
+        take(1)?[0] as usize
+    } else {
+        0
+    };
+    let type_name = take(type_len)?;
+    take(id_len)?;
+    let payload = take(payload_len)?;
+    Ok(Record {
+        tnf: header & 0x07,
+        type_name,
+        payload,
+        last: header & FLAG_ME != 0,
+    })
+}
+
+// หา NDEF message TLV ในหน่วยความจำของ tag (ข้าม NULL TLV และ TLV อื่น)
+fn find_ndef_tlv(bytes: &[u8]) -> Result<&[u8], PromptPayError> {
+    let mut pos = 0;
+    while let Some(&tag) = bytes.get(pos) {
+        pos += 1;
+        match tag {
+            TLV_NULL => continue,
+            TLV_TERMINATOR => break,
+            _ => {}
+        }
+        let mut len = *bytes
+            .get(pos)
+            .ok_or_else(|| invalid("Truncated NDEF TLV"))? as usize;
+        pos += 1;
+        if len == 0xFF {
+            let b = bytes
+                .get(pos..pos + 2)
+                .ok_or_else(|| invalid("Truncated NDEF TLV"))?;
+            len = u16::from_be_bytes([b[0], b[1]]) as usize;
+            pos += 2;
+        }
+        let value = bytes
+            .get(pos..pos + len)
+            .ok_or_else(|| invalid("Truncated NDEF TLV"))?;
+        if tag == TLV_NDEF {
+            return Ok(value);
+        }
+        pos += len;
+    }
+    Err(invalid("No NDEF message found"))
+}
+
+fn invalid(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidPayload, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบ text record: header, status byte และการถอดกลับ
+    #[test]
+    fn test_ndef_text_record() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(50.0);
+        let payload = qr.create().unwrap();
+        let message = qr.to_ndef_message(&NdefRecordType::Text).unwrap();
+
+        let mut expected = vec![0xD1, 1, payload.len() as u8 + 3, b'T', 2, b'e', b'n'];
+        expected.extend(payload.bytes());
+        assert_eq!(message, expected);
+        assert_eq!(
+            PromptPayQR::from_ndef(&message).unwrap().create().unwrap(),
+            payload
+        );
+    }
+
+    /// ทดสอบ external type record ใน TLV ของ NTAG พร้อม padding ให้ครบ page
+    #[test]
+    fn test_ntag_external_record() {
+        let qr = PromptPayQR::new("0812345678");
+        let payload = qr.create().unwrap();
+        let record_type = NdefRecordType::External("EMVCo.com:MPM".to_string());
+        let bytes = qr.to_ntag_bytes(&record_type).unwrap();
+
+        let message_len = 3 + 13 + payload.len();
+        assert_eq!(
+            &bytes[..5],
+            &[0x03, message_len as u8, 0xD4, 13, payload.len() as u8]
+        );
+        assert_eq!(&bytes[5..18], DEFAULT_EXTERNAL_TYPE.as_bytes());
+        assert_eq!(bytes[2 + message_len], 0xFE);
+        assert!(bytes.len().is_multiple_of(4));
+
+        // tag จริงมักมี Lock Control TLV นำหน้า
+        let mut memory = vec![0x01, 0x03, 0xA0, 0x0C, 0x34, 0x00];
+        memory.extend(&bytes);
+        assert_eq!(
+            PromptPayQR::from_ndef(&memory).unwrap().create().unwrap(),
+            payload
+        );
+
+        let invalid_type = NdefRecordType::External(String::new());
+        let err = qr.to_ndef_message(&invalid_type).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    /// ทดสอบ record ยาว (payload > 255 byte) และข้อมูลที่ไม่ถูกต้อง
+    #[test]
+    fn test_ndef_long_record_and_errors() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_merchant_name("COFFEE HOUSE")
+            .set_merchant_city("BANGKOK")
+            .set_additional_data(crate::additional_data::AdditionalData {
+                bill_number: Some("B".repeat(25)),
+                store_label: Some("S".repeat(25)),
+                terminal_label: Some("T".repeat(25)),
+                ..Default::default()
+            })
+            .set_merchant_language(crate::language::MerchantLanguage::thai(
+                "ร้านกาแฟสดหน้าปากซอย",
+            ));
+        let payload = qr.create().unwrap();
+        let message = qr.to_ndef_message(&NdefRecordType::Text).unwrap();
+        assert!(message.len() > 255);
+        assert_eq!(message[0], 0xC1); // ไม่มี SR
+        let ntag = qr.to_ntag_bytes(&NdefRecordType::Text).unwrap();
+        assert_eq!(&ntag[..2], &[0x03, 0xFF]);
+        assert_eq!(
+            PromptPayQR::from_ndef(&ntag).unwrap().create().unwrap(),
+            payload
+        );
+
+        let err = PromptPayQR::from_ndef(&message[..10]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPayload);
+        // URI record เท่านั้น → ไม่มี payload ที่ใช้ได้
+        let uri = [0xD1, 1, 4, b'U', 0x04, b'a', b'.', b'b'];
+        assert!(PromptPayQR::from_ndef(&uri).is_err());
+        assert!(PromptPayQR::from_ndef(&[0x00, 0x00, 0xFE]).is_err());
+        // ความยาว payload 0xFFFFFFFF
+        let huge = [0xC1, 1, 0xFF, 0xFF, 0xFF, 0xFF, b'T'];
+        let err = PromptPayQR::from_ndef(&huge).unwrap_err();
+        assert_eq!(err.to_string(), "Truncated NDEF record");
+    }
+
+    /// ทดสอบว่า external record ชนิดอื่น (Android Application Record) ถูกข้ามไป
+    #[test]
+    fn test_ndef_skips_other_external_records() {
+        let qr = PromptPayQR::new("0812345678");
+        let payload = qr.create().unwrap();
+        let aar = |flags: u8| {
+            let mut record = vec![flags | FLAG_SR | TNF_EXTERNAL, 15, 11];
+            record.extend(b"android.com:pkgcom.example");
+            record
+        };
+        for record_type in [
+            NdefRecordType::Text,
+            NdefRecordType::External(DEFAULT_EXTERNAL_TYPE.to_string()),
+        ] {
+            // AAR นำหน้า record ของ PromptPay
+            let mut record = qr.to_ndef_message(&record_type).unwrap();
+            record[0] &= !FLAG_MB;
+            let mut message = aar(FLAG_MB);
+            message.extend(&record);
+            assert_eq!(
+                PromptPayQR::from_ndef(&message).unwrap().create().unwrap(),
+                payload
+            );
+        }
+
+        // external type อื่นที่ไม่ใช่ของ PromptPay
+        let other = NdefRecordType::External("example.com:promptpay".to_string());
+        let message = qr.to_ndef_message(&other).unwrap();
+        let err = PromptPayQR::from_ndef(&message).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPayload);
+        assert!(PromptPayQR::from_ndef(&aar(FLAG_MB | FLAG_ME)).is_err());
+    }
+}
@@ -0,0 +1,679 @@
+//! **PDF** invoices and posters (requires the `pdf` feature).
+//!
+//! Each entry is laid out as a card with its title, merchant details, a vector
+//! QR code, the masked PromptPay ID, amount, bill number and due date. Pages hold
+//! one entry ([`PdfLayout::Single`], e.g. an A4 invoice or A5 counter poster) or a
+//! grid of entries ([`PdfLayout::Grid`], e.g. one card per restaurant table).
+//!
+//! Text uses the built-in Helvetica font, which only covers Latin characters.
+//! Load a TrueType font with Thai glyphs ([`PdfDocument::set_font`]) to print
+//! Thai merchant names (tag `64`).
+//!
+//! ```toml
+//! [dependencies]
+//! promptpay-rs = { version = "0.6.0", features = ["pdf"] }
+//! ```
+
+use crate::{
+    EcLevel, PromptPayError, PromptPayQR, card::ThaiQrCard, error::ErrorKind,
+    qr::MIN_MODULE_SIZE_MM, utils::format_amount,
+};
+use pdf_writer::{
+    Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr,
+    types::{CidFontType, FontFlags, LineCapStyle, SystemInfo, TextRenderingMode, UnicodeCmap},
+};
+use std::collections::BTreeMap;
+
+const MM_TO_PT: f32 = 72.0 / 25.4;
+const PAGE_MARGIN_MM: f32 = 12.0;
+const QUIET_ZONE: usize = 4;
+const NAVY: [f32; 3] = [0.067, 0.208, 0.4];
+const MUTED: [f32; 3] = [0.42, 0.45, 0.5];
+
+// ความกว้างตัวอักษร ASCII 32-126 ของ Helvetica และ Helvetica-Bold (หน่วย 1/1000 em จาก AFM)
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// Paper size of the pages.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PageSize {
+    /// 210 × 297 mm
+    A4,
+    /// 148 × 210 mm
+    A5,
+    /// Any size in millimetres
+    Custom { width_mm: f32, height_mm: f32 },
+}
+
+impl PageSize {
+    // ขนาดหน้ากระดาษในหน่วย point (1/72 นิ้ว)
+    fn points(&self) -> (f32, f32) {
+        let (w, h) = match *self {
+            PageSize::A4 => (210.0, 297.0),
+            PageSize::A5 => (148.0, 210.0),
+            PageSize::Custom {
+                width_mm,
+                height_mm,
+            } => (width_mm, height_mm),
+        };
+        (w * MM_TO_PT, h * MM_TO_PT)
+    }
+}
+
+/// How entries are placed on the pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PdfLayout {
+    /// One entry per page
+    #[default]
+    Single,
+    /// `columns × rows` entries per page, separated by dashed cut lines
+    Grid { columns: u32, rows: u32 },
+}
+
+/// A payment QR with the details printed around it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfEntry {
+    /// Payload to print
+    pub qr: PromptPayQR,
+    /// Heading above the merchant name; defaults to the terminal label (tag `62`)
+    pub title: Option<String>,
+    /// Due date printed below the amount (e.g. `"31/12/2025"`)
+    pub due_date: Option<String>,
+}
+
+impl From<PromptPayQR> for PdfEntry {
+    fn from(qr: PromptPayQR) -> Self {
+        PdfEntry {
+            qr,
+            title: None,
+            due_date: None,
+        }
+    }
+}
+
+/// Builder for a PDF document of payment QR codes.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{PromptPayQR, additional_data::AdditionalData};
+/// use promptpay_rs::pdf::{PageSize, PdfDocument, PdfLayout};
+///
+/// let mut doc = PdfDocument::new(PageSize::A4);
+/// doc.set_layout(PdfLayout::Grid { columns: 3, rows: 4 });
+/// for table in 1..=12 {
+///     let mut qr = PromptPayQR::new("0812345678");
+///     qr.set_merchant_name("COFFEE HOUSE").set_additional_data(AdditionalData {
+///         terminal_label: Some(format!("TABLE {}", table)),
+///         ..AdditionalData::default()
+///     });
+///     doc.add(qr);
+/// }
+/// let pdf = doc.to_bytes().unwrap();
+/// assert!(pdf.starts_with(b"%PDF-"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PdfDocument {
+    page_size: PageSize,
+    layout: PdfLayout,
+    ec_level: EcLevel,
+    font: Option<Vec<u8>>, // ฟอนต์ TrueType สำหรับข้อความภาษาไทย (ถ้ามี)
+    entries: Vec<PdfEntry>,
+}
+
+impl PdfDocument {
+    /// Creates an empty document with one entry per page and level `M` QR codes.
+    pub fn new(page_size: PageSize) -> Self {
+        PdfDocument {
+            page_size,
+            layout: PdfLayout::Single,
+            ec_level: EcLevel::M,
+            font: None,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sets how entries are placed on the pages.
+    pub fn set_layout(&mut self, layout: PdfLayout) -> &mut Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the error correction level of the QR codes.
+    pub fn set_ec_level(&mut self, ec_level: EcLevel) -> &mut Self {
+        self.ec_level = ec_level;
+        self
+    }
+
+    /// Embeds a TrueType font (e.g. Sarabun or Noto Sans Thai) used for all text.
+    ///
+    /// # Returns
+    /// * `Ok(&mut Self)` - For method chaining
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if the data is not a TrueType font
+    pub fn set_font(&mut self, ttf: Vec<u8>) -> Result<&mut Self, PromptPayError> {
+        ttf_parser::Face::parse(&ttf, 0).map_err(|e| {
+            PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Invalid TrueType font: {}", e),
+            )
+        })?;
+        self.font = Some(ttf);
+        Ok(self)
+    }
+
+    /// Appends an entry (a `PromptPayQR` or a [`PdfEntry`] with title and due date).
+    pub fn add(&mut self, entry: impl Into<PdfEntry>) -> &mut Self {
+        self.entries.push(entry.into());
+        self
+    }
+
+    /// Renders the document.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - PDF file contents
+    /// * `Err(PromptPayError)` - If there are no entries, the grid is empty, a QR would
+    ///   be printed with modules below 0.33 mm, or a payload cannot be created
+    pub fn to_bytes(&self) -> Result<Vec<u8>, PromptPayError> {
+        let (columns, rows) = match self.layout {
+            PdfLayout::Single => (1, 1),
+            PdfLayout::Grid { columns, rows } => (columns as usize, rows as usize),
+        };
+        if columns == 0 || rows == 0 {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "Grid must have at least one column and one row",
+            ));
+        }
+        if self.entries.is_empty() {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "PDF document has no entries",
+            ));
+        }
+
+        let face = match &self.font {
+            Some(data) => Some(ttf_parser::Face::parse(data, 0).map_err(|e| {
+                PromptPayError::with_kind(
+                    ErrorKind::Render,
+                    &format!("Invalid TrueType font: {}", e),
+                )
+            })?),
+            None => None,
+        };
+        let mut writer = TextWriter {
+            face: face.as_ref(),
+            glyphs: BTreeMap::new(),
+        };
+
+        let (page_w, page_h) = self.page_size.points();
+        let margin = PAGE_MARGIN_MM * MM_TO_PT;
+        let cell_w = (page_w - margin * 2.0) / columns as f32;
+        let cell_h = (page_h - margin * 2.0) / rows as f32;
+
+        let mut next_id = Ref::new(1);
+        let mut alloc = || next_id.bump();
+        let catalog_id = alloc();
+        let pages_id = alloc();
+        let regular_id = alloc();
+        let bold_id = alloc();
+
+        let mut pdf = Pdf::new();
+        let mut page_ids = Vec::new();
+        for page_entries in self.entries.chunks(columns * rows) {
+            let mut content = Content::new();
+            for (i, entry) in page_entries.iter().enumerate() {
+                let cell = Rect::new(
+                    margin + (i % columns) as f32 * cell_w,
+                    page_h - margin - (i / columns + 1) as f32 * cell_h,
+                    margin + (i % columns + 1) as f32 * cell_w,
+                    page_h - margin - (i / columns) as f32 * cell_h,
+                );
+                if self.layout != PdfLayout::Single {
+                    draw_cut_lines(&mut content, cell);
+                }
+                self.draw_entry(&mut content, &mut writer, entry, cell)?;
+            }
+
+            let page_id = alloc();
+            let content_id = alloc();
+            page_ids.push(page_id);
+            let mut page = pdf.page(page_id);
+            page.media_box(Rect::new(0.0, 0.0, page_w, page_h))
+                .parent(pages_id)
+                .contents(content_id);
+            let mut resources = page.resources();
+            let mut fonts = resources.fonts();
+            fonts.pair(Name(b"F1"), regular_id);
+            fonts.pair(Name(b"F2"), bold_id);
+            fonts.finish();
+            resources.finish();
+            page.finish();
+            pdf.stream(content_id, &content.finish());
+        }
+
+        pdf.catalog(catalog_id).pages(pages_id);
+        pdf.pages(pages_id)
+            .kids(page_ids.iter().copied())
+            .count(page_ids.len() as i32);
+        match &self.font {
+            Some(data) => writer.write_font(&mut pdf, data, regular_id, bold_id, &mut alloc),
+            None => {
+                pdf.type1_font(regular_id)
+                    .base_font(Name(b"Helvetica"))
+                    .encoding_predefined(Name(b"WinAnsiEncoding"));
+                pdf.type1_font(bold_id)
+                    .base_font(Name(b"Helvetica-Bold"))
+                    .encoding_predefined(Name(b"WinAnsiEncoding"));
+            }
+        }
+        pdf.document_info(alloc())
+            .producer(TextStr(concat!("promptpay-rs ", env!("CARGO_PKG_VERSION"))));
+        Ok(pdf.finish())
+    }
+
+    // วาด 1 entry ภายในช่อง: ข้อความด้านบน, QR, ข้อความด้านล่าง (จัดกึ่งกลางแนวนอน)
+    fn draw_entry(
+        &self,
+        content: &mut Content,
+        writer: &mut TextWriter,
+        entry: &PdfEntry,
+        cell: Rect,
+    ) -> Result<(), PromptPayError> {
+        let qr = &entry.qr;
+        let matrix = qr.to_qr_matrix(self.ec_level)?;
+        let (cell_w, cell_h) = (cell.x2 - cell.x1, cell.y2 - cell.y1);
+        let unit = cell_w.min(cell_h * 0.75) / 24.0; // ขนาดตัวอักษรพื้นฐาน
+        let padding = unit * 1.5;
+
+        // ชื่อภาษาไทย (tag 64) ใช้ได้เมื่อมีฟอนต์ที่รองรับเท่านั้น
+        let language = qr.merchant_language().filter(|_| writer.face.is_some());
+        let additional = qr.additional_data();
+        let title = entry
+            .title
+            .as_deref()
+            .or(additional.and_then(|d| d.terminal_label.as_deref()));
+        let name = language
+            .map(|l| l.merchant_name.as_str())
+            .or(qr.merchant_name());
+        let city = language
+            .and_then(|l| l.merchant_city.as_deref())
+            .or(qr.merchant_city());
+
+        // (ข้อความ, ขนาด, ตัวหนา, สี)
+        let mut top: Vec<(String, f32, bool, [f32; 3])> = Vec::new();
+        if let Some(title) = title {
+            top.push((title.to_string(), unit * 1.6, true, NAVY));
+        }
+        if let Some(name) = name {
+            top.push((name.to_string(), unit * 1.2, true, [0.0; 3]));
+        }
+        if let Some(city) = city {
+            top.push((city.to_string(), unit * 0.8, false, MUTED));
+        }
+
+        let mut bottom: Vec<(String, f32, bool, [f32; 3])> = Vec::new();
+        bottom.push((ThaiQrCard::new(qr).proxy_text(), unit * 0.8, false, MUTED));
+        if let Some(amount) = qr.amount() {
+            bottom.push((
+                format!("{} THB", format_amount(amount)),
+                unit * 1.5,
+                true,
+                NAVY,
+            ));
+        }
+        if let Some(bill) = additional.and_then(|d| d.bill_number.as_deref()) {
+            bottom.push((format!("Bill No. {}", bill), unit * 0.8, false, [0.0; 3]));
+        }
+        if let Some(due) = &entry.due_date {
+            bottom.push((format!("Due {}", due), unit * 0.9, true, [0.0; 3]));
+        }
+
+        let line_height = |lines: &[(String, f32, bool, [f32; 3])]| -> f32 {
+            lines.iter().map(|(_, size, _, _)| size * 1.4).sum()
+        };
+        let qr_size = (cell_w - padding * 2.0)
+            .min(cell_h - padding * 2.0 - line_height(&top) - line_height(&bottom))
+            .max(0.0);
+        let center = cell.x1 + cell_w / 2.0;
+
+        let mut y = cell.y2 - padding;
+        for (text, size, bold, color) in &top {
+            y -= size * 1.4;
+            writer.draw_centered(content, text, center, y + size * 0.3, *size, *bold, *color);
+        }
+
+        // QR แบบ vector: รวม module สีดำที่ติดกันในแถวเป็นสี่เหลี่ยมเดียว
+        let n = matrix.size();
+        let module = qr_size / (n + QUIET_ZONE * 2) as f32;
+        // module ที่เล็กกว่าขั้นต่ำจะสแกนไม่ได้ จึงไม่สร้างหน้าที่ใช้งานไม่ได้
+        if (module / MM_TO_PT) < MIN_MODULE_SIZE_MM as f32 {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!(
+                    "QR modules would be {:.2} mm, below the {} mm minimum; use fewer columns or rows",
+                    module / MM_TO_PT,
+                    MIN_MODULE_SIZE_MM
+                ),
+            ));
+        }
+        let left = center - qr_size / 2.0;
+        let qr_top = y;
+        content.set_fill_rgb(0.0, 0.0, 0.0);
+        for row in 0..n {
+            let mut x = 0;
+            while x < n {
+                if !matrix.is_dark(x, row) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while matrix.is_dark(x, row) {
+                    x += 1;
+                }
+                content.rect(
+                    left + (start + QUIET_ZONE) as f32 * module,
+                    qr_top - (row + QUIET_ZONE + 1) as f32 * module,
+                    (x - start) as f32 * module,
+                    module,
+                );
+            }
+        }
+        content.fill_nonzero();
+
+        y = qr_top - qr_size;
+        for (text, size, bold, color) in &bottom {
+            y -= size * 1.4;
+            writer.draw_centered(content, text, center, y + size * 0.3, *size, *bold, *color);
+        }
+        Ok(())
+    }
+}
+
+// เส้นประรอบช่องสำหรับตัดแยกเป็นใบ
+fn draw_cut_lines(content: &mut Content, cell: Rect) {
+    content
+        .save_state()
+        .set_stroke_rgb(0.75, 0.75, 0.75)
+        .set_line_width(0.5)
+        .set_line_cap(LineCapStyle::ButtCap)
+        .set_dash_pattern([3.0, 3.0], 0.0)
+        .rect(cell.x1, cell.y1, cell.x2 - cell.x1, cell.y2 - cell.y1)
+        .stroke()
+        .restore_state();
+}
+
+// เขียนข้อความด้วย Helvetica (WinAnsi) หรือฟอนต์ TrueType ที่ฝังไว้ (Identity-H)
+struct TextWriter<'a> {
+    face: Option<&'a ttf_parser::Face<'a>>,
+    glyphs: BTreeMap<u16, char>, // glyph ที่ใช้ → ตัวอักษร (สำหรับ width และ ToUnicode)
+}
+
+impl TextWriter<'_> {
+    #[allow(clippy::too_many_arguments)]
+    fn draw_centered(
+        &mut self,
+        content: &mut Content,
+        text: &str,
+        center: f32,
+        baseline: f32,
+        size: f32,
+        bold: bool,
+        color: [f32; 3],
+    ) {
+        let (encoded, width) = self.encode(text, bold);
+        let x = center - width * size / 1000.0 / 2.0;
+        content.begin_text();
+        content.set_fill_rgb(color[0], color[1], color[2]);
+        if self.face.is_some() {
+            // ฟอนต์ที่ฝังมีน้ำหนักเดียว → จำลองตัวหนาด้วยการลากเส้นขอบ
+            let mode = if bold {
+                TextRenderingMode::FillStroke
+            } else {
+                TextRenderingMode::Fill
+            };
+            content
+                .set_stroke_rgb(color[0], color[1], color[2])
+                .set_line_width(size * 0.03)
+                .set_text_rendering_mode(mode)
+                .set_font(Name(b"F1"), size);
+        } else {
+            content.set_font(Name(if bold { b"F2" } else { b"F1" }), size);
+        }
+        content
+            .next_line(x, baseline)
+            .show(Str(&encoded))
+            .end_text();
+    }
+
+    // แปลงข้อความเป็น byte ที่ใช้แสดงผล พร้อมความกว้างรวม (1/1000 em)
+    fn encode(&mut self, text: &str, bold: bool) -> (Vec<u8>, f32) {
+        let mut bytes = Vec::new();
+        let mut width = 0.0;
+        match self.face {
+            Some(face) => {
+                let scale = 1000.0 / face.units_per_em() as f32;
+                for c in text.chars() {
+                    let glyph = face.glyph_index(c).map(|g| g.0).unwrap_or(0);
+                    self.glyphs.entry(glyph).or_insert(c);
+                    bytes.extend(glyph.to_be_bytes());
+                    width += glyph_width(face, glyph) * scale;
+                }
+            }
+            None => {
+                let widths = if bold {
+                    &HELVETICA_BOLD_WIDTHS
+                } else {
+                    &HELVETICA_WIDTHS
+                };
+                for c in text.chars() {
+                    // Helvetica รองรับเฉพาะ ASCII ที่พิมพ์ได้
+                    let b = if (' '..='~').contains(&c) {
+                        c as u8
+                    } else {
+                        b'?'
+                    };
+                    bytes.push(b);
+                    width += widths[(b - b' ') as usize] as f32;
+                }
+            }
+        }
+        (bytes, width)
+    }
+
+    // เขียน Type0 font (CIDFontType2 + FontFile2 + ToUnicode) ลงใน PDF
+    fn write_font(
+        &self,
+        pdf: &mut Pdf,
+        data: &[u8],
+        font_id: Ref,
+        bold_id: Ref,
+        alloc: &mut impl FnMut() -> Ref,
+    ) {
+        let Some(face) = self.face else { return };
+        let scale = 1000.0 / face.units_per_em() as f32;
+        let base_font = Name(b"PromptPayEmbedded");
+        let system_info = SystemInfo {
+            registry: Str(b"Adobe"),
+            ordering: Str(b"Identity"),
+            supplement: 0,
+        };
+        let (cid_id, descriptor_id, file_id, cmap_id) = (alloc(), alloc(), alloc(), alloc());
+
+        // ใช้ฟอนต์เดียวกันทั้ง F1 และ F2
+        for id in [font_id, bold_id] {
+            pdf.type0_font(id)
+                .base_font(base_font)
+                .encoding_predefined(Name(b"Identity-H"))
+                .descendant_font(cid_id)
+                .to_unicode(cmap_id);
+        }
+
+        let mut cid = pdf.cid_font(cid_id);
+        cid.subtype(CidFontType::Type2)
+            .base_font(base_font)
+            .system_info(system_info)
+            .font_descriptor(descriptor_id)
+            .default_width(0.0)
+            .cid_to_gid_map_predefined(Name(b"Identity"));
+        let mut widths = cid.widths();
+        for &glyph in self.glyphs.keys() {
+            widths.consecutive(glyph, [glyph_width(face, glyph) * scale]);
+        }
+        widths.finish();
+        cid.finish();
+
+        let bbox = face.global_bounding_box();
+        pdf.font_descriptor(descriptor_id)
+            .name(base_font)
+            .flags(FontFlags::NON_SYMBOLIC)
+            .bbox(Rect::new(
+                bbox.x_min as f32 * scale,
+                bbox.y_min as f32 * scale,
+                bbox.x_max as f32 * scale,
+                bbox.y_max as f32 * scale,
+            ))
+            .italic_angle(0.0)
+            .ascent(face.ascender() as f32 * scale)
+            .descent(face.descender() as f32 * scale)
+            .cap_height(face.capital_height().unwrap_or(face.ascender()) as f32 * scale)
+            .stem_v(80.0)
+            .font_file2(file_id);
+        pdf.stream(file_id, data)
+            .pair(Name(b"Length1"), data.len() as i32);
+
+        let mut cmap = UnicodeCmap::new(Name(b"Custom"), system_info);
+        for (&glyph, &c) in &self.glyphs {
+            cmap.pair(glyph, c);
+        }
+        pdf.cmap(cmap_id, &cmap.finish());
+    }
+}
+
+fn glyph_width(face: &ttf_parser::Face, glyph: u16) -> f32 {
+    face.glyph_hor_advance(ttf_parser::GlyphId(glyph))
+        .unwrap_or(0) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::additional_data::AdditionalData;
+
+    fn table_qr(table: u32) -> PromptPayQR {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_merchant_name("COFFEE HOUSE")
+            .set_additional_data(AdditionalData {
+                terminal_label: Some(format!("TABLE {}", table)),
+                ..AdditionalData::default()
+            });
+        qr
+    }
+
+    /// ทดสอบใบแจ้งหนี้ 1 หน้า: ยอดเงิน, วันครบกำหนด และข้อมูลร้านค้า
+    #[test]
+    fn test_pdf_single_invoice() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(1250.75)
+            .set_merchant_name("COFFEE HOUSE")
+            .set_merchant_city("BANGKOK")
+            .set_additional_data(AdditionalData {
+                bill_number: Some("INV001".to_string()),
+                ..AdditionalData::default()
+            });
+        let mut doc = PdfDocument::new(PageSize::A4);
+        doc.add(PdfEntry {
+            due_date: Some("31/12/2025".to_string()),
+            ..PdfEntry::from(qr)
+        });
+        let pdf = doc.to_bytes().unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with(b"%PDF-1.7"));
+        assert!(text.contains("/Count 1"));
+        assert!(text.contains("/MediaBox [0 0 595.27"));
+        for line in [
+            "(COFFEE HOUSE) Tj",
+            "(BANGKOK) Tj",
+            "(1,250.75 THB) Tj",
+            "(Bill No. INV001) Tj",
+            "(Due 31/12/2025) Tj",
+            "(Mobile xxx-xxx-5678) Tj",
+        ] {
+            assert!(text.contains(line), "{}", line);
+        }
+        assert!(text.contains("/BaseFont /Helvetica-Bold"));
+    }
+
+    /// ทดสอบการจัดหลายใบต่อหน้าแบบตาราง พร้อม terminal label ของแต่ละโต๊ะ
+    #[test]
+    fn test_pdf_grid_pages() {
+        let mut doc = PdfDocument::new(PageSize::A5);
+        doc.set_layout(PdfLayout::Grid {
+            columns: 2,
+            rows: 2,
+        });
+        for table in 1..=5 {
+            doc.add(table_qr(table));
+        }
+        let pdf = doc.to_bytes().unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/Count 2"));
+        assert!(text.contains("(TABLE 1) Tj") && text.contains("(TABLE 5) Tj"));
+        assert!(text.contains("[3 3] 0 d")); // เส้นประสำหรับตัด
+    }
+
+    /// ทดสอบข้อผิดพลาดของเอกสาร: ไม่มีรายการ, ฟอนต์ไม่ถูกต้อง และ grid ว่าง
+    #[test]
+    fn test_pdf_errors() {
+        assert!(PdfDocument::new(PageSize::A4).to_bytes().is_err());
+        let err = PdfDocument::new(PageSize::A4)
+            .set_font(b"not a font".to_vec())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let mut grid = PdfDocument::new(PageSize::A4);
+        grid.set_layout(PdfLayout::Grid {
+            columns: 0,
+            rows: 1,
+        })
+        .add(table_qr(1));
+        assert!(grid.to_bytes().is_err());
+
+        // grid ที่แน่นเกินไปจน module เล็กกว่า 0.33 มม.
+        let mut dense = PdfDocument::new(PageSize::A5);
+        dense.set_layout(PdfLayout::Grid {
+            columns: 12,
+            rows: 12,
+        });
+        dense.add(table_qr(1));
+        let err = dense.to_bytes().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(err.to_string().contains("below the 0.33 mm minimum"));
+    }
+
+    /// ทดสอบการฝังฟอนต์ TrueType พร้อมชื่อร้านภาษาไทย (ฟอนต์ทดสอบ OFL ใน tests/fixtures)
+    #[test]
+    fn test_pdf_embedded_font() {
+        let font = include_bytes!("../tests/fixtures/PromptPayTest-Regular.ttf").to_vec();
+        let mut qr = table_qr(7);
+        qr.set_merchant_language(crate::language::MerchantLanguage::thai("ร้านกาแฟ"));
+        let mut doc = PdfDocument::new(PageSize::A5);
+        doc.set_font(font).unwrap().add(qr);
+        let text = String::from_utf8_lossy(&doc.to_bytes().unwrap()).into_owned();
+        assert!(text.contains("/Subtype /CIDFontType2"));
+        assert!(text.contains("/FontFile2"));
+        assert!(text.contains("/Encoding /Identity-H"));
+        // ToUnicode ของ glyph ภาษาไทย (ร = U+0E23)
+        assert!(text.contains("<0E23>"), "{}", text);
+    }
+}
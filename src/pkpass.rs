@@ -0,0 +1,570 @@
+//! **Apple Wallet** passes carrying a PromptPay QR (requires the `pkpass` feature).
+//!
+//! A `.pkpass` file is a ZIP archive holding `pass.json`, the pass images, a
+//! `manifest.json` with the SHA-1 hash of every file, and a detached PKCS #7
+//! `signature` of the manifest made with the Pass Type ID certificate issued by
+//! Apple. The pass barcode is the payload from [`PromptPayQR::create`].
+//!
+//! ```toml
+//! [dependencies]
+//! promptpay-rs = { version = "0.6.0", features = ["pkpass"] }
+//! ```
+
+use crate::{
+    PromptPayError, PromptPayQR, card::ThaiQrCard, error::ErrorKind, utils::format_amount,
+};
+use cms::{
+    builder::{SignedDataBuilder, SignerInfoBuilder, create_signing_time_attribute},
+    cert::{
+        CertificateChoices, IssuerAndSerialNumber,
+        x509::{
+            Certificate,
+            der::{DecodePem, Encode, oid::ObjectIdentifier},
+            spki::AlgorithmIdentifierOwned,
+        },
+    },
+    signed_data::{EncapsulatedContentInfo, SignerIdentifier},
+};
+use rsa::{
+    RsaPrivateKey,
+    pkcs1::DecodeRsaPrivateKey,
+    pkcs1v15::{Signature, SigningKey},
+    pkcs8::{DecodePrivateKey, EncodePublicKey},
+};
+use serde_json::{Value, json};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, io::Write};
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
+
+const ID_DATA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.1");
+const ID_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+// ชื่อไฟล์ที่สร้างเอง ห้ามเพิ่มผ่าน add_file
+const RESERVED_FILES: [&str; 3] = ["pass.json", "manifest.json", "signature"];
+
+/// Pass Type ID certificate and key used to sign passes.
+///
+/// # Example
+/// ```rust,no_run
+/// use promptpay_rs::pkpass::PassSigner;
+///
+/// let signer = PassSigner::from_pem(
+///     &std::fs::read_to_string("pass-cert.pem").unwrap(),
+///     &std::fs::read_to_string("pass-key.pem").unwrap(),
+///     &std::fs::read_to_string("AppleWWDRCAG4.pem").unwrap(),
+/// )
+/// .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PassSigner {
+    certificate: Certificate, // ใบรับรอง Pass Type ID
+    wwdr: Certificate,        // ใบรับรองกลางของ Apple (WWDR)
+    key: RsaPrivateKey,       // private key ของใบรับรอง Pass Type ID
+}
+
+impl PassSigner {
+    /// Loads the signing certificate, its RSA private key (PKCS #8 or PKCS #1) and
+    /// the Apple WWDR intermediate certificate from PEM.
+    ///
+    /// # Returns
+    /// * `Ok(PassSigner)` - Ready to sign
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if a PEM block cannot be
+    ///   read or the key does not belong to the certificate
+    pub fn from_pem(
+        certificate_pem: &str,
+        private_key_pem: &str,
+        wwdr_pem: &str,
+    ) -> Result<Self, PromptPayError> {
+        let certificate = Certificate::from_pem(certificate_pem)
+            .map_err(|e| invalid_input(&format!("Invalid pass certificate: {}", e)))?;
+        let wwdr = Certificate::from_pem(wwdr_pem)
+            .map_err(|e| invalid_input(&format!("Invalid WWDR certificate: {}", e)))?;
+        let key = RsaPrivateKey::from_pkcs8_pem(private_key_pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs1_pem(private_key_pem))
+            .map_err(|e| invalid_input(&format!("Invalid RSA private key: {}", e)))?;
+
+        // ตรวจว่า key ตรงกับ public key ในใบรับรอง
+        let public_key = key.to_public_key().to_public_key_der().ok();
+        let certificate_key = certificate
+            .tbs_certificate
+            .subject_public_key_info
+            .to_der()
+            .ok();
+        if public_key.is_none() || public_key.map(|k| k.into_vec()) != certificate_key {
+            return Err(invalid_input(
+                "Private key does not match the pass certificate",
+            ));
+        }
+        Ok(PassSigner {
+            certificate,
+            wwdr,
+            key,
+        })
+    }
+
+    // ลายเซ็น PKCS #7 แบบ detached (SHA-256 + RSA) ของ manifest ในรูป DER
+    fn sign(&self, manifest: &[u8]) -> Result<Vec<u8>, PromptPayError> {
+        let digest = Sha256::digest(manifest);
+        let content = EncapsulatedContentInfo {
+            econtent_type: ID_DATA,
+            econtent: None,
+        };
+        let digest_algorithm = AlgorithmIdentifierOwned {
+            oid: ID_SHA256,
+            parameters: None,
+        };
+        let signing_key = SigningKey::<Sha256>::new(self.key.clone());
+        let signer_id = SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+            issuer: self.certificate.tbs_certificate.issuer.clone(),
+            serial_number: self.certificate.tbs_certificate.serial_number.clone(),
+        });
+
+        let mut signer_info = SignerInfoBuilder::new(
+            &signing_key,
+            signer_id,
+            digest_algorithm.clone(),
+            &content,
+            Some(&digest),
+        )
+        .map_err(signing_error)?;
+        signer_info
+            .add_signed_attribute(create_signing_time_attribute().map_err(signing_error)?)
+            .map_err(signing_error)?;
+
+        SignedDataBuilder::new(&content)
+            .add_digest_algorithm(digest_algorithm)
+            .and_then(|b| {
+                b.add_certificate(CertificateChoices::Certificate(self.certificate.clone()))
+            })
+            .and_then(|b| b.add_certificate(CertificateChoices::Certificate(self.wwdr.clone())))
+            .and_then(|b| b.add_signer_info::<_, Signature>(signer_info))
+            .and_then(|b| b.build())
+            .map_err(signing_error)?
+            .to_der()
+            .map_err(signing_error)
+    }
+}
+
+/// Builder for an **Apple Wallet** pass whose barcode is a PromptPay payload.
+///
+/// The pass uses the generic style: amount as the primary field, merchant name,
+/// masked PromptPay ID and references below it. `icon.png` is required by Wallet
+/// and must be added with [`WalletPass::add_file`].
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{PromptPayQR, pkpass::{PassSigner, WalletPass}};
+///
+/// # let read = |name: &str| {
+/// #     std::fs::read_to_string(format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name))
+/// #         .unwrap()
+/// # };
+/// # let icon_png = b"\x89PNG\r\n\x1a\n".to_vec();
+/// let signer = PassSigner::from_pem(
+///     &read("pkpass-signer.pem"),
+///     &read("pkpass-signer-key.pem"),
+///     &read("pkpass-wwdr.pem"),
+/// )
+/// .unwrap();
+///
+/// let mut qr = PromptPayQR::new("0812345678");
+/// qr.set_amount(350.0).set_merchant_name("BANGKOK RUN 2025");
+///
+/// let pkpass = WalletPass::new(&qr, "pass.com.example.promptpay", "ABCDE12345", "TICKET-0001")
+///     .set_organization_name("Bangkok Run")
+///     .add_file("icon.png", icon_png)
+///     .to_pkpass(&signer)
+///     .unwrap();
+/// assert!(pkpass.starts_with(b"PK")); // ไฟล์ ZIP
+/// ```
+#[derive(Debug, Clone)]
+pub struct WalletPass<'a> {
+    qr: &'a PromptPayQR,               // payload ที่อยู่ใน barcode
+    pass_type_identifier: String,      // ต้องตรงกับ UID ของใบรับรอง
+    team_identifier: String,           // ต้องตรงกับ OU ของใบรับรอง
+    serial_number: String,             // รหัสเฉพาะของ pass
+    organization_name: Option<String>, // ชื่อผู้ออก pass
+    description: Option<String>,       // คำอธิบายสำหรับ accessibility
+    merchant_name: Option<String>,     // ชื่อร้านค้า (ไม่บังคับ)
+    expiration_date: Option<String>,   // วันหมดอายุ (ISO 8601)
+    files: BTreeMap<String, Vec<u8>>,  // รูปภาพและไฟล์อื่นใน bundle
+}
+
+impl<'a> WalletPass<'a> {
+    /// Creates a pass for a payload.
+    ///
+    /// `pass_type_identifier` and `team_identifier` must match the UID and OU of the
+    /// signing certificate; `serial_number` identifies this pass.
+    pub fn new(
+        qr: &'a PromptPayQR,
+        pass_type_identifier: &str,
+        team_identifier: &str,
+        serial_number: &str,
+    ) -> Self {
+        WalletPass {
+            qr,
+            pass_type_identifier: pass_type_identifier.to_string(),
+            team_identifier: team_identifier.to_string(),
+            serial_number: serial_number.to_string(),
+            organization_name: None,
+            description: None,
+            merchant_name: None,
+            expiration_date: None,
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the organisation shown on the lock screen. Defaults to the merchant name.
+    pub fn set_organization_name(&mut self, name: &str) -> &mut Self {
+        self.organization_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the description read by VoiceOver. Defaults to `"PromptPay payment"`.
+    pub fn set_description(&mut self, description: &str) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Sets the merchant name shown on the pass.
+    ///
+    /// Defaults to the payload's Thai name (tag `64`), then its merchant name (tag `59`).
+    pub fn set_merchant_name(&mut self, name: &str) -> &mut Self {
+        let name = name.trim();
+        self.merchant_name = (!name.is_empty()).then(|| name.to_string());
+        self
+    }
+
+    /// Marks the pass as expired after this ISO 8601 date
+    /// (e.g. `"2025-12-31T23:59:59+07:00"`).
+    pub fn set_expiration_date(&mut self, date: &str) -> &mut Self {
+        self.expiration_date = Some(date.to_string());
+        self
+    }
+
+    /// Adds an image or localisation file to the bundle (e.g. `icon.png`,
+    /// `icon@2x.png`, `logo.png`, `th.lproj/pass.strings`).
+    pub fn add_file(&mut self, name: &str, data: Vec<u8>) -> &mut Self {
+        self.files.insert(name.to_string(), data);
+        self
+    }
+
+    /// Builds the `pass.json` document.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Pretty-printed JSON
+    /// * `Err(PromptPayError)` - If the payload cannot be created
+    pub fn pass_json(&self) -> Result<String, PromptPayError> {
+        let payload = self.qr.create()?;
+        // ชื่อร้าน: ค่าที่กำหนด → ชื่อภาษาไทย (tag 64) → ชื่อร้าน (tag 59)
+        let merchant_name = self
+            .merchant_name
+            .as_deref()
+            .or(self
+                .qr
+                .merchant_language()
+                .map(|l| l.merchant_name.as_str()))
+            .or(self.qr.merchant_name());
+
+        let mut primary = Vec::new();
+        if let Some(amount) = self.qr.amount() {
+            primary.push(field(
+                "amount",
+                "AMOUNT",
+                &format!("฿{}", format_amount(amount)),
+            ));
+        }
+        let mut secondary = Vec::new();
+        if let Some(name) = merchant_name {
+            secondary.push(field("merchant", "PAY TO", name));
+        }
+        let mut auxiliary = vec![field(
+            "proxy",
+            "PROMPTPAY",
+            &ThaiQrCard::new(self.qr).proxy_text(),
+        )];
+        if let Some(ref1) = self.qr.reference1() {
+            auxiliary.push(field("reference1", "REF. 1", ref1));
+        }
+        if let Some(ref2) = self.qr.reference2() {
+            auxiliary.push(field("reference2", "REF. 2", ref2));
+        }
+        if let Some(bill) = self
+            .qr
+            .additional_data()
+            .and_then(|d| d.bill_number.as_deref())
+        {
+            auxiliary.push(field("bill", "BILL NO.", bill));
+        }
+
+        // payload ที่มีภาษาไทย (tag 64) ต้องเข้ารหัส QR เป็น UTF-8
+        let encoding = if payload.is_ascii() {
+            "iso-8859-1"
+        } else {
+            "utf-8"
+        };
+        let barcode = json!({
+            "format": "PKBarcodeFormatQR",
+            "message": payload,
+            "messageEncoding": encoding,
+        });
+        let mut pass = json!({
+            "formatVersion": 1,
+            "passTypeIdentifier": self.pass_type_identifier,
+            "teamIdentifier": self.team_identifier,
+            "serialNumber": self.serial_number,
+            "organizationName": self
+                .organization_name
+                .as_deref()
+                .or(merchant_name)
+                .unwrap_or("PromptPay"),
+            "description": self.description.as_deref().unwrap_or("PromptPay payment"),
+            "backgroundColor": "rgb(17,53,102)",
+            "foregroundColor": "rgb(255,255,255)",
+            "labelColor": "rgb(191,219,254)",
+            "barcode": barcode, // สำหรับ iOS 8 และเก่ากว่า
+            "barcodes": [barcode],
+            "generic": {
+                "primaryFields": primary,
+                "secondaryFields": secondary,
+                "auxiliaryFields": auxiliary,
+            },
+        });
+        if let Some(date) = &self.expiration_date {
+            pass["expirationDate"] = Value::from(date.as_str());
+        }
+        Ok(serde_json::to_string_pretty(&pass).expect("JSON value always serializes"))
+    }
+
+    /// Builds and signs the `.pkpass` bundle.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - ZIP archive to serve as `application/vnd.apple.pkpass`
+    /// * `Err(PromptPayError)` - If the payload cannot be created, `icon.png` is missing,
+    ///   a file name is reserved or unsafe, or signing fails
+    pub fn to_pkpass(&self, signer: &PassSigner) -> Result<Vec<u8>, PromptPayError> {
+        if !self.files.contains_key("icon.png") {
+            return Err(invalid_input("Wallet passes require an icon.png file"));
+        }
+        for name in self.files.keys() {
+            let unsafe_name = name.is_empty()
+                || name.starts_with('/')
+                || name.contains('\\')
+                || name.split('/').any(|part| part == ".." || part.is_empty());
+            if unsafe_name || RESERVED_FILES.contains(&name.as_str()) {
+                return Err(invalid_input(&format!(
+                    "Invalid pass file name: {:?}",
+                    name
+                )));
+            }
+        }
+
+        let mut files: Vec<(&str, Vec<u8>)> = vec![("pass.json", self.pass_json()?.into_bytes())];
+        files.extend(
+            self.files
+                .iter()
+                .map(|(name, data)| (name.as_str(), data.clone())),
+        );
+
+        // manifest: ชื่อไฟล์ → SHA-1 (hex) ของทุกไฟล์ยกเว้น manifest และ signature
+        let manifest: serde_json::Map<String, Value> = files
+            .iter()
+            .map(|(name, data)| (name.to_string(), Value::from(hex(&Sha1::digest(data)))))
+            .collect();
+        let manifest = serde_json::to_vec_pretty(&manifest).expect("JSON value always serializes");
+        let signature = signer.sign(&manifest)?;
+        files.push(("manifest.json", manifest));
+        files.push(("signature", signature));
+
+        let zip_error = |e: zip::result::ZipError| {
+            PromptPayError::with_kind(ErrorKind::Render, &format!("Failed to write pkpass: {}", e))
+        };
+        let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        for (name, data) in &files {
+            zip.start_file(*name, options).map_err(zip_error)?;
+            zip.write_all(data).map_err(|e| zip_error(e.into()))?;
+        }
+        Ok(zip.finish().map_err(zip_error)?.into_inner())
+    }
+}
+
+fn field(key: &str, label: &str, value: &str) -> Value {
+    json!({ "key": key, "label": label, "value": value })
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn signing_error(e: impl std::fmt::Display) -> PromptPayError {
+    PromptPayError::with_kind(
+        ErrorKind::Render,
+        &format!("Failed to sign pass manifest: {}", e),
+    )
+}
+
+fn invalid_input(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidInput, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cms::{
+        cert::x509::der::{Decode, asn1::OctetString},
+        content_info::ContentInfo,
+        signed_data::SignedData,
+    };
+    use rsa::{RsaPublicKey, pkcs1v15::VerifyingKey, pkcs8::DecodePublicKey, signature::Verifier};
+    use std::io::Read;
+
+    const ID_MESSAGE_DIGEST: ObjectIdentifier =
+        ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.4");
+
+    // ใบรับรอง self-signed สำหรับทดสอบ (signer ออกโดย CA ทดสอบที่ใช้แทน WWDR)
+    const SIGNER_PEM: &str = include_str!("../tests/fixtures/pkpass-signer.pem");
+    const SIGNER_KEY_PEM: &str = include_str!("../tests/fixtures/pkpass-signer-key.pem");
+    const WWDR_PEM: &str = include_str!("../tests/fixtures/pkpass-wwdr.pem");
+
+    fn signer() -> PassSigner {
+        PassSigner::from_pem(SIGNER_PEM, SIGNER_KEY_PEM, WWDR_PEM).unwrap()
+    }
+
+    fn read_zip(bytes: &[u8]) -> BTreeMap<String, Vec<u8>> {
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+        (0..archive.len())
+            .map(|i| {
+                let mut file = archive.by_index(i).unwrap();
+                let mut data = Vec::new();
+                file.read_to_end(&mut data).unwrap();
+                (file.name().to_string(), data)
+            })
+            .collect()
+    }
+
+    /// ทดสอบ pass.json: barcode เป็น payload พร้อมยอดเงินและชื่อร้าน
+    #[test]
+    fn test_pass_json() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(350.0).set_merchant_name("BANGKOK RUN");
+        let json = WalletPass::new(&qr, "pass.com.example.promptpay", "ABCDE12345", "T-1")
+            .set_expiration_date("2025-12-31T23:59:59+07:00")
+            .pass_json()
+            .unwrap();
+        let pass: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(pass["barcodes"][0]["message"], qr.create().unwrap());
+        assert_eq!(pass["barcodes"][0]["messageEncoding"], "iso-8859-1");
+        assert_eq!(pass["organizationName"], "BANGKOK RUN");
+        assert_eq!(pass["generic"]["primaryFields"][0]["value"], "฿350.00");
+        assert_eq!(
+            pass["generic"]["secondaryFields"][0]["value"],
+            "BANGKOK RUN"
+        );
+        assert_eq!(
+            pass["generic"]["auxiliaryFields"][0]["value"],
+            "Mobile xxx-xxx-5678"
+        );
+        assert_eq!(pass["expirationDate"], "2025-12-31T23:59:59+07:00");
+    }
+
+    /// ทดสอบ bundle: manifest มี SHA-1 ของทุกไฟล์ และมีลายเซ็นพร้อมใบรับรอง
+    #[test]
+    fn test_to_pkpass() {
+        let qr = PromptPayQR::new("0812345678");
+        let icon = b"\x89PNG fake icon".to_vec();
+        let bytes = WalletPass::new(&qr, "pass.com.example.promptpay", "ABCDE12345", "T-2")
+            .add_file("icon.png", icon.clone())
+            .to_pkpass(&signer())
+            .unwrap();
+        let files = read_zip(&bytes);
+
+        let names: Vec<&str> = files.keys().map(|k| k.as_str()).collect();
+        assert_eq!(
+            names,
+            ["icon.png", "manifest.json", "pass.json", "signature"]
+        );
+        let manifest: Value = serde_json::from_slice(&files["manifest.json"]).unwrap();
+        assert_eq!(manifest["icon.png"], hex(&Sha1::digest(&icon)));
+        assert_eq!(
+            manifest["pass.json"],
+            hex(&Sha1::digest(&files["pass.json"]))
+        );
+        assert!(manifest.get("signature").is_none());
+
+        // signature เป็น SignedData (DER) ที่มีใบรับรองทั้งสองใบ
+        let signature = &files["signature"];
+        let content_info = ContentInfo::from_der(signature).unwrap();
+        let signed_data: SignedData = content_info.content.decode_as().unwrap();
+        let certificate = Certificate::from_pem(SIGNER_PEM).unwrap();
+        let wwdr = Certificate::from_pem(WWDR_PEM).unwrap();
+        // certificates เป็น SET OF จึงเรียงตาม DER ไม่ใช่ตามลำดับที่เพิ่ม
+        let certificates = signed_data.certificates.unwrap().0;
+        assert_eq!(certificates.len(), 2);
+        for cert in [&certificate, &wwdr] {
+            assert!(
+                certificates
+                    .iter()
+                    .any(|c| *c == CertificateChoices::Certificate(cert.clone()))
+            );
+        }
+
+        // signed attributes มี SHA-256 ของ manifest และลายเซ็น RSA ตรวจสอบได้ด้วยใบรับรอง
+        let signer_info = signed_data.signer_infos.0.get(0).unwrap();
+        assert_eq!(
+            signer_info.sid,
+            SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+                issuer: certificate.tbs_certificate.issuer.clone(),
+                serial_number: certificate.tbs_certificate.serial_number.clone(),
+            })
+        );
+        let signed_attrs = signer_info.signed_attrs.as_ref().unwrap();
+        let message_digest = signed_attrs
+            .iter()
+            .find(|attr| attr.oid == ID_MESSAGE_DIGEST)
+            .unwrap();
+        let digest: OctetString = message_digest.values.get(0).unwrap().decode_as().unwrap();
+        assert_eq!(
+            digest.as_bytes(),
+            Sha256::digest(&files["manifest.json"]).as_slice()
+        );
+        let public_key = RsaPublicKey::from_public_key_der(
+            &certificate
+                .tbs_certificate
+                .subject_public_key_info
+                .to_der()
+                .unwrap(),
+        )
+        .unwrap();
+        let rsa_signature = Signature::try_from(signer_info.signature.as_bytes()).unwrap();
+        VerifyingKey::<Sha256>::new(public_key)
+            .verify(&signed_attrs.to_der().unwrap(), &rsa_signature)
+            .unwrap();
+    }
+
+    /// ทดสอบข้อผิดพลาด: ไม่มี icon, ชื่อไฟล์ไม่ปลอดภัย และ key ไม่ตรงกับใบรับรอง
+    #[test]
+    fn test_pkpass_errors() {
+        let qr = PromptPayQR::new("0812345678");
+        let signer = signer();
+        let mut pass = WalletPass::new(&qr, "pass.com.example.promptpay", "ABCDE12345", "T-3");
+        assert_eq!(
+            pass.to_pkpass(&signer).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        pass.add_file("icon.png", vec![0]);
+        for name in ["../icon.png", "signature", "/logo.png"] {
+            let mut bad = pass.clone();
+            bad.add_file(name, vec![0]);
+            assert_eq!(
+                bad.to_pkpass(&signer).unwrap_err().kind(),
+                ErrorKind::InvalidInput
+            );
+        }
+
+        let err = PassSigner::from_pem(WWDR_PEM, SIGNER_KEY_PEM, WWDR_PEM).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(PassSigner::from_pem("not a cert", SIGNER_KEY_PEM, WWDR_PEM).is_err());
+    }
+}
@@ -1,15 +1,21 @@
 use crate::{
     CountryCode, CurrencyCode, PromptPayError,
-    constants::MerchantType,
-    crc::calculate_crc,
-    utils::{format_target, sanitize_target},
+    additional_data::AdditionalData,
+    constants::{MerchantType, PROMPTPAY_AID, PROMPTPAY_BILL_AID},
+    crc::{calculate_crc, verify_crc},
+    error::ErrorKind,
+    language::MerchantLanguage,
+    tlv,
+    utils::{format_target, is_valid_biller_id, is_valid_reference, sanitize_target},
 };
 
 /// Main struct for generating **PromptPay QR codes** compliant with **EMVCo** standards.
 ///
 /// Supports:
 /// - Mobile number, Tax ID, E-Wallet ID
+/// - Bill payment (Biller ID with `Ref1` / `Ref2`)
 /// - Optional amount (static/dynamic QR)
+/// - Merchant name / city, additional data (tag `62`) and Thai language template (tag `64`)
 /// - Automatic formatting and CRC calculation
 ///
 /// # Example
@@ -19,12 +25,19 @@ use crate::{
 /// qr.set_amount(100.0);
 /// let payload = qr.create().unwrap();
 /// ```
+#[derive(Debug, Clone, PartialEq)]
 pub struct PromptPayQR {
     merchant_id: String,      // รหัสผู้รับเงินดิบ (เช่น "0812345678")
-    merchant_type: MerchantType, // ชนิดของรหัส (Mobile, Tax, EWallet)
+    merchant_type: MerchantType, // ชนิดของรหัส (Mobile, Tax, EWallet, Biller)
+    reference1: Option<String>, // Ref1 สำหรับ bill payment
+    reference2: Option<String>, // Ref2 สำหรับ bill payment (ถ้ามี)
     amount: Option<f64>,      // จำนวนเงิน (ถ้ามี)
     country_code: CountryCode,   // รหัสประเทศ (default: TH)
     currency_code: CurrencyCode, // รหัสสกุลเงิน (default: 764)
+    merchant_name: Option<String>, // ชื่อร้านค้า (tag 59)
+    merchant_city: Option<String>, // เมือง (tag 60)
+    additional_data: Option<AdditionalData>, // ข้อมูลเพิ่มเติม (tag 62)
+    merchant_language: Option<MerchantLanguage>, // ชื่อร้านภาษาไทย (tag 64)
 }
 
 impl PromptPayQR {
@@ -46,9 +59,52 @@ impl PromptPayQR {
         PromptPayQR {
             merchant_id: merchant_id.to_string(),
             merchant_type,
+            reference1: None,
+            reference2: None,
             amount: None,
             country_code: CountryCode::Thailand,
             currency_code: CurrencyCode::THB,
+            merchant_name: None,
+            merchant_city: None,
+            additional_data: None,
+            merchant_language: None,
+        }
+    }
+
+    /// Creates a **bill payment** `PromptPayQR` (Merchant Account Information tag `30`).
+    ///
+    /// Bill payment QRs identify the payee by a Biller ID and carry references the
+    /// biller uses to match the payment (e.g. customer number and invoice number).
+    ///
+    /// # Arguments
+    /// * `biller_id` - 15-digit Biller ID (Tax ID + 2-digit suffix)
+    /// * `reference1` - `Ref1`, required (digits and uppercase letters, up to 20)
+    /// * `reference2` - `Ref2`, optional (same format as `Ref1`)
+    ///
+    /// # Returns
+    /// A new `PromptPayQR` instance; the IDs are validated by [`PromptPayQR::create`]
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::PromptPayQR;
+    /// let mut qr = PromptPayQR::bill_payment("010753600031508", "CUST001", Some("INV2025001"));
+    /// qr.set_amount(1500.0);
+    /// let payload = qr.create().unwrap();
+    /// assert!(payload.contains("0016A000000677010112"));
+    /// ```
+    pub fn bill_payment(biller_id: &str, reference1: &str, reference2: Option<&str>) -> Self {
+        PromptPayQR {
+            merchant_id: biller_id.to_string(),
+            merchant_type: MerchantType::BillerId,
+            reference1: Some(reference1.to_string()),
+            reference2: reference2.map(|r| r.to_string()),
+            amount: None,
+            country_code: CountryCode::Thailand,
+            currency_code: CurrencyCode::THB,
+            merchant_name: None,
+            merchant_city: None,
+            additional_data: None,
+            merchant_language: None,
         }
     }
 
@@ -71,24 +127,63 @@ impl PromptPayQR {
         self
     }
 
+    /// Sets the merchant name (tag `59`, up to 25 Latin characters).
+    ///
+    /// Use [`PromptPayQR::set_merchant_language`] for the name in Thai.
+    pub fn set_merchant_name(&mut self, name: &str) -> &mut Self {
+        self.merchant_name = Some(name.trim().to_string());
+        self
+    }
+
+    /// Sets the merchant city (tag `60`, up to 15 Latin characters).
+    pub fn set_merchant_city(&mut self, city: &str) -> &mut Self {
+        self.merchant_city = Some(city.trim().to_string());
+        self
+    }
+
+    /// Sets the **additional data** template (tag `62`), e.g. bill number or terminal label.
+    ///
+    /// An empty template is not written to the payload.
+    pub fn set_additional_data(&mut self, data: AdditionalData) -> &mut Self {
+        self.additional_data = (!data.is_empty()).then_some(data);
+        self
+    }
+
+    /// Sets the merchant name and city in Thai (language template, tag `64`).
+    pub fn set_merchant_language(&mut self, language: MerchantLanguage) -> &mut Self {
+        self.merchant_language = Some(language);
+        self
+    }
+
     /// Generates the complete **EMVCo-compliant payload** and wraps it in a `Formatter`.
     ///
     /// # Returns
     /// * `Ok(Formatter)` - Ready for `.to_string()` or `.to_image()`
-    /// * `Err(PromptPayError)` - If merchant ID is empty
+    /// * `Err(PromptPayError)` - If merchant ID is empty, or a bill payment
+    ///   Biller ID / reference or a merchant field is malformed
+    ///
+    /// Every TLV value is capped at 99 characters, so a generated payload is
+    /// at most 448 characters and always within the EMVCo maximum of 512.
     ///
     /// # Payload Structure (TLV format)
     /// - `00` Payload Format Indicator
     /// - `01` Point of Initiation Method (`11` = static, `12` = dynamic)
     /// - `29` Merchant Account Information (with PromptPay AID)
+    /// - `30` Bill payment Merchant Account Information (instead of `29`)
     /// - `53` Currency Code
     /// - `54` Amount (if present)
     /// - `58` Country Code
+    /// - `59` / `60` Merchant Name / City (if set)
+    /// - `62` Additional Data (if set)
+    /// - `64` Merchant Information — Language Template (if set)
     /// - `63` CRC-16
     pub fn create(&self) -> Result<String, PromptPayError> {
         // ตรวจสอบว่ามีรหัสผู้รับเงินหรือไม่
         if self.merchant_id.trim().is_empty() {
-            return Err(PromptPayError::new("Merchant ID is required"));
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "Merchant ID is required",
+            ));
         }
 
         let mut payload = String::new();
@@ -100,25 +195,21 @@ impl PromptPayQR {
         // 11 = Static QR (no amount), 12 = Dynamic QR (with amount)
         payload.push_str(if self.amount.is_some() { "010212" } else { "010211" });
 
-        // ID 29: Merchant Account Information
-        let mut merchant_info = String::new();
-        merchant_info.push_str("0016A000000677010111"); // PromptPay AID
+        if self.merchant_type == MerchantType::BillerId {
+            // ID 30: Bill payment Merchant Account Information
+            payload.push_str(&tlv::encode("30", &self.bill_payment_info()?)?);
+        } else {
+            // ID 29: Merchant Account Information
+            let mut merchant_info = String::new();
+            merchant_info.push_str(&tlv::encode("00", PROMPTPAY_AID)?); // PromptPay AID
 
-        let target_type = self.merchant_type.as_str(); // "01", "02", or "03"
-        let formatted_target = format_target(&sanitize_target(&self.merchant_id)); // จัดรูปแบบให้ถูกต้อง
+            let target_type = self.merchant_type.as_str(); // "01", "02", or "03"
+            let formatted_target = format_target(&sanitize_target(&self.merchant_id)); // จัดรูปแบบให้ถูกต้อง
+            merchant_info.push_str(&tlv::encode(target_type, &formatted_target)?);
 
-        let merchant_id_field = format!(
-            "{}{:02}{}",
-            target_type,
-            formatted_target.len(),
-            formatted_target
-        );
-        merchant_info.push_str(&merchant_id_field);
-
-        // เพิ่มความยาวของ Merchant Info
-        let merchant_info_len = format!("{:02}", merchant_info.len());
-        payload.push_str(&format!("29{}", merchant_info_len));
-        payload.push_str(&merchant_info);
+            // เพิ่มความยาวของ Merchant Info
+            payload.push_str(&tlv::encode("29", &merchant_info)?);
+        }
 
         // ID 58: Country Code
         payload.push_str(&format!("5802{}", self.country_code));
@@ -129,9 +220,33 @@ impl PromptPayQR {
         // ID 54: Amount (ถ้ามี)
         if let Some(amount) = self.amount {
             let amount_str = format!("{:.2}", amount); // 2 ทศนิยม
-            let amount_len = format!("{:02}", amount_str.len());
-            payload.push_str(&format!("54{}", amount_len));
-            payload.push_str(&amount_str);
+            payload.push_str(&tlv::encode("54", &amount_str)?);
+        }
+
+        // ID 59, 60: ชื่อร้านค้าและเมือง (ตัวอักษรละติน)
+        let latin = [
+            ("59", &self.merchant_name, "Merchant name", 25),
+            ("60", &self.merchant_city, "Merchant city", 15),
+        ];
+        for (tag, value, name, max) in latin {
+            let Some(value) = value else { continue };
+            if !value.is_ascii() {
+                return Err(PromptPayError::with_kind(
+                    ErrorKind::InvalidInput,
+                    &format!("{} must be Latin text, use the language template (tag 64) for Thai: {}", name, value),
+                ));
+            }
+            payload.push_str(&tlv::encode_checked(tag, value, name, max)?);
+        }
+
+        // ID 62: Additional Data Field Template
+        if let Some(data) = &self.additional_data {
+            payload.push_str(&tlv::encode("62", &data.encode()?)?);
+        }
+
+        // ID 64: Merchant Information — Language Template
+        if let Some(language) = &self.merchant_language {
+            payload.push_str(&tlv::encode("64", &language.encode()?)?);
         }
 
         // ID 63: CRC (คำนวณจาก payload + "6304")
@@ -142,12 +257,175 @@ impl PromptPayQR {
         Ok(payload)
     }
 
+    // สร้างค่าของ tag 30 (AID + Biller ID + Ref1 + Ref2) พร้อมตรวจสอบความถูกต้อง
+    fn bill_payment_info(&self) -> Result<String, PromptPayError> {
+        let biller_id = sanitize_target(&self.merchant_id);
+        if !is_valid_biller_id(&biller_id) {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Biller ID must be 15 digits: {}", self.merchant_id),
+            ));
+        }
+
+        let mut info = tlv::encode("00", PROMPTPAY_BILL_AID)?;
+        info.push_str(&tlv::encode("01", &biller_id)?);
+        let references = [("02", &self.reference1, "Ref1"), ("03", &self.reference2, "Ref2")];
+        for (tag, reference, name) in references {
+            match reference {
+                Some(value) if is_valid_reference(value) => info.push_str(&tlv::encode(tag, value)?),
+                Some(value) => {
+                    return Err(PromptPayError::with_kind(
+                        ErrorKind::InvalidInput,
+                        &format!("{} must be 1-20 digits or uppercase letters: {}", name, value),
+                    ));
+                }
+                None if tag == "02" => {
+                    return Err(PromptPayError::with_kind(
+                        ErrorKind::InvalidInput,
+                        "Ref1 is required for bill payment",
+                    ));
+                }
+                None => {}
+            }
+        }
+        Ok(info)
+    }
+
+    /// Decodes an **EMVCo payload** back into a `PromptPayQR`.
+    ///
+    /// This is the inverse of [`PromptPayQR::create`]: the CRC is verified first, then
+    /// the merchant account information (tag `29` or bill payment tag `30`), country,
+    /// currency and amount are read.
+    /// Tags this library does not generate are ignored.
+    ///
+    /// Mobile numbers are restored to local format (`"0066812345678"` → `"0812345678"`),
+    /// so `PromptPayQR::parse(&qr.create()?)?.create()?` yields the same payload.
+    ///
+    /// # Arguments
+    /// * `payload` - Text scanned from a PromptPay QR code
+    ///
+    /// # Returns
+    /// * `Ok(PromptPayQR)` - Decoded QR definition
+    /// * `Err(PromptPayError)` - [`ErrorKind::CrcMismatch`] if the CRC is wrong,
+    ///   [`ErrorKind::InvalidPayload`] if the payload is not a PromptPay payload
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::PromptPayQR;
+    /// let mut qr = PromptPayQR::new("0812345678");
+    /// qr.set_amount(150.75);
+    /// let decoded = PromptPayQR::parse(&qr.create().unwrap()).unwrap();
+    /// assert_eq!(decoded.merchant_id(), "0812345678");
+    /// assert_eq!(decoded.amount(), Some(150.75));
+    /// ```
+    pub fn parse(payload: &str) -> Result<Self, PromptPayError> {
+        let payload = payload.trim();
+
+        // ตรวจสอบ CRC (tag 63) ก่อนอ่านข้อมูล
+        if !verify_crc(payload, "63") {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::CrcMismatch,
+                "Payload CRC mismatch",
+            ));
+        }
+
+        let fields = tlv::parse(payload)?;
+
+        // ID 00 ต้องเป็นฟิลด์แรกเสมอ
+        match fields.first() {
+            Some(field) if field.tag == "00" && field.value == "01" => {}
+            _ => return Err(invalid("Payload format indicator (tag 00) must be \"01\"")),
+        }
+
+        // ID 30: Bill payment (Biller ID + Ref1 + Ref2)
+        if tlv::find(&fields, "29").is_none()
+            && let Some(bill_info) = tlv::find(&fields, "30")
+        {
+            let sub_fields = tlv::parse(bill_info)?;
+            if tlv::find(&sub_fields, "00") != Some(PROMPTPAY_BILL_AID) {
+                return Err(invalid("Merchant account is not a PromptPay bill payment account"));
+            }
+            let biller_id = tlv::find(&sub_fields, "01").ok_or_else(|| invalid("Biller ID is missing"))?;
+            let reference1 = tlv::find(&sub_fields, "02").ok_or_else(|| invalid("Ref1 is missing"))?;
+            let mut qr = PromptPayQR::bill_payment(biller_id, reference1, tlv::find(&sub_fields, "03"));
+            qr.parse_common_fields(&fields)?;
+            return Ok(qr);
+        }
+
+        // ID 29: Merchant Account Information
+        let merchant_info = tlv::find(&fields, "29")
+            .ok_or_else(|| invalid("PromptPay merchant account (tag 29) is missing"))?;
+        let sub_fields = tlv::parse(merchant_info)?;
+        if tlv::find(&sub_fields, "00") != Some(PROMPTPAY_AID) {
+            return Err(invalid("Merchant account is not a PromptPay account"));
+        }
+        let (merchant_type, target) = sub_fields
+            .iter()
+            .find_map(|f| MerchantType::from_tag(f.tag).map(|t| (t, f.value)))
+            .ok_or_else(|| invalid("PromptPay target is missing"))?;
+        if target.is_empty() || !target.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid(&format!("Invalid PromptPay target: {}", target)));
+        }
+
+        // แปลงเบอร์โทรกลับเป็นรูปแบบในประเทศ (0066xxxxxxxxx → 0xxxxxxxxx)
+        let merchant_id = match merchant_type {
+            MerchantType::MobileNumber => match target.trim_start_matches('0').strip_prefix("66") {
+                Some(local) => format!("0{}", local),
+                None => target.to_string(),
+            },
+            _ => target.to_string(),
+        };
+
+        let mut qr = PromptPayQR::new(&merchant_id);
+        qr.merchant_type = merchant_type;
+        qr.parse_common_fields(&fields)?;
+        Ok(qr)
+    }
+
+    // อ่านฟิลด์ที่ใช้ร่วมกันทั้ง tag 29 และ 30 (ประเทศ, สกุลเงิน, จำนวนเงิน, ข้อมูลร้านค้า)
+    fn parse_common_fields(&mut self, fields: &[tlv::Field<'_>]) -> Result<(), PromptPayError> {
+        let country = tlv::find(fields, "58").ok_or_else(|| invalid("Country code (tag 58) is missing"))?;
+        self.country_code = CountryCode::from_str(country)
+            .ok_or_else(|| invalid(&format!("Unsupported country code: {}", country)))?;
+
+        let currency = tlv::find(fields, "53").ok_or_else(|| invalid("Currency code (tag 53) is missing"))?;
+        self.currency_code = CurrencyCode::from_numeric(currency)
+            .ok_or_else(|| invalid(&format!("Unsupported currency code: {}", currency)))?;
+
+        // ID 54: Amount (ถ้ามี)
+        self.amount = match tlv::find(fields, "54") {
+            Some(value) => match value.parse::<f64>() {
+                Ok(amount) if amount.is_finite() && amount >= 0.0 => Some(amount),
+                _ => return Err(invalid(&format!("Invalid amount: {}", value))),
+            },
+            None => None,
+        };
+
+        // ID 59, 60, 62, 64: ข้อมูลร้านค้า (ถ้ามี)
+        self.merchant_name = tlv::find(fields, "59").map(|v| v.to_string());
+        self.merchant_city = tlv::find(fields, "60").map(|v| v.to_string());
+        self.additional_data = tlv::find(fields, "62").map(AdditionalData::parse).transpose()?;
+        self.merchant_language = tlv::find(fields, "64").map(MerchantLanguage::parse).transpose()?;
+        Ok(())
+    }
+
     // --- Getters ---
     pub fn merchant_id(&self) -> &str { &self.merchant_id }
     pub fn amount(&self) -> Option<f64> { self.amount }
     pub fn country_code(&self) -> CountryCode { self.country_code }
     pub fn currency_code(&self) -> CurrencyCode { self.currency_code }
     pub fn merchant_type(&self) -> MerchantType { self.merchant_type }
+    pub fn reference1(&self) -> Option<&str> { self.reference1.as_deref() }
+    pub fn reference2(&self) -> Option<&str> { self.reference2.as_deref() }
+    pub fn merchant_name(&self) -> Option<&str> { self.merchant_name.as_deref() }
+    pub fn merchant_city(&self) -> Option<&str> { self.merchant_city.as_deref() }
+    pub fn additional_data(&self) -> Option<&AdditionalData> { self.additional_data.as_ref() }
+    pub fn merchant_language(&self) -> Option<&MerchantLanguage> { self.merchant_language.as_ref() }
+}
+
+
+fn invalid(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidPayload, msg)
 }
 
 #[cfg(test)]
@@ -325,4 +603,142 @@ mod tests {
         assert_eq!(qr.country_code().as_str(), "TH");
         assert_eq!(qr.currency_code().numeric_code(), "764");
     }
+
+    /// ทดสอบการถอดรหัส payload กลับเป็น PromptPayQR และสร้างซ้ำได้ payload เดิม
+    #[test]
+    fn test_parse_roundtrip() {
+        for id in ["0812345678", "+66-8-1234-500 0", "1234567890123", "123456789012345"] {
+            let mut qr = PromptPayQR::new(id);
+            qr.set_amount(1234.5);
+            let payload = qr.create().unwrap();
+            let decoded = PromptPayQR::parse(&payload).unwrap();
+            assert_eq!(decoded.merchant_type(), qr.merchant_type());
+            assert_eq!(decoded.amount(), Some(1234.5));
+            assert_eq!(decoded.create().unwrap(), payload);
+        }
+
+        let decoded = PromptPayQR::parse(&PromptPayQR::new("0812345678").create().unwrap()).unwrap();
+        assert_eq!(decoded.merchant_id(), "0812345678");
+        assert_eq!(decoded.amount(), None);
+    }
+
+    /// ทดสอบการถอดรหัส payload ที่ CRC ไม่ถูกต้องหรือไม่ใช่ PromptPay
+    #[test]
+    fn test_parse_invalid_payload() {
+        let payload = PromptPayQR::new("0812345678").create().unwrap();
+        let tampered = payload.replace("0066812345678", "0066812345679");
+        assert_eq!(PromptPayQR::parse(&tampered).unwrap_err().kind(), ErrorKind::CrcMismatch);
+
+        let mut other_aid = "00020101021129370016A000000677010199011300668123456785802TH53037646304".to_string();
+        let crc = calculate_crc(&other_aid);
+        other_aid.push_str(&format!("{:04X}", crc));
+        let err = PromptPayQR::parse(&other_aid).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPayload);
+        assert_eq!(err.to_string(), "Merchant account is not a PromptPay account");
+
+        // ตัวอักษรหลาย byte ท้าย payload ต้องได้ error ไม่ใช่ panic
+        assert_eq!(PromptPayQR::parse("0000กก").unwrap_err().kind(), ErrorKind::CrcMismatch);
+        assert!(PromptPayQR::parse(&format!("{}ก", &payload[..payload.len() - 1])).is_err());
+    }
+
+    /// ทดสอบการสร้าง payload สำหรับ bill payment (tag 30)
+    #[test]
+    fn test_create_bill_payment() {
+        let mut qr = PromptPayQR::bill_payment("010753600031508", "CUST001", Some("INV2025001"));
+        qr.set_amount(1500.0);
+        let result = qr.create().unwrap();
+        assert!(result.starts_with("000201010212"));
+        assert!(result.contains(
+            "30640016A00000067701011201150107536000315080207CUST0010310INV2025001"
+        ));
+        assert!(!result.contains("0016A000000677010111"));
+        assert!(result.contains("54071500.00"));
+
+        let decoded = PromptPayQR::parse(&result).unwrap();
+        assert_eq!(decoded.merchant_type(), MerchantType::BillerId);
+        assert_eq!(decoded.merchant_id(), "010753600031508");
+        assert_eq!(decoded.reference1(), Some("CUST001"));
+        assert_eq!(decoded.reference2(), Some("INV2025001"));
+        assert_eq!(decoded, qr);
+    }
+
+    /// ทดสอบการตรวจสอบ Biller ID และ reference ที่ไม่ถูกต้อง
+    #[test]
+    fn test_create_bill_payment_invalid() {
+        let err = PromptPayQR::bill_payment("0107536000315", "CUST001", None).create().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "Biller ID must be 15 digits: 0107536000315");
+
+        let err = PromptPayQR::bill_payment("010753600031508", "cust-001", None).create().unwrap_err();
+        assert_eq!(err.to_string(), "Ref1 must be 1-20 digits or uppercase letters: cust-001");
+
+        assert!(PromptPayQR::bill_payment("010753600031508", "CUST001", None).create().is_ok());
+    }
+
+    /// ทดสอบชื่อร้านค้า, tag 62 และ tag 64 รวมถึงการถอดรหัสกลับ
+    #[test]
+    fn test_create_merchant_fields() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(80.0)
+            .set_merchant_name("COFFEE HOUSE")
+            .set_merchant_city("BANGKOK")
+            .set_additional_data(AdditionalData {
+                bill_number: Some("INV001".to_string()),
+                terminal_label: Some("T07".to_string()),
+                ..AdditionalData::default()
+            })
+            .set_merchant_language(MerchantLanguage::thai("ร้านกาแฟ").with_city("กรุงเทพ"));
+        let result = qr.create().unwrap();
+        assert!(result.contains("5912COFFEE HOUSE6007BANGKOK"));
+        assert!(result.contains("62170106INV0010703T07"));
+        assert!(result.contains("64290002TH0108ร้านกาแฟ0207กรุงเทพ6304"));
+
+        let decoded = PromptPayQR::parse(&result).unwrap();
+        assert_eq!(decoded, qr);
+        assert_eq!(decoded.additional_data().unwrap().terminal_label.as_deref(), Some("T07"));
+
+        // ชื่อภาษาไทยต้องอยู่ใน tag 64 และความยาวแต่ละฟิลด์มีขีดจำกัด
+        let err = PromptPayQR::new("0812345678").set_merchant_name("ร้านกาแฟ").create().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(PromptPayQR::new("0812345678").set_merchant_city("A VERY LONG CITY NAME").create().is_err());
+    }
+
+    /// ทดสอบขีดจำกัดความยาว payload 512 ตัวอักษรตาม EMVCo
+    #[test]
+    fn test_create_payload_length_limit() {
+        // ทุกฟิลด์ยาวเต็มที่ได้ payload 448 ตัวอักษร ไม่มีทางเกิน 512
+        let label = |c: char| Some(c.to_string().repeat(25));
+        let mut qr = PromptPayQR::new(&"1".repeat(75));
+        qr.set_amount(1e95)
+            .set_merchant_name(&"N".repeat(25))
+            .set_merchant_city(&"C".repeat(15))
+            .set_additional_data(AdditionalData {
+                bill_number: label('A'),
+                store_label: label('B'),
+                reference_label: label('C'),
+                purpose: Some("D".repeat(8)),
+                ..AdditionalData::default()
+            })
+            .set_merchant_language(MerchantLanguage::thai(&"ก".repeat(25)).with_city(&"ข".repeat(15)));
+        let payload = qr.create().unwrap();
+        assert_eq!(payload.chars().count(), 448);
+        assert!(payload.chars().count() < crate::constants::MAX_PAYLOAD_LENGTH);
+
+        // ค่าที่ยาวเกิน 99 ตัวอักษรถูกปฏิเสธตั้งแต่ระดับ TLV
+        let err = PromptPayQR::new(&"1".repeat(480)).create().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "Value of tag 03 is 480 characters, the maximum is 99");
+        let err = PromptPayQR::new("0812345678").set_amount(1e100).create().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        // tag 62 รวมกันเกิน 99 ตัวอักษร
+        qr.set_additional_data(AdditionalData {
+            bill_number: label('A'),
+            store_label: label('B'),
+            reference_label: label('C'),
+            purpose: label('D'),
+            ..AdditionalData::default()
+        });
+        assert_eq!(qr.create().unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
 }
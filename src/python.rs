@@ -0,0 +1,394 @@
+//! **Python bindings** built with pyo3 (requires the `python` feature).
+//!
+//! Build and install the `promptpay` extension module with
+//! [maturin](https://www.maturin.rs/) from the repository root, which reads
+//! `pyproject.toml` and ships the type stubs in `promptpay.pyi`:
+//!
+//! ```sh
+//! maturin develop --release      # or: pip install .
+//! ```
+//!
+//! ```python
+//! import promptpay
+//!
+//! qr = promptpay.PromptPayQR("0812345678", 150.0, merchant_name="COFFEE HOUSE")
+//! payload = qr.create()
+//!
+//! try:
+//!     decoded = promptpay.PromptPayQR.parse(payload)
+//! except promptpay.CrcMismatchError:
+//!     ...
+//! print(decoded.merchant_id, decoded.amount, decoded.additional_data)
+//! ```
+//!
+//! Errors are raised as subclasses of `promptpay.PromptPayError` (itself a
+//! `ValueError`), one per [`ErrorKind`].
+
+use crate::{
+    EcLevel, PromptPayError, PromptPayQR,
+    additional_data::AdditionalData,
+    crc::{calculate_crc, verify_crc},
+    error::ErrorKind,
+    language::MerchantLanguage,
+};
+use pyo3::{create_exception, exceptions::PyValueError, prelude::*};
+use std::collections::HashMap;
+
+create_exception!(
+    promptpay,
+    PyPromptPayError,
+    PyValueError,
+    "Base class of all errors raised by promptpay."
+);
+create_exception!(
+    promptpay,
+    InvalidInputError,
+    PyPromptPayError,
+    "Input supplied by the caller is missing or malformed."
+);
+create_exception!(
+    promptpay,
+    InvalidPayloadError,
+    PyPromptPayError,
+    "A payload could not be decoded."
+);
+create_exception!(
+    promptpay,
+    CrcMismatchError,
+    PyPromptPayError,
+    "The payload CRC does not match its content."
+);
+create_exception!(
+    promptpay,
+    RenderError,
+    PyPromptPayError,
+    "The QR code could not be rendered."
+);
+
+impl From<PromptPayError> for PyErr {
+    fn from(e: PromptPayError) -> Self {
+        let message = e.to_string();
+        match e.kind() {
+            ErrorKind::InvalidInput => InvalidInputError::new_err(message),
+            ErrorKind::InvalidPayload => InvalidPayloadError::new_err(message),
+            ErrorKind::CrcMismatch => CrcMismatchError::new_err(message),
+            ErrorKind::Render => RenderError::new_err(message),
+            _ => PyPromptPayError::new_err(message),
+        }
+    }
+}
+
+// ชื่อ key ของ additional_data ใน Python ตามชื่อฟิลด์ของ AdditionalData
+const ADDITIONAL_DATA_KEYS: [&str; 8] = [
+    "bill_number",
+    "mobile_number",
+    "store_label",
+    "loyalty_number",
+    "reference_label",
+    "customer_label",
+    "terminal_label",
+    "purpose",
+];
+
+fn additional_data_fields(data: &mut AdditionalData) -> [&mut Option<String>; 8] {
+    [
+        &mut data.bill_number,
+        &mut data.mobile_number,
+        &mut data.store_label,
+        &mut data.loyalty_number,
+        &mut data.reference_label,
+        &mut data.customer_label,
+        &mut data.terminal_label,
+        &mut data.purpose,
+    ]
+}
+
+/// A PromptPay payload, mirroring [`PromptPayQR`].
+#[pyclass(name = "PromptPayQR", module = "promptpay", eq, skip_from_py_object)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PyPromptPayQR(PromptPayQR);
+
+impl PyPromptPayQR {
+    fn apply(
+        mut qr: PromptPayQR,
+        amount: Option<f64>,
+        merchant_name: Option<&str>,
+        merchant_city: Option<&str>,
+        thai_name: Option<&str>,
+        additional_data: Option<HashMap<String, String>>,
+    ) -> PyResult<Self> {
+        if let Some(amount) = amount {
+            qr.set_amount(amount);
+        }
+        if let Some(name) = merchant_name {
+            qr.set_merchant_name(name);
+        }
+        if let Some(city) = merchant_city {
+            qr.set_merchant_city(city);
+        }
+        if let Some(name) = thai_name {
+            qr.set_merchant_language(MerchantLanguage::thai(name));
+        }
+        if let Some(values) = additional_data {
+            let mut data = AdditionalData::default();
+            for (key, value) in values {
+                let Some(i) = ADDITIONAL_DATA_KEYS.iter().position(|k| *k == key) else {
+                    return Err(InvalidInputError::new_err(format!(
+                        "Unknown additional_data key: {} (expected one of {})",
+                        key,
+                        ADDITIONAL_DATA_KEYS.join(", ")
+                    )));
+                };
+                *additional_data_fields(&mut data)[i] = Some(value);
+            }
+            qr.set_additional_data(data);
+        }
+        Ok(PyPromptPayQR(qr))
+    }
+}
+
+#[pymethods]
+impl PyPromptPayQR {
+    /// Creates a credit transfer QR for a mobile number, national / tax ID or e-wallet ID.
+    #[new]
+    #[pyo3(signature = (proxy, amount=None, *, merchant_name=None, merchant_city=None, thai_name=None, additional_data=None))]
+    fn new(
+        proxy: &str,
+        amount: Option<f64>,
+        merchant_name: Option<&str>,
+        merchant_city: Option<&str>,
+        thai_name: Option<&str>,
+        additional_data: Option<HashMap<String, String>>,
+    ) -> PyResult<Self> {
+        let qr = PromptPayQR::new(proxy);
+        Self::apply(
+            qr,
+            amount,
+            merchant_name,
+            merchant_city,
+            thai_name,
+            additional_data,
+        )
+    }
+
+    /// Creates a bill payment QR (tag 30).
+    #[staticmethod]
+    #[pyo3(signature = (biller_id, ref1, ref2=None, amount=None, *, merchant_name=None, merchant_city=None, thai_name=None, additional_data=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn bill_payment(
+        biller_id: &str,
+        ref1: &str,
+        ref2: Option<&str>,
+        amount: Option<f64>,
+        merchant_name: Option<&str>,
+        merchant_city: Option<&str>,
+        thai_name: Option<&str>,
+        additional_data: Option<HashMap<String, String>>,
+    ) -> PyResult<Self> {
+        let qr = PromptPayQR::bill_payment(biller_id, ref1, ref2);
+        Self::apply(
+            qr,
+            amount,
+            merchant_name,
+            merchant_city,
+            thai_name,
+            additional_data,
+        )
+    }
+
+    /// Decodes and CRC-checks a payload.
+    #[staticmethod]
+    fn parse(payload: &str) -> PyResult<Self> {
+        Ok(PyPromptPayQR(PromptPayQR::parse(payload)?))
+    }
+
+    /// Builds the payload string.
+    fn create(&self) -> PyResult<String> {
+        Ok(self.0.create()?)
+    }
+
+    /// Renders the payload as an SVG document with the built-in encoder.
+    #[pyo3(signature = (ec_level="M"))]
+    fn to_svg(&self, ec_level: &str) -> PyResult<String> {
+        let ec_level: EcLevel = ec_level.parse()?;
+        Ok(self.0.to_qr_matrix(ec_level)?.to_svg(None))
+    }
+
+    #[getter]
+    fn merchant_id(&self) -> &str {
+        self.0.merchant_id()
+    }
+
+    /// `"mobile_number"`, `"tax_id"`, `"ewallet_id"` or `"biller_id"`.
+    #[getter]
+    fn merchant_type(&self) -> &'static str {
+        self.0.merchant_type().name()
+    }
+
+    #[getter]
+    fn amount(&self) -> Option<f64> {
+        self.0.amount()
+    }
+
+    #[getter]
+    fn country_code(&self) -> &'static str {
+        self.0.country_code().as_str()
+    }
+
+    #[getter]
+    fn currency_code(&self) -> &'static str {
+        self.0.currency_code().numeric_code()
+    }
+
+    #[getter]
+    fn reference1(&self) -> Option<&str> {
+        self.0.reference1()
+    }
+
+    #[getter]
+    fn reference2(&self) -> Option<&str> {
+        self.0.reference2()
+    }
+
+    #[getter]
+    fn merchant_name(&self) -> Option<&str> {
+        self.0.merchant_name()
+    }
+
+    #[getter]
+    fn merchant_city(&self) -> Option<&str> {
+        self.0.merchant_city()
+    }
+
+    #[getter]
+    fn thai_name(&self) -> Option<&str> {
+        self.0
+            .merchant_language()
+            .map(|language| language.merchant_name.as_str())
+    }
+
+    /// Tag 62 fields that are set, keyed like the constructor argument.
+    #[getter]
+    fn additional_data(&self) -> HashMap<&'static str, String> {
+        let mut data = self.0.additional_data().cloned().unwrap_or_default();
+        ADDITIONAL_DATA_KEYS
+            .into_iter()
+            .zip(additional_data_fields(&mut data))
+            .filter_map(|(key, value)| value.take().map(|value| (key, value)))
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        match self.0.amount() {
+            Some(amount) => format!("PromptPayQR('{}', {:.2})", self.0.merchant_id(), amount),
+            None => format!("PromptPayQR('{}')", self.0.merchant_id()),
+        }
+    }
+}
+
+/// Returns `True` if the payload ends with a CRC field (tag 63) that matches its content.
+#[pyfunction(name = "verify_crc")]
+fn py_verify_crc(payload: &str) -> bool {
+    verify_crc(payload.trim(), "63")
+}
+
+/// Returns the CRC-16/CCITT-FALSE of `data` as 4 uppercase hex digits.
+#[pyfunction(name = "calculate_crc")]
+fn py_calculate_crc(data: &str) -> String {
+    format!("{:04X}", calculate_crc(data))
+}
+
+/// The `promptpay` Python module.
+#[pymodule]
+#[pyo3(name = "promptpay")]
+pub fn python_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_class::<PyPromptPayQR>()?;
+    m.add_function(wrap_pyfunction!(py_verify_crc, m)?)?;
+    m.add_function(wrap_pyfunction!(py_calculate_crc, m)?)?;
+    m.add("PromptPayError", py.get_type::<PyPromptPayError>())?;
+    m.add("InvalidInputError", py.get_type::<InvalidInputError>())?;
+    m.add("InvalidPayloadError", py.get_type::<InvalidPayloadError>())?;
+    m.add("CrcMismatchError", py.get_type::<CrcMismatchError>())?;
+    m.add("RenderError", py.get_type::<RenderError>())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+    use std::ffi::CStr;
+
+    // รันโค้ด Python โดยมี module promptpay อยู่ในตัวแปร promptpay
+    fn run(code: &CStr) {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "promptpay").unwrap();
+            python_module(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("promptpay", module).unwrap();
+            if let Err(e) = py.run(code, Some(&globals), None) {
+                e.display(py);
+                panic!("Python code failed: {}", e);
+            }
+        });
+    }
+
+    /// ทดสอบการสร้างและ decode payload จาก Python
+    #[test]
+    fn test_python_round_trip() {
+        run(c"
+qr = promptpay.PromptPayQR('0812345678', 150.0, merchant_name='COFFEE HOUSE',
+                           additional_data={'reference_label': 'INV-1'})
+payload = qr.create()
+assert promptpay.verify_crc(payload)
+assert payload[-4:] == promptpay.calculate_crc(payload[:-4])
+
+decoded = promptpay.PromptPayQR.parse(payload)
+assert decoded.merchant_type == 'mobile_number'
+assert decoded.amount == 150.0
+assert (decoded.country_code, decoded.currency_code) == ('TH', '764')
+assert decoded.merchant_name == 'COFFEE HOUSE'
+assert decoded.additional_data == {'reference_label': 'INV-1'}
+assert decoded.create() == payload
+assert repr(decoded) == \"PromptPayQR('0812345678', 150.00)\"
+
+bill = promptpay.PromptPayQR.bill_payment('099400016550100', 'REF1', 'REF2')
+assert promptpay.PromptPayQR.parse(bill.create()).reference2 == 'REF2'
+assert qr.to_svg('h').startswith('<svg')
+");
+    }
+
+    /// ทดสอบการแปลงข้อผิดพลาดเป็น exception ของ Python
+    #[test]
+    fn test_python_exceptions() {
+        run(c"
+assert issubclass(promptpay.CrcMismatchError, promptpay.PromptPayError)
+assert issubclass(promptpay.PromptPayError, ValueError)
+
+payload = promptpay.PromptPayQR('0812345678').create()
+try:
+    promptpay.PromptPayQR.parse(payload[:-4] + '0000')
+    raise AssertionError('expected CrcMismatchError')
+except promptpay.CrcMismatchError:
+    pass
+
+for make in (lambda: promptpay.PromptPayQR('').create(),
+             lambda: promptpay.PromptPayQR('0812345678', additional_data={'table': '7'}),
+             lambda: promptpay.PromptPayQR('0812345678').to_svg('X')):
+    try:
+        make()
+        raise AssertionError('expected InvalidInputError')
+    except promptpay.InvalidInputError:
+        pass
+
+try:
+    promptpay.PromptPayQR.parse('000201')
+    raise AssertionError('expected PromptPayError')
+except promptpay.PromptPayError:
+    pass
+");
+    }
+}
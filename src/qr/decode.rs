@@ -0,0 +1,234 @@
+use super::{
+    QrMatrix, format_information, mask_bit, reed_solomon,
+    segment::{ALPHANUMERIC_CHARSET, Mode},
+    tables::{self, MAX_VERSION, MIN_VERSION},
+};
+use crate::{EcLevel, PromptPayError, error::ErrorKind};
+
+impl QrMatrix {
+    /// Decodes the symbol back to text (see [`decode_modules`]).
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::{EcLevel, qr::QrMatrix};
+    /// let matrix = QrMatrix::encode("HELLO WORLD", EcLevel::Q).unwrap();
+    /// assert_eq!(matrix.decode().unwrap(), "HELLO WORLD");
+    /// ```
+    pub fn decode(&self) -> Result<String, PromptPayError> {
+        decode_modules(self.size, &self.modules)
+    }
+}
+
+/// Decodes a grid of QR modules back to text, correcting damaged codewords.
+///
+/// The grid must be aligned and unrotated, e.g. sampled from a photo or an
+/// encoder output with a logo drawn over it. Damage is repaired with the
+/// symbol's Reed-Solomon error correction.
+///
+/// # Arguments
+/// * `size` - Number of modules per side (21, 25, ..., 177)
+/// * `modules` - `size * size` modules in row-major order (`true` = dark)
+///
+/// # Returns
+/// * `Ok(String)` - Decoded text
+/// * `Err(PromptPayError)` - [`ErrorKind::InvalidPayload`] if the grid is not a
+///   valid symbol or has more damage than its error correction level can repair
+pub fn decode_modules(size: usize, modules: &[bool]) -> Result<String, PromptPayError> {
+    let version = (MIN_VERSION..=MAX_VERSION)
+        .find(|&v| tables::size(v) == size)
+        .ok_or_else(|| invalid(&format!("Invalid QR code size: {}", size)))?;
+    if modules.len() != size * size {
+        return Err(invalid("Module count does not match the QR code size"));
+    }
+    let dark = |x: usize, y: usize| modules[y * size + x];
+
+    let (ec_level, mask) = read_format(size, &dark)?;
+    let template = QrMatrix::blank(version, ec_level);
+
+    // อ่าน bit ตามลำดับ zigzag พร้อมยกเลิก mask
+    let raw_codewords = tables::raw_data_modules(version) / 8;
+    let mut codewords = vec![0u8; raw_codewords];
+    for (i, (x, y)) in super::data_module_order(size, &template.function)
+        .into_iter()
+        .take(raw_codewords * 8)
+        .enumerate()
+    {
+        if dark(x, y) ^ mask_bit(mask, x, y) {
+            codewords[i >> 3] |= 0x80 >> (i & 7);
+        }
+    }
+
+    let data = correct_blocks(&codewords, version, ec_level)?;
+    read_segments(&data, version)
+}
+
+// อ่าน format information ทั้งสองชุด แล้วเลือก (EC level, mask) ที่ใกล้เคียงที่สุด
+fn read_format(
+    size: usize,
+    dark: &impl Fn(usize, usize) -> bool,
+) -> Result<(EcLevel, u8), PromptPayError> {
+    let mut first = 0u32;
+    let mut second = 0u32;
+    let set = |bits: &mut u32, i: usize, x: usize, y: usize| {
+        if dark(x, y) {
+            *bits |= 1 << i;
+        }
+    };
+    for i in 0..=5 {
+        set(&mut first, i, 8, i);
+    }
+    set(&mut first, 6, 8, 7);
+    set(&mut first, 7, 8, 8);
+    set(&mut first, 8, 7, 8);
+    for i in 9..15 {
+        set(&mut first, i, 14 - i, 8);
+    }
+    for i in 0..8 {
+        set(&mut second, i, size - 1 - i, 8);
+    }
+    for i in 8..15 {
+        set(&mut second, i, 8, size - 15 + i);
+    }
+
+    // BCH(15,5) มีระยะห่างขั้นต่ำ 7 → แก้ได้สูงสุด 3 bit
+    let mut best: Option<(u32, EcLevel, u8)> = None;
+    for ec_level in [EcLevel::L, EcLevel::M, EcLevel::Q, EcLevel::H] {
+        for mask in 0..8 {
+            let expected = format_information(ec_level, mask);
+            let distance = (expected ^ first)
+                .count_ones()
+                .min((expected ^ second).count_ones());
+            if best.is_none_or(|(d, _, _)| distance < d) {
+                best = Some((distance, ec_level, mask));
+            }
+        }
+    }
+    match best {
+        Some((distance, ec_level, mask)) if distance <= 3 => Ok((ec_level, mask)),
+        _ => Err(invalid("Unreadable QR format information")),
+    }
+}
+
+// แยก codeword กลับเป็น block (กลับด้านของการ interleave), แก้ไขข้อผิดพลาด แล้วรวมเฉพาะข้อมูล
+fn correct_blocks(
+    codewords: &[u8],
+    version: u8,
+    ec_level: EcLevel,
+) -> Result<Vec<u8>, PromptPayError> {
+    let num_blocks = tables::num_blocks(version, ec_level);
+    let ecc_len = tables::ecc_per_block(version, ec_level);
+    let num_short_blocks = num_blocks - codewords.len() % num_blocks;
+    let short_block_len = codewords.len() / num_blocks;
+
+    let mut blocks: Vec<Vec<u8>> = vec![Vec::with_capacity(short_block_len + 1); num_blocks];
+    let mut k = 0;
+    for i in 0..=short_block_len {
+        for (j, block) in blocks.iter_mut().enumerate() {
+            if i != short_block_len - ecc_len || j >= num_short_blocks {
+                block.push(codewords[k]);
+                k += 1;
+            }
+        }
+    }
+
+    let mut data = Vec::new();
+    for block in &mut blocks {
+        reed_solomon::correct(block, ecc_len)
+            .ok_or_else(|| invalid("QR code is too damaged to decode"))?;
+        data.extend_from_slice(&block[..block.len() - ecc_len]);
+    }
+    Ok(data)
+}
+
+// แปลง bit stream เป็นข้อความ (numeric / alphanumeric / byte, ข้าม ECI)
+fn read_segments(data: &[u8], version: u8) -> Result<String, PromptPayError> {
+    let mut reader = BitReader { data, pos: 0 };
+    let mut bytes = Vec::new();
+
+    while reader.remaining() >= 4 {
+        let mode = match reader.read(4)? {
+            0b0000 => break, // terminator
+            0b0001 => Mode::Numeric,
+            0b0010 => Mode::Alphanumeric,
+            0b0100 => Mode::Byte,
+            0b0111 => {
+                // ECI designator ยาว 1-3 byte ตาม bit นำหน้า
+                let first = reader.read(8)?;
+                if first & 0x80 != 0 {
+                    reader.read(if first & 0x40 == 0 { 8 } else { 16 })?;
+                }
+                continue;
+            }
+            other => return Err(invalid(&format!("Unsupported QR mode: {:#06b}", other))),
+        };
+
+        let count = reader.read(mode.char_count_bits(version))? as usize;
+        match mode {
+            Mode::Numeric => {
+                let mut left = count;
+                while left > 0 {
+                    let digits = left.min(3);
+                    let value = reader.read(digits * 3 + 1)?;
+                    let text = format!("{:0width$}", value, width = digits);
+                    if text.len() != digits {
+                        return Err(invalid("Invalid numeric QR segment"));
+                    }
+                    bytes.extend_from_slice(text.as_bytes());
+                    left -= digits;
+                }
+            }
+            Mode::Alphanumeric => {
+                let charset = ALPHANUMERIC_CHARSET.as_bytes();
+                let char_at = |i: u32| {
+                    charset
+                        .get(i as usize)
+                        .copied()
+                        .ok_or_else(|| invalid("Invalid alphanumeric QR segment"))
+                };
+                for _ in 0..count / 2 {
+                    let value = reader.read(11)?;
+                    bytes.push(char_at(value / 45)?);
+                    bytes.push(char_at(value % 45)?);
+                }
+                if count % 2 == 1 {
+                    bytes.push(char_at(reader.read(6)?)?);
+                }
+            }
+            Mode::Byte => {
+                for _ in 0..count {
+                    bytes.push(reader.read(8)? as u8);
+                }
+            }
+        }
+    }
+
+    String::from_utf8(bytes).map_err(|_| invalid("QR code data is not valid UTF-8"))
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl BitReader<'_> {
+    fn remaining(&self) -> usize {
+        self.data.len() * 8 - self.pos
+    }
+
+    fn read(&mut self, len: usize) -> Result<u32, PromptPayError> {
+        if len > self.remaining() {
+            return Err(invalid("QR code data ends unexpectedly"));
+        }
+        let mut value = 0;
+        for _ in 0..len {
+            let bit = (self.data[self.pos >> 3] >> (7 - (self.pos & 7))) & 1;
+            value = (value << 1) | bit as u32;
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+}
+
+fn invalid(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidPayload, msg)
+}
@@ -0,0 +1,730 @@
+use super::{decode_modules, tables::MAX_VERSION};
+use crate::{PromptPayError, error::ErrorKind};
+
+// ค่าคงที่ของการค้นหา finder pattern
+const MAX_FINDERS: usize = 40; // จำกัดจำนวน finder ที่นำมาจับกลุ่ม (O(n³))
+const MIN_FINDER_HITS: usize = 2; // finder จริงต้องพบในอย่างน้อย 2 แถว
+const THRESHOLD_OFFSET: u64 = 8; // pixel ต้องมืดกว่าค่าเฉลี่ยรอบข้างอย่างน้อยเท่านี้
+const ALIGNMENT_SCALES: [f64; 4] = [0.8, 1.0, 1.25, 1.5]; // อัตราส่วนขนาด module ที่ใช้เทียบ alignment pattern
+
+/// Pixel rectangle of a code found in an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    /// Left edge in pixels
+    pub x: u32,
+    /// Top edge in pixels
+    pub y: u32,
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+}
+
+/// A QR code located and decoded by [`detect_codes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedCode {
+    /// Decoded text
+    pub text: String,
+    /// Area of the symbol (without quiet zone) in the image
+    pub bounds: BoundingBox,
+}
+
+/// Locates and decodes every QR code in a grayscale image.
+///
+/// Finder patterns are found on an adaptively thresholded copy of the image,
+/// grouped into symbols, and each symbol is sampled (with perspective correction
+/// from the alignment pattern when present) and decoded with [`decode_modules`].
+/// Codes may be rotated by any angle; mirrored and light-on-dark codes are not detected.
+///
+/// # Arguments
+/// * `width`, `height` - Image size in pixels
+/// * `luma` - `width * height` luminance values in row-major order (0 = black)
+///
+/// # Returns
+/// * `Ok(Vec<DetectedCode>)` - Decoded codes, empty if none were found
+/// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if `luma` has the wrong length
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{EcLevel, qr::{QrMatrix, detect_codes}};
+///
+/// // วาด QR ขนาด 4 pixel ต่อ module พร้อม quiet zone 4 module
+/// let matrix = QrMatrix::encode("HELLO", EcLevel::M).unwrap();
+/// let side = (matrix.size() + 8) * 4;
+/// let luma: Vec<u8> = (0..side * side)
+///     .map(|i| {
+///         let (x, y) = ((i % side) / 4, (i / side) / 4);
+///         let dark = (4..matrix.size() + 4).contains(&x)
+///             && (4..matrix.size() + 4).contains(&y)
+///             && matrix.is_dark(x - 4, y - 4);
+///         if dark { 0 } else { 255 }
+///     })
+///     .collect();
+///
+/// let codes = detect_codes(side, side, &luma).unwrap();
+/// assert_eq!(codes[0].text, "HELLO");
+/// assert_eq!(codes[0].bounds.x, 16);
+/// ```
+pub fn detect_codes(
+    width: usize,
+    height: usize,
+    luma: &[u8],
+) -> Result<Vec<DetectedCode>, PromptPayError> {
+    if luma.len() != width * height {
+        return Err(PromptPayError::with_kind(
+            ErrorKind::InvalidInput,
+            &format!(
+                "Image data is {} bytes, expected {} x {}",
+                luma.len(),
+                width,
+                height
+            ),
+        ));
+    }
+    let bitmap = Bitmap::binarize(width, height, luma);
+    let mut finders = find_finders(&bitmap);
+    finders.sort_by_key(|f| std::cmp::Reverse(f.hits));
+    finders.truncate(MAX_FINDERS);
+
+    // จับกลุ่ม finder 3 ตัวที่เป็นมุมของ QR เดียวกัน เรียงจากรูปทรงที่ใกล้เคียงที่สุด
+    let mut triples = Vec::new();
+    for i in 0..finders.len() {
+        for j in i + 1..finders.len() {
+            for k in j + 1..finders.len() {
+                if let Some(triple) = Triple::new(&finders, [i, j, k]) {
+                    triples.push(triple);
+                }
+            }
+        }
+    }
+    triples.sort_by(|a, b| a.score.total_cmp(&b.score));
+
+    let mut used = vec![false; finders.len()];
+    let mut codes = Vec::new();
+    for triple in &triples {
+        if triple.indices.iter().any(|&i| used[i]) {
+            continue;
+        }
+        if let Some(code) = triple.decode(&bitmap, &finders) {
+            triple.indices.iter().for_each(|&i| used[i] = true);
+            codes.push(code);
+        }
+    }
+    Ok(codes)
+}
+
+// ภาพขาวดำหลังผ่าน threshold (true = มืด)
+struct Bitmap {
+    width: usize,
+    height: usize,
+    dark: Vec<bool>,
+}
+
+impl Bitmap {
+    // threshold แบบ adaptive: เทียบแต่ละ pixel กับค่าเฉลี่ยในหน้าต่างรอบตัว (ใช้ integral image)
+    fn binarize(width: usize, height: usize, luma: &[u8]) -> Self {
+        let stride = width + 1;
+        let mut integral = vec![0u64; stride * (height + 1)];
+        for y in 0..height {
+            let mut row = 0u64;
+            for x in 0..width {
+                row += luma[y * width + x] as u64;
+                integral[(y + 1) * stride + x + 1] = integral[y * stride + x + 1] + row;
+            }
+        }
+
+        let radius = (width.min(height) / 8).max(8);
+        let mut dark = vec![false; width * height];
+        for y in 0..height {
+            let (y0, y1) = (y.saturating_sub(radius), (y + radius + 1).min(height));
+            for x in 0..width {
+                let (x0, x1) = (x.saturating_sub(radius), (x + radius + 1).min(width));
+                let sum = integral[y1 * stride + x1] + integral[y0 * stride + x0]
+                    - integral[y0 * stride + x1]
+                    - integral[y1 * stride + x0];
+                let count = ((x1 - x0) * (y1 - y0)) as u64;
+                dark[y * width + x] = (luma[y * width + x] as u64 + THRESHOLD_OFFSET) * count < sum;
+            }
+        }
+        Bitmap {
+            width,
+            height,
+            dark,
+        }
+    }
+
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.dark[y * self.width + x]
+    }
+
+    // อ่านค่าที่ตำแหน่งจริง (นอกภาพถือเป็นสีขาว)
+    fn sample(&self, (x, y): (f64, f64)) -> bool {
+        if x < 0.0 || y < 0.0 || x >= self.width as f64 || y >= self.height as f64 {
+            return false;
+        }
+        self.is_dark(x as usize, y as usize)
+    }
+}
+
+// finder pattern ที่พบ: จุดศูนย์กลาง, ขนาด module โดยประมาณ, จำนวนแถวที่พบ
+struct Finder {
+    x: f64,
+    y: f64,
+    module: f64,
+    hits: usize,
+}
+
+// สแกนทุกแถวหาอัตราส่วน มืด:สว่าง:มืด:สว่าง:มืด = 1:1:3:1:1 แล้วยืนยันในแนวตั้ง
+fn find_finders(bitmap: &Bitmap) -> Vec<Finder> {
+    let mut finders: Vec<Finder> = Vec::new();
+    for y in 0..bitmap.height {
+        // run-length ของแถว: (มืดหรือไม่, ตำแหน่งเริ่ม, ความยาว)
+        let mut runs: Vec<(bool, usize, usize)> = Vec::new();
+        for x in 0..bitmap.width {
+            let dark = bitmap.is_dark(x, y);
+            match runs.last_mut() {
+                Some(run) if run.0 == dark => run.2 += 1,
+                _ => runs.push((dark, x, 1)),
+            }
+        }
+
+        for window in runs.windows(5) {
+            if !window[0].0 {
+                continue;
+            }
+            let counts = [
+                window[0].2,
+                window[1].2,
+                window[2].2,
+                window[3].2,
+                window[4].2,
+            ];
+            if !is_finder_ratio(&counts) {
+                continue;
+            }
+            let total = counts.iter().sum::<usize>() as f64;
+            let cx = window[2].1 as f64 + window[2].2 as f64 / 2.0;
+            let Some((cy, v_total)) = cross_check(bitmap, cx as usize, y, true, total) else {
+                continue;
+            };
+            let Some((cx, h_total)) = cross_check(bitmap, cy as usize, cx as usize, false, total)
+            else {
+                continue;
+            };
+            let module = (h_total + v_total) / 14.0;
+
+            // รวมกับ finder เดิมถ้าเป็นตัวเดียวกัน (ค่าเฉลี่ยถ่วงน้ำหนักตามจำนวนครั้งที่พบ)
+            let existing = finders.iter_mut().find(|f| {
+                (f.x - cx).abs() <= f.module * 2.0
+                    && (f.y - cy).abs() <= f.module * 2.0
+                    && (f.module - module).abs() <= f.module * 0.5
+            });
+            match existing {
+                Some(f) => {
+                    let n = f.hits as f64;
+                    f.x = (f.x * n + cx) / (n + 1.0);
+                    f.y = (f.y * n + cy) / (n + 1.0);
+                    f.module = (f.module * n + module) / (n + 1.0);
+                    f.hits += 1;
+                }
+                None => finders.push(Finder {
+                    x: cx,
+                    y: cy,
+                    module,
+                    hits: 1,
+                }),
+            }
+        }
+    }
+    finders.retain(|f| f.hits >= MIN_FINDER_HITS);
+    finders
+}
+
+fn is_finder_ratio(counts: &[usize; 5]) -> bool {
+    let total: usize = counts.iter().sum();
+    if total < 7 {
+        return false;
+    }
+    let module = total as f64 / 7.0;
+    let variance = module / 2.0;
+    counts
+        .iter()
+        .zip([1.0, 1.0, 3.0, 1.0, 1.0])
+        .all(|(&count, expected)| (count as f64 - module * expected).abs() < variance * expected)
+}
+
+// ตรวจอัตราส่วน 1:1:3:1:1 ตามแนวตั้ง (vertical) หรือแนวนอน ผ่านจุด (fixed, center)
+// คืนค่า (ตำแหน่งศูนย์กลาง, ความยาวรวม)
+fn cross_check(
+    bitmap: &Bitmap,
+    fixed: usize,
+    center: usize,
+    vertical: bool,
+    expected_total: f64,
+) -> Option<(f64, f64)> {
+    let length = if vertical {
+        bitmap.height
+    } else {
+        bitmap.width
+    };
+    let dark_at = |pos: usize| {
+        if vertical {
+            bitmap.is_dark(fixed, pos)
+        } else {
+            bitmap.is_dark(pos, fixed)
+        }
+    };
+    if center >= length || !dark_at(center) {
+        return None;
+    }
+
+    let mut counts = [0usize; 5];
+    // ขึ้นไป (ลดตำแหน่ง): มืดตรงกลาง → สว่าง → มืดวงนอก
+    let mut pos = center as isize;
+    for (state, dark) in [(2, true), (1, false), (0, true)] {
+        while pos >= 0 && dark_at(pos as usize) == dark {
+            counts[state] += 1;
+            pos -= 1;
+        }
+    }
+    let center_start = center + 1 - counts[2];
+    // ลงมา (เพิ่มตำแหน่ง)
+    let mut pos = center + 1;
+    for (state, dark) in [(2, true), (3, false), (4, true)] {
+        while pos < length && dark_at(pos) == dark {
+            counts[state] += 1;
+            pos += 1;
+        }
+    }
+
+    let total = counts.iter().sum::<usize>() as f64;
+    let similar = (total - expected_total).abs() * 5.0 < expected_total * 2.0;
+    if !similar || !is_finder_ratio(&counts) {
+        return None;
+    }
+    Some((center_start as f64 + counts[2] as f64 / 2.0, total))
+}
+
+// วัดอัตราส่วน 1:1:3:1:1 ของ finder ตามแนวเส้นไปยัง finder อีกตัว (ขนานกับขอบ symbol)
+// คืนค่าขนาด module ในหน่วย pixel
+fn module_along(bitmap: &Bitmap, finder: &Finder, toward: &Finder) -> Option<f64> {
+    let (dx, dy) = (toward.x - finder.x, toward.y - finder.y);
+    let length = dx.hypot(dy);
+    if length == 0.0 {
+        return None;
+    }
+    let (ux, uy) = (dx / length, dy / length);
+    let dark_at = |t: isize| bitmap.sample((finder.x + ux * t as f64, finder.y + uy * t as f64));
+    let limit = (finder.module * 7.0).ceil() as isize;
+    if !dark_at(0) {
+        return None;
+    }
+
+    let mut counts = [0usize; 5];
+    let mut t = 0;
+    for (state, dark) in [(2, true), (1, false), (0, true)] {
+        while t > -limit && dark_at(t) == dark {
+            counts[state] += 1;
+            t -= 1;
+        }
+    }
+    let mut t = 1;
+    for (state, dark) in [(2, true), (3, false), (4, true)] {
+        while t < limit && dark_at(t) == dark {
+            counts[state] += 1;
+            t += 1;
+        }
+    }
+    is_finder_ratio(&counts).then(|| counts.iter().sum::<usize>() as f64 / 7.0)
+}
+
+// กลุ่ม finder 3 ตัว: มุมซ้ายบน, ขวาบน, ซ้ายล่าง
+struct Triple {
+    indices: [usize; 3],
+    score: f64, // ยิ่งน้อยยิ่งใกล้สามเหลี่ยมมุมฉากหน้าจั่ว
+}
+
+impl Triple {
+    fn new(finders: &[Finder], [i, j, k]: [usize; 3]) -> Option<Self> {
+        let modules = [finders[i].module, finders[j].module, finders[k].module];
+        let max_module = modules.iter().cloned().fold(f64::MIN, f64::max);
+        let min_module = modules.iter().cloned().fold(f64::MAX, f64::min);
+        if max_module > min_module * 2.0 {
+            return None;
+        }
+
+        // มุมซ้ายบนอยู่ตรงข้ามด้านที่ยาวที่สุด
+        let dist =
+            |a: usize, b: usize| (finders[a].x - finders[b].x).hypot(finders[a].y - finders[b].y);
+        let (tl, mut tr, mut bl) = [(i, j, k), (j, i, k), (k, i, j)]
+            .into_iter()
+            .max_by(|a, b| dist(a.1, a.2).total_cmp(&dist(b.1, b.2)))?;
+        let (side1, side2, longest) = (dist(tl, tr), dist(tl, bl), dist(tr, bl));
+        let hypot = side1.hypot(side2);
+        // module จากการสแกนแนวนอน / ตั้งยาวขึ้นตามมุมที่หมุน: แปลงกลับตามทิศของด้าน tl → tr
+        let (dx, dy) = (finders[tr].x - finders[tl].x, finders[tr].y - finders[tl].y);
+        let module = modules.iter().sum::<f64>() / 3.0 * dx.abs().max(dy.abs()) / side1;
+        if side1.min(side2) < module * 12.0 || side1.max(side2) > side1.min(side2) * 1.5 {
+            return None;
+        }
+        let skew = (longest - hypot).abs() / hypot;
+        if skew > 0.2 {
+            return None;
+        }
+
+        // ให้ขวาบนอยู่ตามเข็มนาฬิกาจากซ้ายบน (แกน y ของภาพชี้ลง)
+        let (t, r, b) = (&finders[tl], &finders[tr], &finders[bl]);
+        if (r.x - t.x) * (b.y - t.y) - (r.y - t.y) * (b.x - t.x) < 0.0 {
+            std::mem::swap(&mut tr, &mut bl);
+        }
+        Some(Triple {
+            indices: [tl, tr, bl],
+            score: skew + (side1 - side2).abs() / side1.max(side2),
+        })
+    }
+
+    // ลองขนาด symbol ที่ใกล้เคียงค่าประมาณ แล้วอ่าน module ผ่าน perspective transform
+    fn decode(&self, bitmap: &Bitmap, finders: &[Finder]) -> Option<DetectedCode> {
+        let [tl, tr, bl] = self.indices.map(|i| &finders[i]);
+        // ขนาด module ตามแกนของ symbol (ค่าจากการสแกนแนวนอน / ตั้งจะโตขึ้นถึง √2 เท่าเมื่อหมุน 45 องศา)
+        let measured: Vec<f64> = [(tl, tr), (tl, bl), (tr, tl), (bl, tl)]
+            .into_iter()
+            .filter_map(|(finder, toward)| module_along(bitmap, finder, toward))
+            .collect();
+        let module = match measured.len() {
+            0 => (tl.module + tr.module + bl.module) / 3.0,
+            n => measured.iter().sum::<f64>() / n as f64,
+        };
+        let across = ((tl.x - tr.x).hypot(tl.y - tr.y) + (tl.x - bl.x).hypot(tl.y - bl.y)) / 2.0;
+        let estimate = across / module + 7.0;
+        let version = ((estimate - 17.0) / 4.0).round() as i64;
+
+        for version in [version, version - 1, version + 1] {
+            if !(1..=MAX_VERSION as i64).contains(&version) {
+                continue;
+            }
+            let size = version as usize * 4 + 17;
+            let far = size as f64 - 3.5;
+            let src = [(3.5, 3.5), (far, 3.5), (far, far), (3.5, far)];
+            // มุมขวาล่างโดยประมาณ (สมมติเป็นสี่เหลี่ยมด้านขนาน)
+            let br = (tr.x + bl.x - tl.x, tr.y + bl.y - tl.y);
+            let affine =
+                Transform::quad_to_quad(src, [(tl.x, tl.y), (tr.x, tr.y), br, (bl.x, bl.y)]);
+
+            let mut transforms = Vec::new();
+            let alignment = size as f64 - 6.5;
+            if version >= 2
+                && let Some(found) = find_alignment(bitmap, &affine, alignment)
+            {
+                let src = [(3.5, 3.5), (far, 3.5), (alignment, alignment), (3.5, far)];
+                let dst = [(tl.x, tl.y), (tr.x, tr.y), found, (bl.x, bl.y)];
+                transforms.push(Transform::quad_to_quad(src, dst));
+            }
+            transforms.push(affine);
+
+            for transform in &transforms {
+                let modules: Vec<bool> = (0..size * size)
+                    .map(|i| {
+                        let (x, y) = ((i % size) as f64 + 0.5, (i / size) as f64 + 0.5);
+                        bitmap.sample(transform.map(x, y))
+                    })
+                    .collect();
+                if let Ok(text) = decode_modules(size, &modules) {
+                    return Some(DetectedCode {
+                        text,
+                        bounds: transform.bounds(size as f64, bitmap),
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+// หา alignment pattern (5×5 module) ใกล้ตำแหน่งที่คาดไว้ด้วยการเทียบ template:
+// ค้นแบบหยาบในรัศมี 8 module ก่อน แล้วค้นละเอียดทีละ pixel รอบจุดที่ดีที่สุด
+fn find_alignment(bitmap: &Bitmap, transform: &Transform, center: f64) -> Option<(f64, f64)> {
+    let (px, py) = transform.map(center, center);
+    let (rx, ry) = transform.map(center + 1.0, center);
+    let (dx, dy) = transform.map(center, center + 1.0);
+    let (u, v) = ((rx - px, ry - py), (dx - px, dy - py));
+    let module = u.0.hypot(u.1).max(v.0.hypot(v.1));
+    let step = (module / 3.0).floor().max(1.0) as i64;
+    let range = (module * 8.0).ceil() as i64 / step;
+
+    // ขนาด module จริงบริเวณมุมขวาล่างอาจต่างจากค่าประมาณเมื่อภาพเอียง จึงลองหลายขนาด
+    let score_at = |cx: f64, cy: f64, scale: f64| {
+        let mut score = 0;
+        for j in -2i32..=2 {
+            for i in -2i32..=2 {
+                // วงนอกและจุดกลางเป็นสีดำ, วงในเป็นสีขาว
+                let expected = i.abs().max(j.abs()) != 1;
+                let (i, j) = (i as f64 * scale, j as f64 * scale);
+                let point = (cx + i * u.0 + j * v.0, cy + i * u.1 + j * v.1);
+                if bitmap.sample(point) == expected {
+                    score += 1;
+                }
+            }
+        }
+        score
+    };
+    // (คะแนน, ระยะจากจุดที่คาดไว้, ตำแหน่ง): คะแนนสูงกว่าชนะ ถ้าเท่ากันเลือกจุดที่ใกล้กว่า
+    let search = |origin: (f64, f64), range: i64, step: i64| {
+        let mut best: Option<(usize, f64, (f64, f64))> = None;
+        for oy in -range..=range {
+            for ox in -range..=range {
+                let (cx, cy) = (origin.0 + (ox * step) as f64, origin.1 + (oy * step) as f64);
+                let score = ALIGNMENT_SCALES
+                    .iter()
+                    .map(|&scale| score_at(cx, cy, scale))
+                    .max()
+                    .unwrap_or(0);
+                let distance = (cx - px).hypot(cy - py);
+                let better = match best {
+                    Some((s, d, _)) => score > s || (score == s && distance < d),
+                    None => true,
+                };
+                if better {
+                    best = Some((score, distance, (cx, cy)));
+                }
+            }
+        }
+        best
+    };
+
+    let (_, _, coarse) = search((px, py), range, step)?;
+    search(coarse, step, 1)
+        .filter(|&(score, _, _)| score >= 24)
+        .map(|(_, _, point)| point)
+}
+
+// perspective transform แบบ 3×3: [X, Y, W] = M · [x, y, 1]
+#[derive(Clone, Copy)]
+struct Transform([[f64; 3]; 3]);
+
+impl Transform {
+    // สี่เหลี่ยมหนึ่งหน่วย (0,0) (1,0) (1,1) (0,1) → จุดทั้ง 4 (ตามเข็มนาฬิกาจากซ้ายบน)
+    fn square_to_quad(q: [(f64, f64); 4]) -> Self {
+        let [(x0, y0), (x1, y1), (x2, y2), (x3, y3)] = q;
+        let (dx3, dy3) = (x0 - x1 + x2 - x3, y0 - y1 + y2 - y3);
+        if dx3 == 0.0 && dy3 == 0.0 {
+            return Transform([
+                [x1 - x0, x2 - x1, x0],
+                [y1 - y0, y2 - y1, y0],
+                [0.0, 0.0, 1.0],
+            ]);
+        }
+        let (dx1, dx2, dy1, dy2) = (x1 - x2, x3 - x2, y1 - y2, y3 - y2);
+        let denominator = dx1 * dy2 - dx2 * dy1;
+        let g = (dx3 * dy2 - dx2 * dy3) / denominator;
+        let h = (dx1 * dy3 - dx3 * dy1) / denominator;
+        Transform([
+            [x1 - x0 + g * x1, x3 - x0 + h * x3, x0],
+            [y1 - y0 + g * y1, y3 - y0 + h * y3, y0],
+            [g, h, 1.0],
+        ])
+    }
+
+    fn quad_to_quad(src: [(f64, f64); 4], dst: [(f64, f64); 4]) -> Self {
+        Self::square_to_quad(dst).times(&Self::square_to_quad(src).adjugate())
+    }
+
+    // adjugate ใช้แทน inverse ได้ เพราะ scale ของ perspective transform ไม่มีผล
+    fn adjugate(&self) -> Self {
+        let m = &self.0;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        Transform([
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ])
+    }
+
+    fn times(&self, other: &Transform) -> Self {
+        let mut out = [[0.0; 3]; 3];
+        for (r, row) in out.iter_mut().enumerate() {
+            for (c, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.0[r][k] * other.0[k][c]).sum();
+            }
+        }
+        Transform(out)
+    }
+
+    fn map(&self, x: f64, y: f64) -> (f64, f64) {
+        let m = &self.0;
+        let w = m[2][0] * x + m[2][1] * y + m[2][2];
+        (
+            (m[0][0] * x + m[0][1] * y + m[0][2]) / w,
+            (m[1][0] * x + m[1][1] * y + m[1][2]) / w,
+        )
+    }
+
+    // กรอบของ symbol (ไม่รวม quiet zone) ในภาพ
+    fn bounds(&self, size: f64, bitmap: &Bitmap) -> BoundingBox {
+        let corners =
+            [(0.0, 0.0), (size, 0.0), (size, size), (0.0, size)].map(|(x, y)| self.map(x, y));
+        let clamp = |v: f64, max: usize| v.round().clamp(0.0, max as f64) as u32;
+        let left = clamp(
+            corners.iter().map(|c| c.0).fold(f64::MAX, f64::min),
+            bitmap.width,
+        );
+        let top = clamp(
+            corners.iter().map(|c| c.1).fold(f64::MAX, f64::min),
+            bitmap.height,
+        );
+        let right = clamp(
+            corners.iter().map(|c| c.0).fold(f64::MIN, f64::max),
+            bitmap.width,
+        );
+        let bottom = clamp(
+            corners.iter().map(|c| c.1).fold(f64::MIN, f64::max),
+            bitmap.height,
+        );
+        BoundingBox {
+            x: left,
+            y: top,
+            width: right - left,
+            height: bottom - top,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EcLevel, PromptPayQR, qr::QrMatrix};
+
+    // วาด QR ลงภาพ: pixel (x, y) → ตำแหน่ง module ผ่านการหมุนและย่อ/ขยาย
+    fn draw(
+        luma: &mut [u8],
+        width: usize,
+        matrix: &QrMatrix,
+        origin: (f64, f64),
+        scale: f64,
+        angle: f64,
+    ) {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let height = luma.len() / width;
+        for py in 0..height {
+            for px in 0..width {
+                let (dx, dy) = (px as f64 + 0.5 - origin.0, py as f64 + 0.5 - origin.1);
+                let mx = (dx * cos + dy * sin) / scale;
+                let my = (-dx * sin + dy * cos) / scale;
+                let n = matrix.size() as f64;
+                if mx >= 0.0 && my >= 0.0 && mx < n && my < n {
+                    let dark = matrix.is_dark(mx as usize, my as usize);
+                    luma[py * width + px] = if dark { 20 } else { 235 };
+                }
+            }
+        }
+    }
+
+    /// ทดสอบ QR หลายอันในภาพเดียว: ขนาด module ไม่เป็นจำนวนเต็ม และหมุน 15 องศา
+    #[test]
+    fn test_detect_multiple_and_rotated() {
+        let (width, height) = (640, 360);
+        let mut luma = vec![200u8; width * height];
+        let first = QrMatrix::encode("00020101021129370016A000000677010111", EcLevel::M).unwrap();
+        let second = QrMatrix::encode("HELLO PROMPTPAY", EcLevel::Q).unwrap();
+        draw(&mut luma, width, &first, (30.0, 40.0), 3.5, 0.0);
+        draw(&mut luma, width, &second, (400.0, 60.0), 5.0, 15.0);
+
+        let mut codes = detect_codes(width, height, &luma).unwrap();
+        codes.sort_by_key(|c| c.bounds.x);
+        assert_eq!(codes.len(), 2);
+        assert_eq!(codes[0].text, "00020101021129370016A000000677010111");
+        let side = first.size() as f64 * 3.5;
+        let bounds = codes[0].bounds;
+        assert_eq!((bounds.x, bounds.y), (30, 40));
+        assert!((bounds.width as f64 - side).abs() <= 1.0);
+        assert!((bounds.height as f64 - side).abs() <= 1.0);
+        assert_eq!(codes[1].text, "HELLO PROMPTPAY");
+    }
+
+    /// ทดสอบ QR ที่หมุนหลายมุม (รวมแนวทแยง 45 องศา) กับขนาด module และ version ต่าง ๆ
+    #[test]
+    fn test_detect_rotated() {
+        let payload = PromptPayQR::new("0812345678")
+            .set_amount(150.0)
+            .create()
+            .unwrap();
+        let (width, height) = (400, 400);
+        for text in [payload.as_str(), "HELLO"] {
+            let matrix = QrMatrix::encode(text, EcLevel::M).unwrap();
+            for scale in [4.0, 6.0, 8.0] {
+                for angle in (0..360).step_by(15).chain([40, 50, 130, 320]) {
+                    // หมุนรอบจุดกลางภาพ
+                    let half = matrix.size() as f64 * scale / 2.0;
+                    let (sin, cos) = (angle as f64).to_radians().sin_cos();
+                    let origin = (
+                        200.0 - half * cos + half * sin,
+                        200.0 - half * sin - half * cos,
+                    );
+                    let mut luma = vec![235u8; width * height];
+                    draw(&mut luma, width, &matrix, origin, scale, angle as f64);
+                    let codes = detect_codes(width, height, &luma).unwrap();
+                    assert_eq!(codes.len(), 1, "scale {} angle {}", scale, angle);
+                    assert_eq!(codes[0].text, text);
+                }
+            }
+        }
+    }
+
+    /// ทดสอบภาพถ่ายเอียง (perspective) ที่ต้องใช้ alignment pattern ช่วยปรับตำแหน่ง
+    #[test]
+    fn test_detect_perspective() {
+        let text = "00020101021229370016A000000677010111021300668123456785802TH530376463045D82";
+        let matrix = QrMatrix::encode(text, EcLevel::M).unwrap();
+        let n = matrix.size() as f64;
+        let (width, height) = (400, 400);
+        // มุมของ symbol ในภาพ: ด้านบนแคบกว่าด้านล่าง
+        let quad = [(100.0, 60.0), (300.0, 70.0), (340.0, 330.0), (60.0, 320.0)];
+        let to_module = Transform::quad_to_quad(quad, [(0.0, 0.0), (n, 0.0), (n, n), (0.0, n)]);
+
+        let mut luma = vec![240u8; width * height];
+        for py in 0..height {
+            for px in 0..width {
+                let (mx, my) = to_module.map(px as f64 + 0.5, py as f64 + 0.5);
+                if mx >= 0.0
+                    && my >= 0.0
+                    && mx < n
+                    && my < n
+                    && matrix.is_dark(mx as usize, my as usize)
+                {
+                    luma[py * width + px] = 30;
+                }
+            }
+        }
+
+        let codes = detect_codes(width, height, &luma).unwrap();
+        assert_eq!(codes.len(), 1);
+        assert_eq!(codes[0].text, text);
+        let bounds = codes[0].bounds;
+        assert!(bounds.x.abs_diff(60) <= 2 && bounds.y.abs_diff(60) <= 2);
+        // มุมขวาล่างได้จากการประมาณนอกจุดอ้างอิง จึงคลาดเคลื่อนได้ราว 1 module
+        assert!((bounds.x + bounds.width).abs_diff(340) <= 10);
+        assert!((bounds.y + bounds.height).abs_diff(330) <= 10);
+    }
+
+    /// ทดสอบภาพที่ไม่มี QR และขนาดข้อมูลไม่ตรงกับภาพ
+    #[test]
+    fn test_detect_empty_and_invalid() {
+        assert!(detect_codes(50, 50, &[255; 2500]).unwrap().is_empty());
+        let err = detect_codes(50, 50, &[255; 100]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+}
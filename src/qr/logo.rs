@@ -0,0 +1,211 @@
+use super::{QrMatrix, data_module_order, tables};
+use crate::{EcLevel, PromptPayError, PromptPayQR, error::ErrorKind};
+
+// ระยะขอบรอบโลโก้ (module) ที่ถูกล้างเป็นสีขาวด้วย
+const LOGO_MARGIN: usize = 1;
+
+/// Size of a logo drawn over the centre of a QR code, relative to the symbol.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{EcLevel, PromptPayQR, qr::LogoOverlay};
+///
+/// let qr = PromptPayQR::new("0812345678");
+/// let (matrix, area) = qr.to_qr_matrix_with_logo(EcLevel::M, LogoOverlay::square(0.2)).unwrap();
+/// assert!(matrix.ec_level() >= EcLevel::Q);
+/// assert!(!matrix.is_dark(area.x + area.width / 2, area.y + area.height / 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogoOverlay {
+    /// Logo width as a fraction of the symbol width (e.g. `0.2`)
+    pub width_ratio: f64,
+    /// Logo height as a fraction of the symbol height
+    pub height_ratio: f64,
+}
+
+impl LogoOverlay {
+    /// Square logo covering `ratio` of the symbol width and height.
+    pub fn square(ratio: f64) -> Self {
+        LogoOverlay {
+            width_ratio: ratio,
+            height_ratio: ratio,
+        }
+    }
+
+    // พื้นที่โลโก้ในหน่วย module (รวมขอบ) จัดกึ่งกลางสัญลักษณ์
+    fn area(&self, size: usize) -> LogoArea {
+        let span = |ratio: f64| {
+            let mut len = (ratio * size as f64).ceil() as usize + LOGO_MARGIN * 2;
+            // ให้เหลือพื้นที่สองข้างเท่ากันพอดี
+            if (size - len.min(size)) % 2 == 1 {
+                len += 1;
+            }
+            len.min(size)
+        };
+        let (width, height) = (span(self.width_ratio), span(self.height_ratio));
+        LogoArea {
+            x: (size - width) / 2,
+            y: (size - height) / 2,
+            width,
+            height,
+        }
+    }
+}
+
+/// Region of a QR code cleared for a logo, in module coordinates (including a
+/// 1-module light margin around the logo).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogoArea {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl LogoArea {
+    fn contains(&self, x: usize, y: usize) -> bool {
+        (self.x..self.x + self.width).contains(&x) && (self.y..self.y + self.height).contains(&y)
+    }
+}
+
+impl QrMatrix {
+    /// Encodes text with the centre cleared for a logo, and proves it still decodes.
+    ///
+    /// Error correction starts at level `Q` (or `min_ec_level` if higher) and is
+    /// raised to `H` when needed. The logo may damage at most 3/8 of each block's
+    /// EC codewords, i.e. three quarters of what the level can repair, leaving the
+    /// rest for print and scan damage. The cleared symbol is then decoded with
+    /// [`QrMatrix::decode`] and compared with `text`; a mismatch also moves on to `H`.
+    ///
+    /// # Arguments
+    /// * `text` - Data to encode
+    /// * `min_ec_level` - Lowest acceptable error correction level
+    /// * `logo` - Logo size relative to the symbol
+    ///
+    /// # Returns
+    /// * `Ok((QrMatrix, LogoArea))` - Symbol with light data modules under the logo, and where
+    ///   to draw it; function patterns in the area (e.g. an alignment pattern) are kept
+    /// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if the logo is too large even at
+    ///   level `H`, or [`ErrorKind::Render`] if the symbol does not decode back to `text` at
+    ///   level `H` either
+    pub fn encode_with_logo(
+        text: &str,
+        min_ec_level: EcLevel,
+        logo: LogoOverlay,
+    ) -> Result<(QrMatrix, LogoArea), PromptPayError> {
+        let valid_ratio = |r: f64| r.is_finite() && r > 0.0 && r < 1.0;
+        if !valid_ratio(logo.width_ratio) || !valid_ratio(logo.height_ratio) {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "Logo ratio must be between 0 and 1",
+            ));
+        }
+
+        let mut last_error = None;
+        for ec_level in [EcLevel::Q, EcLevel::H] {
+            if ec_level < min_ec_level {
+                continue;
+            }
+            let mut matrix = QrMatrix::encode(text, ec_level)?;
+            let area = logo.area(matrix.size);
+            if let Err(e) = matrix.check_logo_damage(&area) {
+                last_error = Some(e);
+                continue;
+            }
+
+            // ล้างเฉพาะ module ข้อมูล ส่วน function pattern (เช่น alignment) คงไว้ตามเดิม
+            for y in area.y..area.y + area.height {
+                for x in area.x..area.x + area.width {
+                    let idx = y * matrix.size + x;
+                    if !matrix.function[idx] {
+                        matrix.modules[idx] = false;
+                    }
+                }
+            }
+            // ถอดรหัสสัญลักษณ์ที่มีโลโก้ เพื่อยืนยันว่ายังสแกนได้ข้อมูลเดิม ถ้าไม่ได้ให้ลองระดับถัดไป
+            match matrix.decode() {
+                Ok(decoded) if decoded == text => return Ok((matrix, area)),
+                _ => {
+                    last_error = Some(PromptPayError::with_kind(
+                        ErrorKind::Render,
+                        "QR code with logo does not decode back to the payload",
+                    ));
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            PromptPayError::with_kind(ErrorKind::InvalidInput, "Logo is too large")
+        }))
+    }
+
+    // ตรวจว่าโลโก้ไม่ทับ finder / format information และไม่ทำลาย codeword เกินงบของแต่ละ block
+    fn check_logo_damage(&self, area: &LogoArea) -> Result<(), PromptPayError> {
+        let too_large = |msg: String| PromptPayError::with_kind(ErrorKind::InvalidInput, &msg);
+        let reserved = 9; // finder + separator + format information
+        if area.x < reserved
+            || area.y < reserved
+            || area.x + area.width > self.size - reserved
+            || area.y + area.height > self.size - reserved
+        {
+            return Err(too_large(
+                "Logo is too large: it would cover the finder patterns".to_string(),
+            ));
+        }
+
+        let blocks = codeword_blocks(self.version, self.ec_level);
+        let num_blocks = tables::num_blocks(self.version, self.ec_level);
+        let mut damaged = vec![false; blocks.len()];
+        for (i, (x, y)) in data_module_order(self.size, &self.function)
+            .into_iter()
+            .enumerate()
+        {
+            if i / 8 < blocks.len() && area.contains(x, y) {
+                damaged[i / 8] = true;
+            }
+        }
+        let mut per_block = vec![0usize; num_blocks];
+        for (i, _) in damaged.iter().enumerate().filter(|(_, d)| **d) {
+            per_block[blocks[i]] += 1;
+        }
+
+        let worst = per_block.into_iter().max().unwrap_or(0);
+        let budget = tables::ecc_per_block(self.version, self.ec_level) * 3 / 8;
+        if worst > budget {
+            return Err(too_large(format!(
+                "Logo is too large: it damages {} codewords of a block at level {:?}, at most {} allowed",
+                worst, self.ec_level, budget
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl PromptPayQR {
+    /// Encodes the payload with the centre cleared for a logo (see [`QrMatrix::encode_with_logo`]).
+    pub fn to_qr_matrix_with_logo(
+        &self,
+        min_ec_level: EcLevel,
+        logo: LogoOverlay,
+    ) -> Result<(QrMatrix, LogoArea), PromptPayError> {
+        QrMatrix::encode_with_logo(&self.create()?, min_ec_level, logo)
+    }
+}
+
+// index ของ block ที่ codeword แต่ละตัว (ตามลำดับหลัง interleave) สังกัดอยู่
+fn codeword_blocks(version: u8, ec_level: EcLevel) -> Vec<usize> {
+    let num_blocks = tables::num_blocks(version, ec_level);
+    let ecc_len = tables::ecc_per_block(version, ec_level);
+    let raw_codewords = tables::raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_len = raw_codewords / num_blocks;
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..=short_block_len {
+        for j in 0..num_blocks {
+            if i != short_block_len - ecc_len || j >= num_short_blocks {
+                result.push(j);
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,699 @@
+//! Built-in, dependency-free **QR code encoder**.
+//!
+//! Payloads are split into numeric / alphanumeric / byte segments optimally
+//! (see [`segment`]), the smallest version that fits the chosen error correction
+//! level is selected, and the result is returned as a [`QrMatrix`] of modules that
+//! can be drawn with any graphics backend (thermal printers, PDF, SVG, ...).
+//!
+//! Symbols can be decoded again with [`decode_modules`], which is used to verify
+//! codes with a logo in the centre ([`QrMatrix::encode_with_logo`]), and located
+//! in grayscale images with [`detect_codes`].
+//!
+//! # Example
+//! ```rust
+//! use promptpay_rs::{EcLevel, PromptPayQR};
+//!
+//! let mut qr = PromptPayQR::new("0812345678");
+//! qr.set_amount(150.75);
+//! let matrix = qr.to_qr_matrix(EcLevel::M).unwrap();
+//! assert_eq!(matrix.size(), matrix.version() as usize * 4 + 17);
+//! ```
+
+mod decode;
+mod detect;
+mod logo;
+mod reed_solomon;
+mod render;
+mod segment;
+mod symbol;
+mod tables;
+
+pub use decode::decode_modules;
+pub use detect::{BoundingBox, DetectedCode, detect_codes};
+pub use logo::{LogoArea, LogoOverlay};
+pub use render::QUIET_ZONE;
+pub use segment::{Mode, Segment, segment};
+pub use symbol::SymbolInfo;
+#[cfg(feature = "pdf")]
+pub(crate) use symbol::MIN_MODULE_SIZE_MM;
+pub use tables::{MAX_VERSION, MIN_VERSION};
+
+use crate::{EcLevel, PromptPayError, PromptPayQR, error::ErrorKind};
+use segment::BitBuffer;
+
+/// A QR code symbol as a square matrix of modules.
+///
+/// Coordinates are `(x, y)` with `(0, 0)` at the top-left corner, excluding the quiet zone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrMatrix {
+    version: u8,         // version 1-40
+    ec_level: EcLevel,   // ระดับการแก้ไขข้อผิดพลาด
+    mask: u8,            // mask pattern 0-7 ที่เลือกใช้
+    size: usize,         // จำนวน module ต่อด้าน
+    modules: Vec<bool>,  // true = dark (row-major)
+    function: Vec<bool>, // true = function pattern (finder, timing, format, ...)
+}
+
+impl QrMatrix {
+    /// Encodes text into the smallest QR symbol for the given error correction level.
+    ///
+    /// # Arguments
+    /// * `text` - Data to encode (any UTF-8 text)
+    /// * `ec_level` - Error correction level
+    ///
+    /// # Returns
+    /// * `Ok(QrMatrix)` - Encoded symbol
+    /// * `Err(PromptPayError)` - [`ErrorKind::Render`] if the text does not fit in version 40
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::{EcLevel, qr::QrMatrix};
+    /// let matrix = QrMatrix::encode("HELLO WORLD", EcLevel::Q).unwrap();
+    /// assert_eq!(matrix.version(), 1);
+    /// assert_eq!(matrix.size(), 21);
+    /// ```
+    pub fn encode(text: &str, ec_level: EcLevel) -> Result<Self, PromptPayError> {
+        let (version, segments) = QrMatrix::fit(text, ec_level)?;
+        Ok(QrMatrix::encode_segments(&segments, version, ec_level))
+    }
+
+    // หา version ที่เล็กที่สุดที่บรรจุข้อมูลได้ พร้อม segment ที่เหมาะสมกับ version นั้น
+    pub(crate) fn fit(text: &str, ec_level: EcLevel) -> Result<(u8, Vec<Segment>), PromptPayError> {
+        let mut segments = Vec::new();
+        for version in MIN_VERSION..=MAX_VERSION {
+            // ความยาวของ character count เปลี่ยนที่ version 1, 10 และ 27 เท่านั้น
+            if matches!(version, 1 | 10 | 27) {
+                segments = segment(text, version);
+            }
+            let capacity = tables::data_codewords(version, ec_level) * 8;
+            let used: Option<usize> = segments.iter().map(|s| s.total_bits(version)).sum();
+            if let Some(used) = used
+                && used <= capacity
+            {
+                return Ok((version, segments));
+            }
+        }
+        Err(PromptPayError::with_kind(
+            ErrorKind::Render,
+            &format!(
+                "Data is too long for a QR code at error correction level {:?}",
+                ec_level
+            ),
+        ))
+    }
+
+    fn encode_segments(segments: &[Segment], version: u8, ec_level: EcLevel) -> Self {
+        let capacity = tables::data_codewords(version, ec_level) * 8;
+
+        let mut bits = BitBuffer::default();
+        for seg in segments {
+            seg.write_bits(version, &mut bits);
+        }
+        // terminator (สูงสุด 4 bit) แล้วเติมให้ครบ byte
+        bits.push(0, (capacity - bits.len()).min(4));
+        bits.push(0, (8 - bits.len() % 8) % 8);
+        // pad bytes สลับกัน 0xEC, 0x11
+        for pad in [0xEC, 0x11].into_iter().cycle() {
+            if bits.len() >= capacity {
+                break;
+            }
+            bits.push(pad, 8);
+        }
+
+        let codewords = add_ecc_and_interleave(&bits.to_bytes(), version, ec_level);
+
+        let mut matrix = QrMatrix::blank(version, ec_level);
+        matrix.draw_codewords(&codewords);
+
+        // เลือก mask ที่มีค่า penalty ต่ำที่สุด
+        let mut best: Option<(usize, u8)> = None;
+        for mask in 0..8 {
+            matrix.apply_mask(mask);
+            matrix.draw_format_bits(mask);
+            let penalty = matrix.penalty_score();
+            if best.is_none_or(|(p, _)| penalty < p) {
+                best = Some((penalty, mask));
+            }
+            matrix.apply_mask(mask); // XOR ซ้ำเพื่อยกเลิก mask
+        }
+        let mask = best.map(|(_, m)| m).unwrap_or(0);
+        matrix.apply_mask(mask);
+        matrix.draw_format_bits(mask);
+        matrix.mask = mask;
+        matrix
+    }
+
+    // สัญลักษณ์ที่มีเฉพาะ function pattern (ยังไม่มีข้อมูล)
+    pub(crate) fn blank(version: u8, ec_level: EcLevel) -> Self {
+        let size = tables::size(version);
+        let mut matrix = QrMatrix {
+            version,
+            ec_level,
+            mask: 0,
+            size,
+            modules: vec![false; size * size],
+            function: vec![false; size * size],
+        };
+        matrix.draw_function_patterns();
+        matrix
+    }
+
+    /// Version of the symbol (1-40).
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Error correction level of the symbol.
+    pub fn ec_level(&self) -> EcLevel {
+        self.ec_level
+    }
+
+    /// Mask pattern (0-7) chosen by the encoder.
+    pub fn mask(&self) -> u8 {
+        self.mask
+    }
+
+    /// Number of modules per side (`version * 4 + 17`), excluding the quiet zone.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the module at `(x, y)` is dark; out-of-range modules are light.
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.modules[y * self.size + x]
+    }
+
+    /// Returns `true` if the module at `(x, y)` belongs to a function pattern
+    /// (finder, separator, timing, alignment, format or version information).
+    pub fn is_function(&self, x: usize, y: usize) -> bool {
+        x < self.size && y < self.size && self.function[y * self.size + x]
+    }
+
+    /// All modules in row-major order (`true` = dark).
+    pub fn modules(&self) -> &[bool] {
+        &self.modules
+    }
+
+    /// Returns SVG path data (`d` attribute) drawing the dark modules, one user unit
+    /// per module with the origin at the top-left module.
+    ///
+    /// Horizontal runs of dark modules are merged into a single rectangle, which
+    /// keeps the markup small enough to embed in cards and HTML pages.
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::{EcLevel, qr::QrMatrix};
+    /// let matrix = QrMatrix::encode("HELLO WORLD", EcLevel::Q).unwrap();
+    /// assert!(matrix.svg_path().starts_with("M0 0h7v1h-7z")); // แถวบนของ finder pattern
+    /// ```
+    pub fn svg_path(&self) -> String {
+        let mut d = String::new();
+        for y in 0..self.size {
+            let mut x = 0;
+            while x < self.size {
+                if !self.is_dark(x, y) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while self.is_dark(x, y) {
+                    x += 1;
+                }
+                let run = x - start;
+                d.push_str(&format!("M{} {}h{}v1h-{}z", start, y, run, run));
+            }
+        }
+        d
+    }
+
+    // --- การวาด function pattern ---
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        let idx = y * self.size + x;
+        self.modules[idx] = dark;
+        self.function[idx] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        let size = self.size;
+
+        // Timing patterns
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        // Finder patterns (3 มุม) รวม separator
+        self.draw_finder(3, 3);
+        self.draw_finder(size - 4, 3);
+        self.draw_finder(3, size - 4);
+
+        // Alignment patterns (ยกเว้นตำแหน่งที่ทับ finder)
+        let positions = tables::alignment_positions(self.version);
+        let last = positions.len().saturating_sub(1);
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                // มุมที่ทับ finder pattern: (0, 0), (0, last), (last, 0)
+                let corner = (i == 0 && (j == 0 || j == last)) || (i == last && j == 0);
+                if corner {
+                    continue;
+                }
+                for dy in -2i32..=2 {
+                    for dx in -2i32..=2 {
+                        let dark = dx.abs().max(dy.abs()) != 1;
+                        self.set_function((x as i32 + dx) as usize, (y as i32 + dy) as usize, dark);
+                    }
+                }
+            }
+        }
+
+        // จองพื้นที่ format bits ไว้ก่อน (วาดจริงหลังเลือก mask)
+        self.draw_format_bits(0);
+        self.draw_version_bits();
+    }
+
+    fn draw_finder(&mut self, cx: usize, cy: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let (x, y) = (cx as i32 + dx, cy as i32 + dy);
+                if x < 0 || y < 0 || x >= self.size as i32 || y >= self.size as i32 {
+                    continue;
+                }
+                let dist = dx.abs().max(dy.abs());
+                self.set_function(x as usize, y as usize, dist != 2 && dist != 4);
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u8) {
+        let bits = format_information(self.ec_level, mask);
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+        let size = self.size;
+
+        // ชุดแรก รอบ finder มุมบนซ้าย
+        for i in 0..=5 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        // ชุดที่สอง แยกอยู่ข้าง finder มุมบนขวาและล่างซ้าย
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true); // dark module
+    }
+
+    fn draw_version_bits(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let bits = version_information(self.version);
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    // --- ข้อมูลและ mask ---
+
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let mut i = 0;
+        for (x, y) in data_module_order(self.size, &self.function) {
+            if i < codewords.len() * 8 {
+                self.modules[y * self.size + x] = (codewords[i >> 3] >> (7 - (i & 7))) & 1 != 0;
+                i += 1;
+            }
+        }
+    }
+
+    pub(crate) fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let idx = y * self.size + x;
+                if !self.function[idx] && mask_bit(mask, x, y) {
+                    self.modules[idx] = !self.modules[idx];
+                }
+            }
+        }
+    }
+
+    fn penalty_score(&self) -> usize {
+        let size = self.size;
+        let get = |x: usize, y: usize| self.modules[y * size + x];
+        let mut result = 0;
+
+        // Rule 1 และ 3: แถวและคอลัมน์
+        for horizontal in [true, false] {
+            for a in 0..size {
+                let line: Vec<bool> = (0..size)
+                    .map(|b| if horizontal { get(b, a) } else { get(a, b) })
+                    .collect();
+                result += run_penalty(&line) + finder_penalty(&line);
+            }
+        }
+
+        // Rule 2: บล็อก 2x2 สีเดียวกัน
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let c = get(x, y);
+                if c == get(x + 1, y) && c == get(x, y + 1) && c == get(x + 1, y + 1) {
+                    result += PENALTY_N2;
+                }
+            }
+        }
+
+        // Rule 4: สัดส่วน dark module ห่างจาก 50%
+        let total = size * size;
+        let dark = self.modules.iter().filter(|&&m| m).count();
+        let k = (dark * 20).abs_diff(total * 10).div_ceil(total) - 1;
+        result + k * PENALTY_N4
+    }
+}
+
+impl PromptPayQR {
+    /// Encodes the payload with the built-in QR encoder.
+    ///
+    /// # Arguments
+    /// * `ec_level` - Error correction level
+    ///
+    /// # Returns
+    /// * `Ok(QrMatrix)` - Smallest symbol that holds the payload
+    /// * `Err(PromptPayError)` - If the payload cannot be created or is too long
+    pub fn to_qr_matrix(&self, ec_level: EcLevel) -> Result<QrMatrix, PromptPayError> {
+        QrMatrix::encode(&self.create()?, ec_level)
+    }
+}
+
+const PENALTY_N1: usize = 3;
+const PENALTY_N2: usize = 3;
+const PENALTY_N3: usize = 40;
+const PENALTY_N4: usize = 10;
+
+// ลำดับการวาง module ข้อมูลแบบ zigzag จากมุมล่างขวา (ข้าม function pattern)
+pub(crate) fn data_module_order(size: usize, function: &[bool]) -> Vec<(usize, usize)> {
+    let mut order = Vec::new();
+    let mut right = size as i32 - 1;
+    while right >= 1 {
+        if right == 6 {
+            right = 5; // ข้าม timing pattern แนวตั้ง
+        }
+        for vert in 0..size {
+            for j in 0..2 {
+                let x = (right - j) as usize;
+                let upward = (right + 1) & 2 == 0;
+                let y = if upward { size - 1 - vert } else { vert };
+                if !function[y * size + x] {
+                    order.push((x, y));
+                }
+            }
+        }
+        right -= 2;
+    }
+    order
+}
+
+pub(crate) fn mask_bit(mask: u8, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        _ => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+    }
+}
+
+// 15-bit format information (EC level + mask) พร้อม BCH code และ XOR mask
+pub(crate) fn format_information(ec_level: EcLevel, mask: u8) -> u32 {
+    let data = (tables::format_bits(ec_level) << 3) | mask as u32;
+    let mut rem = data;
+    for _ in 0..10 {
+        rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+    }
+    ((data << 10) | rem) ^ 0x5412
+}
+
+// 18-bit version information (version 7 ขึ้นไป)
+pub(crate) fn version_information(version: u8) -> u32 {
+    let mut rem = version as u32;
+    for _ in 0..12 {
+        rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+    }
+    ((version as u32) << 12) | rem
+}
+
+fn add_ecc_and_interleave(data: &[u8], version: u8, ec_level: EcLevel) -> Vec<u8> {
+    let num_blocks = tables::num_blocks(version, ec_level);
+    let ecc_len = tables::ecc_per_block(version, ec_level);
+    let raw_codewords = tables::raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_len = raw_codewords / num_blocks;
+
+    // แบ่งข้อมูลเป็น block แล้วคำนวณ EC ของแต่ละ block
+    let divisor = reed_solomon::divisor(ecc_len);
+    let mut blocks: Vec<Vec<u8>> = Vec::with_capacity(num_blocks);
+    let mut k = 0;
+    for i in 0..num_blocks {
+        let len = short_block_len - ecc_len + usize::from(i >= num_short_blocks);
+        let mut block = data[k..k + len].to_vec();
+        k += len;
+        let ecc = reed_solomon::remainder(&block, &divisor);
+        if i < num_short_blocks {
+            block.push(0); // ตำแหน่งว่างเพื่อให้ทุก block ยาวเท่ากัน
+        }
+        block.extend(ecc);
+        blocks.push(block);
+    }
+
+    // สลับ codeword ระหว่าง block (interleave)
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            if i != short_block_len - ecc_len || j >= num_short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+// Rule 1: run ของสีเดียวกันยาว 5 ขึ้นไป
+fn run_penalty(line: &[bool]) -> usize {
+    let mut result = 0;
+    let mut run = 1;
+    for i in 1..=line.len() {
+        if i < line.len() && line[i] == line[i - 1] {
+            run += 1;
+        } else {
+            if run >= 5 {
+                result += PENALTY_N1 + run - 5;
+            }
+            run = 1;
+        }
+    }
+    result
+}
+
+// Rule 3: รูปแบบคล้าย finder (1:1:3:1:1) ที่มี light 4 module ด้านใดด้านหนึ่ง
+fn finder_penalty(line: &[bool]) -> usize {
+    const PATTERN: [bool; 7] = [true, false, true, true, true, false, true];
+    let light = |i: isize| i < 0 || i >= line.len() as isize || !line[i as usize];
+    let mut result = 0;
+    for start in 0..line.len().saturating_sub(6) {
+        if line[start..start + 7] != PATTERN {
+            continue;
+        }
+        let s = start as isize;
+        let before = (1..=4).all(|d| light(s - d));
+        let after = (7..=10).all(|d| light(s + d));
+        if before || after {
+            result += PENALTY_N3;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการแบ่ง segment ของ payload PromptPay
+    #[test]
+    fn test_segment_promptpay_payload() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(250.75);
+        let payload = qr.create().unwrap();
+        let segments = segment(&payload, 1);
+
+        let joined: String = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(joined, payload);
+        assert!(segments.iter().all(|s| s.mode != Mode::Byte));
+        assert!(
+            segments
+                .iter()
+                .any(|s| s.mode == Mode::Numeric && s.text.len() > 20)
+        );
+
+        // segment แบบผสมต้องใช้ bit น้อยกว่าการเข้ารหัสแบบ byte ทั้งหมด
+        let mixed: usize = segments.iter().map(|s| s.total_bits(1).unwrap()).sum();
+        let byte_only = Segment {
+            mode: Mode::Byte,
+            text: payload.clone(),
+        };
+        assert!(mixed < byte_only.total_bits(1).unwrap() * 3 / 4);
+    }
+
+    /// ทดสอบตำแหน่ง alignment pattern และความจุของแต่ละ version
+    #[test]
+    fn test_tables() {
+        assert!(tables::alignment_positions(1).is_empty());
+        assert_eq!(tables::alignment_positions(7), vec![6, 22, 38]);
+        assert_eq!(
+            tables::alignment_positions(32),
+            vec![6, 34, 60, 86, 112, 138]
+        );
+        assert_eq!(tables::data_codewords(1, EcLevel::L), 19);
+        assert_eq!(tables::data_codewords(1, EcLevel::H), 9);
+        assert_eq!(tables::data_codewords(40, EcLevel::L), 2956);
+        assert_eq!(format_information(EcLevel::M, 5), 0b100000011001110);
+        assert_eq!(version_information(7), 0x07C94);
+    }
+
+    /// ทดสอบการเข้ารหัสตัวอย่างจากมาตรฐาน (HELLO WORLD, version 1-Q)
+    #[test]
+    fn test_encode_hello_world() {
+        let matrix = QrMatrix::encode("HELLO WORLD", EcLevel::Q).unwrap();
+        assert_eq!(matrix.version(), 1);
+        // finder pattern มุมบนซ้าย
+        assert!(matrix.is_dark(0, 0) && matrix.is_dark(6, 6) && !matrix.is_dark(1, 1));
+        assert!(!matrix.is_dark(7, 7));
+        // dark module คงที่ที่ (8, size - 8)
+        assert!(matrix.is_dark(8, matrix.size() - 8));
+    }
+
+    /// ทดสอบการเลือก version ที่เล็กที่สุดและข้อผิดพลาดเมื่อข้อมูลยาวเกิน
+    #[test]
+    fn test_encode_version_selection() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(250.75);
+        let low = qr.to_qr_matrix(EcLevel::L).unwrap();
+        let high = qr.to_qr_matrix(EcLevel::H).unwrap();
+        assert!(low.version() < high.version());
+        assert_eq!(low.ec_level(), EcLevel::L);
+
+        let digits = "1".repeat(7089);
+        assert_eq!(QrMatrix::encode(&digits, EcLevel::L).unwrap().version(), 40);
+        let err = QrMatrix::encode(&format!("{}1", digits), EcLevel::L).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Render);
+    }
+
+    /// ทดสอบการถอดรหัสกลับทุกระดับ EC และหลาย version (numeric, alphanumeric, byte)
+    #[test]
+    fn test_decode_roundtrip() {
+        let texts = [
+            "HELLO WORLD".to_string(),
+            "00020101021129370016A000000677010111".repeat(5),
+            format!("ร้านกาแฟ {}", "1234567890".repeat(40)),
+        ];
+        for text in &texts {
+            for ec_level in [EcLevel::L, EcLevel::M, EcLevel::Q, EcLevel::H] {
+                let matrix = QrMatrix::encode(text, ec_level).unwrap();
+                assert_eq!(&matrix.decode().unwrap(), text, "{:?}", ec_level);
+            }
+        }
+        assert!(decode_modules(20, &[false; 400]).is_err());
+        assert!(decode_modules(21, &[false; 441]).is_err());
+    }
+
+    /// ทดสอบการแก้ไข module ที่เสียหาย และการปฏิเสธเมื่อเสียหายเกินความสามารถ
+    #[test]
+    fn test_decode_with_damage() {
+        let payload = PromptPayQR::new("0812345678").create().unwrap();
+        let matrix = QrMatrix::encode(&payload, EcLevel::H).unwrap();
+        let size = matrix.size();
+
+        let mut modules = matrix.modules().to_vec();
+        for y in 12..18 {
+            for x in 12..16 {
+                modules[y * size + x] = !modules[y * size + x];
+            }
+        }
+        assert_eq!(decode_modules(size, &modules).unwrap(), payload);
+
+        let mut modules = matrix.modules().to_vec();
+        for y in 9..size - 9 {
+            for x in 9..size {
+                modules[y * size + x] = !modules[y * size + x];
+            }
+        }
+        assert!(decode_modules(size, &modules).is_err());
+    }
+
+    /// ทดสอบการเว้นพื้นที่โลโก้: เลือก EC level ให้อัตโนมัติ และปฏิเสธโลโก้ที่ใหญ่เกิน
+    #[test]
+    fn test_encode_with_logo() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(150.0);
+        let payload = qr.create().unwrap();
+
+        let (matrix, area) = qr
+            .to_qr_matrix_with_logo(EcLevel::L, LogoOverlay::square(0.15))
+            .unwrap();
+        assert!(matrix.ec_level() >= EcLevel::Q);
+        assert_eq!(matrix.decode().unwrap(), payload);
+        assert_eq!(area.x * 2 + area.width, matrix.size());
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                assert!(!matrix.is_dark(x, y));
+            }
+        }
+
+        // alignment pattern กลางสัญลักษณ์ (version 7 ขึ้นไป) ต้องไม่ถูกล้าง
+        let text = "0".repeat(200);
+        let plain = QrMatrix::encode(&text, EcLevel::Q).unwrap();
+        let (matrix, area) =
+            QrMatrix::encode_with_logo(&text, EcLevel::Q, LogoOverlay::square(0.1)).unwrap();
+        assert_eq!(matrix.version(), plain.version());
+        let centre = matrix.size() / 2;
+        assert!(matrix.version() >= 7 && matrix.is_function(centre, centre));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                if matrix.is_function(x, y) {
+                    assert_eq!(matrix.is_dark(x, y), plain.is_dark(x, y));
+                } else {
+                    assert!(!matrix.is_dark(x, y));
+                }
+            }
+        }
+        assert!(matrix.is_dark(centre, centre));
+        assert_eq!(matrix.decode().unwrap(), text);
+
+        // โลโก้ใหญ่ขึ้นต้องใช้ระดับ H
+        let (matrix, _) = qr
+            .to_qr_matrix_with_logo(EcLevel::L, LogoOverlay::square(0.25))
+            .unwrap();
+        assert_eq!(matrix.ec_level(), EcLevel::H);
+
+        let err = qr
+            .to_qr_matrix_with_logo(EcLevel::L, LogoOverlay::square(0.4))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(
+            qr.to_qr_matrix_with_logo(EcLevel::L, LogoOverlay::square(0.0))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,207 @@
+// เลขคณิตใน GF(2^8) ด้วย primitive polynomial x^8 + x^4 + x^3 + x^2 + 1 (0x11D)
+
+/// Multiplies two elements of GF(2^8).
+pub fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut z: u16 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u16 >> i) & 1) * x as u16;
+    }
+    z as u8
+}
+
+/// Returns the generator polynomial of the given degree (coefficients from highest
+/// to lowest power, leading `1` omitted).
+pub fn divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+
+    // คูณ (x - α^0)(x - α^1)...(x - α^(degree-1))
+    let mut root: u8 = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_mul(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    result
+}
+
+/// Computes the EC codewords (remainder of `data` divided by `divisor`).
+pub fn remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for &b in data {
+        let factor = b ^ result.remove(0);
+        result.push(0);
+        for (r, &d) in result.iter_mut().zip(divisor) {
+            *r ^= gf_mul(d, factor);
+        }
+    }
+    result
+}
+
+// ตาราง exp / log ของ α = 0x02 สำหรับการหารและยกกำลังใน GF(2^8)
+struct Tables {
+    exp: [u8; 255],
+    log: [u8; 256],
+}
+
+impl Tables {
+    fn new() -> Self {
+        let mut tables = Tables {
+            exp: [0; 255],
+            log: [0; 256],
+        };
+        let mut x: u8 = 1;
+        for i in 0..255 {
+            tables.exp[i] = x;
+            tables.log[x as usize] = i as u8;
+            x = gf_mul(x, 0x02);
+        }
+        tables
+    }
+
+    // α^power (power ติดลบได้)
+    fn pow(&self, power: i32) -> u8 {
+        self.exp[power.rem_euclid(255) as usize]
+    }
+
+    fn div(&self, x: u8, y: u8) -> u8 {
+        if x == 0 {
+            return 0;
+        }
+        self.pow(self.log[x as usize] as i32 - self.log[y as usize] as i32)
+    }
+}
+
+// ประเมินค่า polynomial (สัมประสิทธิ์เรียงจากกำลังต่ำไปสูง) ที่ x
+fn eval_low_first(poly: &[u8], x: u8) -> u8 {
+    poly.iter().rev().fold(0, |acc, &c| gf_mul(acc, x) ^ c)
+}
+
+/// Corrects errors in a block of data followed by `ecc_len` EC codewords, in place.
+///
+/// Uses Berlekamp-Massey to find the error locator, a Chien search for the
+/// positions and Forney's formula for the values.
+///
+/// # Returns
+/// * `Some(n)` - Number of corrected codewords (at most `ecc_len / 2`)
+/// * `None` - If the block has too many errors to correct
+pub fn correct(block: &mut [u8], ecc_len: usize) -> Option<usize> {
+    let gf = Tables::new();
+    let len = block.len();
+
+    // syndrome S_i = c(α^i); block[0] คือสัมประสิทธิ์ของกำลังสูงสุด
+    let syndromes: Vec<u8> = (0..ecc_len)
+        .map(|i| {
+            let x = gf.pow(i as i32);
+            block.iter().fold(0, |acc, &c| gf_mul(acc, x) ^ c)
+        })
+        .collect();
+    if syndromes.iter().all(|&s| s == 0) {
+        return Some(0);
+    }
+
+    // Berlekamp-Massey → error locator Λ(x)
+    let mut locator = vec![1u8];
+    let mut prev = vec![1u8];
+    let mut errors = 0;
+    let mut shift = 1;
+    let mut prev_discrepancy = 1u8;
+    for n in 0..ecc_len {
+        let discrepancy = (1..=errors.min(locator.len() - 1)).fold(syndromes[n], |acc, i| {
+            acc ^ gf_mul(locator[i], syndromes[n - i])
+        });
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+        let coef = gf.div(discrepancy, prev_discrepancy);
+        let mut next = locator.clone();
+        next.resize(next.len().max(prev.len() + shift), 0);
+        for (i, &p) in prev.iter().enumerate() {
+            next[i + shift] ^= gf_mul(coef, p);
+        }
+        if 2 * errors <= n {
+            prev = std::mem::replace(&mut locator, next);
+            errors = n + 1 - errors;
+            prev_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            locator = next;
+            shift += 1;
+        }
+    }
+    if 2 * errors > ecc_len {
+        return None;
+    }
+
+    // Chien search: Λ(α^-p) = 0 → ผิดที่กำลัง p (index len - 1 - p)
+    let positions: Vec<usize> = (0..len)
+        .filter(|&p| eval_low_first(&locator, gf.pow(-(p as i32))) == 0)
+        .collect();
+    if positions.len() != errors {
+        return None;
+    }
+
+    // Forney: e = X · Ω(X⁻¹) / Λ'(X⁻¹) โดย Ω(x) = S(x)Λ(x) mod x^ecc_len
+    let mut evaluator = vec![0u8; ecc_len];
+    for (i, &s) in syndromes.iter().enumerate() {
+        for (j, &l) in locator.iter().enumerate() {
+            if i + j < ecc_len {
+                evaluator[i + j] ^= gf_mul(s, l);
+            }
+        }
+    }
+    let derivative: Vec<u8> = locator
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, &l)| if i % 2 == 1 { l } else { 0 })
+        .collect();
+    for &p in &positions {
+        let x_inv = gf.pow(-(p as i32));
+        let denominator = eval_low_first(&derivative, x_inv);
+        if denominator == 0 {
+            return None;
+        }
+        let magnitude = gf_mul(
+            gf.pow(p as i32),
+            gf.div(eval_low_first(&evaluator, x_inv), denominator),
+        );
+        block[len - 1 - p] ^= magnitude;
+    }
+    Some(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการแก้ไข codeword ที่ผิดได้สูงสุด ecc_len / 2 ตำแหน่ง
+    #[test]
+    fn test_correct_errors() {
+        let data: Vec<u8> = (0..20u8).map(|i| i.wrapping_mul(37)).collect();
+        let mut block = data.clone();
+        block.extend(remainder(&data, &divisor(10)));
+        let original = block.clone();
+
+        assert_eq!(correct(&mut block.clone(), 10), Some(0));
+        for (i, pos) in [0, 7, 13, 22, 29].into_iter().enumerate() {
+            block[pos] ^= 0x5A + i as u8;
+        }
+        assert_eq!(correct(&mut block, 10), Some(5));
+        assert_eq!(block, original);
+
+        for pos in [1, 3, 5, 8, 11, 17] {
+            block[pos] ^= 0xFF;
+        }
+        let mut damaged = block.clone();
+        if correct(&mut damaged, 10).is_some() {
+            assert_ne!(damaged, original); // เกินความสามารถ → ไม่ได้ข้อมูลเดิมกลับมา
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use super::QrMatrix;
+#[cfg(any(feature = "qrcode", feature = "scan", feature = "batch"))]
+use crate::{PromptPayError, error::ErrorKind};
+
+/// Width of the light border around the symbol, in modules.
+pub const QUIET_ZONE: usize = 4;
+
+impl QrMatrix {
+    /// Renders the symbol with a 4-module quiet zone as an SVG document.
+    ///
+    /// # Arguments
+    /// * `module_size` - Pixels per module for the `width` / `height` attributes;
+    ///   `None` leaves them out so the image scales to its container
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::{EcLevel, qr::QrMatrix};
+    /// let matrix = QrMatrix::encode("HELLO WORLD", EcLevel::Q).unwrap();
+    /// assert!(matrix.to_svg(Some(10)).contains(r#"width="290""#)); // (21 + 8) * 10
+    /// assert!(matrix.to_svg(None).contains(r#"svg" viewBox="0 0 29 29""#)); // ไม่มี width / height
+    /// ```
+    pub fn to_svg(&self, module_size: Option<u32>) -> String {
+        let size = self.size + QUIET_ZONE * 2;
+        let dimensions = match module_size {
+            Some(module_size) => {
+                let px = size as u32 * module_size;
+                format!(r#" width="{}" height="{}""#, px, px)
+            }
+            None => String::new(),
+        };
+        format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg"{dimensions} viewBox="0 0 {size} {size}" shape-rendering="crispEdges">"#,
+                r##"<rect width="{size}" height="{size}" fill="#ffffff"/>"##,
+                r#"<path transform="translate({quiet} {quiet})" d="{path}"/></svg>"#
+            ),
+            dimensions = dimensions,
+            size = size,
+            quiet = QUIET_ZONE,
+            path = self.svg_path(),
+        )
+    }
+
+    /// Renders the symbol with a 4-module quiet zone as a grayscale PNG
+    /// (requires the `qrcode`, `scan` or `batch` feature).
+    ///
+    /// # Arguments
+    /// * `module_size` - Pixels per module (at least 1)
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - PNG file contents
+    /// * `Err(PromptPayError)` - [`ErrorKind::Render`] if `module_size` is 0 or encoding fails
+    #[cfg(any(feature = "qrcode", feature = "scan", feature = "batch"))]
+    pub fn to_png(&self, module_size: u32) -> Result<Vec<u8>, PromptPayError> {
+        if module_size == 0 {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::Render,
+                "Module size must be at least 1 pixel",
+            ));
+        }
+        let size = (self.size + QUIET_ZONE * 2) as u32 * module_size;
+        let image = image::GrayImage::from_fn(size, size, |x, y| {
+            let (mx, my) = ((x / module_size) as usize, (y / module_size) as usize);
+            let dark = mx >= QUIET_ZONE
+                && my >= QUIET_ZONE
+                && self.is_dark(mx - QUIET_ZONE, my - QUIET_ZONE);
+            image::Luma([if dark { 0 } else { 255 }])
+        });
+        let mut png = Vec::new();
+        image
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .map_err(|e| {
+                PromptPayError::with_kind(
+                    ErrorKind::Render,
+                    &format!("Failed to encode PNG: {}", e),
+                )
+            })?;
+        Ok(png)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EcLevel;
+
+    /// ทดสอบ SVG ทั้งแบบกำหนดขนาด pixel และแบบปรับตามขนาดพื้นที่
+    #[test]
+    fn test_to_svg() {
+        let matrix = QrMatrix::encode("HELLO WORLD", EcLevel::Q).unwrap();
+        let svg = matrix.to_svg(Some(4));
+        assert!(svg.starts_with(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="116" height="116" viewBox="0 0 29 29""#
+        ));
+        assert!(svg.contains(r#"translate(4 4)"#));
+        assert!(svg.contains(&matrix.svg_path()));
+        assert!(
+            matrix
+                .to_svg(None)
+                .starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 29 29""#)
+        );
+    }
+
+    /// ทดสอบว่า PNG มีขนาดตาม module และอ่านกลับได้ตรงกับ matrix
+    #[cfg(any(feature = "qrcode", feature = "scan", feature = "batch"))]
+    #[test]
+    fn test_to_png() {
+        let matrix = QrMatrix::encode("HELLO WORLD", EcLevel::Q).unwrap();
+        let png = matrix.to_png(3).unwrap();
+        let image = image::load_from_memory(&png).unwrap().to_luma8();
+        assert_eq!(image.dimensions(), (87, 87));
+        assert_eq!(image.get_pixel(0, 0).0, [255]); // quiet zone
+        assert_eq!(image.get_pixel(12, 12).0, [0]); // มุมบนซ้ายของ finder pattern
+        assert_eq!(
+            image.get_pixel(12 + 3 * 8, 12 + 3 * 8).0[0] == 0,
+            matrix.is_dark(8, 8)
+        );
+        assert!(matrix.to_png(0).is_err());
+    }
+}
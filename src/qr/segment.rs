@@ -0,0 +1,234 @@
+/// QR **encoding mode** of a segment.
+///
+/// - `Numeric` → digits only, 10 bits per 3 characters
+/// - `Alphanumeric` → `0-9 A-Z space $ % * + - . / :`, 11 bits per 2 characters
+/// - `Byte` → any text as UTF-8, 8 bits per byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Numeric,
+    Alphanumeric,
+    Byte,
+}
+
+pub(crate) const ALPHANUMERIC_CHARSET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+impl Mode {
+    const ALL: [Mode; 3] = [Mode::Numeric, Mode::Alphanumeric, Mode::Byte];
+
+    /// 4-bit mode indicator written before the segment data.
+    pub fn indicator(&self) -> u32 {
+        match self {
+            Mode::Numeric => 0b0001,
+            Mode::Alphanumeric => 0b0010,
+            Mode::Byte => 0b0100,
+        }
+    }
+
+    /// Width of the character count field for a version.
+    pub fn char_count_bits(&self, version: u8) -> usize {
+        // แบ่งเป็น 3 ช่วง: version 1-9, 10-26, 27-40
+        let class = match version {
+            1..=9 => 0,
+            10..=26 => 1,
+            _ => 2,
+        };
+        match self {
+            Mode::Numeric => [10, 12, 14][class],
+            Mode::Alphanumeric => [9, 11, 13][class],
+            Mode::Byte => [8, 16, 16][class],
+        }
+    }
+
+    fn can_encode(&self, c: char) -> bool {
+        match self {
+            Mode::Numeric => c.is_ascii_digit(),
+            Mode::Alphanumeric => ALPHANUMERIC_CHARSET.contains(c),
+            Mode::Byte => true,
+        }
+    }
+
+    // ต้นทุนต่อตัวอักษรในหน่วย 1/6 bit (numeric 10/3 bit, alphanumeric 5.5 bit)
+    fn char_cost(&self, c: char) -> usize {
+        match self {
+            Mode::Numeric => 20,
+            Mode::Alphanumeric => 33,
+            Mode::Byte => c.len_utf8() * 8 * 6,
+        }
+    }
+}
+
+/// A run of text encoded in a single [`Mode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub mode: Mode,
+    pub text: String,
+}
+
+impl Segment {
+    /// Number of characters stored in the character count field.
+    pub fn char_count(&self) -> usize {
+        match self.mode {
+            Mode::Byte => self.text.len(),
+            _ => self.text.chars().count(),
+        }
+    }
+
+    /// Number of data bits (excluding mode indicator and character count).
+    pub fn data_bits(&self) -> usize {
+        let n = self.char_count();
+        match self.mode {
+            Mode::Numeric => n / 3 * 10 + [0, 4, 7][n % 3],
+            Mode::Alphanumeric => n / 2 * 11 + (n % 2) * 6,
+            Mode::Byte => n * 8,
+        }
+    }
+
+    /// Total bits for a version, or `None` if the character count does not fit.
+    pub fn total_bits(&self, version: u8) -> Option<usize> {
+        let count_bits = self.mode.char_count_bits(version);
+        if self.char_count() >= 1 << count_bits {
+            return None;
+        }
+        Some(4 + count_bits + self.data_bits())
+    }
+
+    /// Appends the encoded segment (mode, count and data) to a bit buffer.
+    pub(crate) fn write_bits(&self, version: u8, bits: &mut BitBuffer) {
+        bits.push(self.mode.indicator(), 4);
+        bits.push(self.char_count() as u32, self.mode.char_count_bits(version));
+        match self.mode {
+            Mode::Numeric => {
+                let digits: Vec<u32> = self.text.bytes().map(|b| (b - b'0') as u32).collect();
+                for chunk in digits.chunks(3) {
+                    let value = chunk.iter().fold(0, |acc, d| acc * 10 + d);
+                    bits.push(value, chunk.len() * 3 + 1);
+                }
+            }
+            Mode::Alphanumeric => {
+                let values: Vec<u32> = self
+                    .text
+                    .chars()
+                    .map(|c| ALPHANUMERIC_CHARSET.find(c).unwrap_or(0) as u32)
+                    .collect();
+                for chunk in values.chunks(2) {
+                    match chunk {
+                        [a, b] => bits.push(a * 45 + b, 11),
+                        [a] => bits.push(*a, 6),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+            Mode::Byte => {
+                for b in self.text.bytes() {
+                    bits.push(b as u32, 8);
+                }
+            }
+        }
+    }
+}
+
+/// Splits text into segments that minimise the encoded size for a version.
+///
+/// Uses dynamic programming over every character, accounting for the header
+/// (mode indicator + character count) paid on each mode switch. PromptPay
+/// payloads are mostly digits with a few uppercase letters, so they usually end
+/// up as long numeric runs joined by short alphanumeric segments.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::qr::{Mode, segment};
+///
+/// let segments = segment("00020101021129370016A000000677010111", 1);
+/// assert_eq!(segments[0].mode, Mode::Numeric);
+/// ```
+pub fn segment(text: &str, version: u8) -> Vec<Segment> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.is_empty() {
+        return Vec::new();
+    }
+
+    let head_costs: Vec<usize> = Mode::ALL
+        .iter()
+        .map(|m| (4 + m.char_count_bits(version)) * 6)
+        .collect();
+
+    // char_modes[i][m] = mode ของตัวอักษรก่อนหน้าเมื่อตัวอักษร i ถูกเข้ารหัสด้วย mode m
+    let mut char_modes: Vec<[Option<usize>; 3]> = vec![[None; 3]; chars.len()];
+    let mut prev_costs = head_costs.clone();
+
+    for (i, &c) in chars.iter().enumerate() {
+        let mut cur_costs = [0usize; 3];
+        for (m, mode) in Mode::ALL.iter().enumerate() {
+            if mode.can_encode(c) {
+                cur_costs[m] = prev_costs[m] + mode.char_cost(c);
+                char_modes[i][m] = Some(m);
+            }
+        }
+
+        // พิจารณาการเปลี่ยน mode หลังตัวอักษรนี้
+        for to in 0..3 {
+            for from in 0..3 {
+                if char_modes[i][from].is_none() {
+                    continue;
+                }
+                let new_cost = cur_costs[from].div_ceil(6) * 6 + head_costs[to];
+                if char_modes[i][to].is_none() || new_cost < cur_costs[to] {
+                    cur_costs[to] = new_cost;
+                    char_modes[i][to] = Some(from);
+                }
+            }
+        }
+        prev_costs = cur_costs.to_vec();
+    }
+
+    // ย้อนกลับหา mode ที่ดีที่สุดของแต่ละตัวอักษร
+    let mut cur_mode = (0..3).min_by_key(|&m| prev_costs[m]).unwrap_or(2);
+    let mut modes = vec![0usize; chars.len()];
+    for i in (0..chars.len()).rev() {
+        cur_mode = char_modes[i][cur_mode].unwrap_or(2);
+        modes[i] = cur_mode;
+    }
+
+    let mut segments: Vec<Segment> = Vec::new();
+    for (c, m) in chars.into_iter().zip(modes) {
+        let mode = Mode::ALL[m];
+        match segments.last_mut() {
+            Some(last) if last.mode == mode => last.text.push(c),
+            _ => segments.push(Segment {
+                mode,
+                text: c.to_string(),
+            }),
+        }
+    }
+    segments
+}
+
+/// Growable big-endian bit buffer.
+#[derive(Debug, Default)]
+pub(crate) struct BitBuffer {
+    pub bits: Vec<bool>,
+}
+
+impl BitBuffer {
+    pub fn push(&mut self, value: u32, len: usize) {
+        for i in (0..len).rev() {
+            self.bits.push((value >> i) & 1 != 0);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.bits
+            .chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u8, |acc, (i, &b)| acc | ((b as u8) << (7 - i)))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,84 @@
+use super::{QrMatrix, tables};
+use crate::{EcLevel, PromptPayError, PromptPayQR, error::ErrorKind};
+
+// ขนาด module ขั้นต่ำที่กล้องโทรศัพท์ทั่วไปอ่านได้ที่ระยะสแกนปกติ (มม.)
+pub(crate) const MIN_MODULE_SIZE_MM: f64 = 0.33;
+// quiet zone ที่มาตรฐานกำหนด (module ต่อด้าน)
+const QUIET_ZONE_MODULES: usize = 4;
+
+/// Size report for the QR symbol of a payload at a given print resolution.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{EcLevel, PromptPayQR};
+///
+/// let mut qr = PromptPayQR::new("0812345678");
+/// qr.set_amount(150.0);
+/// let info = qr.symbol_info(EcLevel::M, 203).unwrap(); // 203 DPI thermal printer
+/// assert_eq!(info.version, 4);
+/// assert_eq!(info.modules, 33);
+/// assert!(info.fits_within_mm(20.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymbolInfo {
+    /// QR version (1-40)
+    pub version: u8,
+    /// Error correction level
+    pub ec_level: EcLevel,
+    /// Modules per side, excluding the quiet zone
+    pub modules: usize,
+    /// Payload length in characters
+    pub payload_length: usize,
+    /// Printer dots per module (whole dots, at least 0.33 mm per module)
+    pub dots_per_module: u32,
+    /// Smallest printable side length including the 4-module quiet zone, in millimetres
+    pub min_print_size_mm: f64,
+}
+
+impl SymbolInfo {
+    /// Returns `true` if the symbol can be printed within a square of `size_mm`
+    /// (e.g. `20.0` for a 2 cm sticker).
+    pub fn fits_within_mm(&self, size_mm: f64) -> bool {
+        self.min_print_size_mm <= size_mm
+    }
+}
+
+impl PromptPayQR {
+    /// Reports the QR version, module count and minimum print size of the payload.
+    ///
+    /// Modules are rounded up to whole printer dots of at least 0.33 mm, the usual
+    /// minimum for phone cameras, and a 4-module quiet zone is added on each side.
+    ///
+    /// # Arguments
+    /// * `ec_level` - Error correction level
+    /// * `dpi` - Printer resolution in dots per inch (e.g. `203` or `300`)
+    ///
+    /// # Returns
+    /// * `Ok(SymbolInfo)` - Size report
+    /// * `Err(PromptPayError)` - If the payload cannot be created, or `dpi` is 0
+    pub fn symbol_info(&self, ec_level: EcLevel, dpi: u32) -> Result<SymbolInfo, PromptPayError> {
+        if dpi == 0 {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "DPI must be at least 1",
+            ));
+        }
+        let payload = self.create()?;
+        let (version, _) = QrMatrix::fit(&payload, ec_level)?;
+        let modules = tables::size(version);
+
+        let dot_mm = 25.4 / dpi as f64;
+        let dots_per_module = (MIN_MODULE_SIZE_MM / dot_mm).ceil().max(1.0) as u32;
+        let min_print_size_mm =
+            (modules + QUIET_ZONE_MODULES * 2) as f64 * dots_per_module as f64 * dot_mm;
+
+        Ok(SymbolInfo {
+            version,
+            ec_level,
+            modules,
+            payload_length: payload.chars().count(),
+            dots_per_module,
+            min_print_size_mm,
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use crate::EcLevel;
+
+// จำนวน codeword สำหรับแก้ไขข้อผิดพลาดต่อ block (index = version, 0 ไม่ใช้)
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    // L
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    // M
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    // Q
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    // H
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+// จำนวน block ของ error correction (index = version, 0 ไม่ใช้)
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    // L
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    // M
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    // Q
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    // H
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// Smallest QR version.
+pub const MIN_VERSION: u8 = 1;
+/// Largest QR version.
+pub const MAX_VERSION: u8 = 40;
+
+fn level_index(ec_level: EcLevel) -> usize {
+    match ec_level {
+        EcLevel::L => 0,
+        EcLevel::M => 1,
+        EcLevel::Q => 2,
+        EcLevel::H => 3,
+    }
+}
+
+/// 2-bit value of the EC level in the format information.
+pub fn format_bits(ec_level: EcLevel) -> u32 {
+    match ec_level {
+        EcLevel::L => 1,
+        EcLevel::M => 0,
+        EcLevel::Q => 3,
+        EcLevel::H => 2,
+    }
+}
+
+/// Number of modules per side for a version.
+pub fn size(version: u8) -> usize {
+    version as usize * 4 + 17
+}
+
+/// EC codewords per block.
+pub fn ecc_per_block(version: u8, ec_level: EcLevel) -> usize {
+    ECC_CODEWORDS_PER_BLOCK[level_index(ec_level)][version as usize] as usize
+}
+
+/// Number of EC blocks.
+pub fn num_blocks(version: u8, ec_level: EcLevel) -> usize {
+    NUM_ERROR_CORRECTION_BLOCKS[level_index(ec_level)][version as usize] as usize
+}
+
+/// Number of modules available for data and EC codewords (including remainder bits).
+pub fn raw_data_modules(version: u8) -> usize {
+    let v = version as usize;
+    let mut result = (16 * v + 128) * v + 64;
+    if v >= 2 {
+        let num_align = v / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if v >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+/// Number of 8-bit data codewords (excluding EC) for a version and EC level.
+pub fn data_codewords(version: u8, ec_level: EcLevel) -> usize {
+    raw_data_modules(version) / 8 - ecc_per_block(version, ec_level) * num_blocks(version, ec_level)
+}
+
+/// Centre coordinates of the alignment patterns (same for rows and columns).
+pub fn alignment_positions(version: u8) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let v = version as usize;
+    let num_align = v / 7 + 2;
+    let step = (v * 8 + num_align * 3 + 5) / (num_align * 4 - 4) * 2;
+    let last = size(version) - 7;
+    (0..num_align)
+        .map(|i| {
+            if i == 0 {
+                6
+            } else {
+                last - (num_align - 1 - i) * step
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,290 @@
+//! Rendering of PromptPay payloads to images (requires the `qrcode` feature).
+//!
+//! ```toml
+//! [dependencies]
+//! promptpay-rs = { version = "0.6.0", features = ["qrcode"] }
+//! ```
+
+use crate::{EcLevel, PromptPayError, PromptPayQR, error::ErrorKind};
+use image::{ImageFormat, Rgb, RgbImage};
+use qrcode::QrCode;
+use std::{fmt::Write, io::Cursor};
+
+/// Options controlling how a QR code is rendered.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{EcLevel, render::RenderOptions};
+///
+/// let options = RenderOptions {
+///     module_size: 4,
+///     ec_level: EcLevel::Q,
+///     ..RenderOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Size of one module in pixels (PNG) or user units (SVG); must be at least 1
+    pub module_size: u32,
+    /// Width of the light border around the symbol, in modules (4 recommended)
+    pub quiet_zone: u32,
+    /// RGB colour of dark modules
+    pub dark_color: [u8; 3],
+    /// RGB colour of light modules and the quiet zone
+    pub light_color: [u8; 3],
+    /// Error correction level of the symbol
+    pub ec_level: EcLevel,
+}
+
+impl Default for RenderOptions {
+    /// 8 px modules, 4-module quiet zone, black on white, level `M`.
+    fn default() -> Self {
+        RenderOptions {
+            module_size: 8,
+            quiet_zone: 4,
+            dark_color: [0, 0, 0],
+            light_color: [255, 255, 255],
+            ec_level: EcLevel::M,
+        }
+    }
+}
+
+impl PromptPayQR {
+    /// Renders the payload as an **SVG** document.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - SVG markup
+    /// * `Err(PromptPayError)` - If the payload cannot be created or encoded ([`ErrorKind::Render`])
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::{PromptPayQR, render::RenderOptions};
+    /// let svg = PromptPayQR::new("0812345678").to_svg(&RenderOptions::default()).unwrap();
+    /// assert!(svg.starts_with("<svg"));
+    /// ```
+    pub fn to_svg(&self, options: &RenderOptions) -> Result<String, PromptPayError> {
+        let modules = Modules::encode(self, options)?;
+        let size = modules.width as u32 + options.quiet_zone * 2;
+        let px = size * options.module_size;
+
+        let mut svg = format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" version="1.1" width="{px}" height="{px}""#,
+                r#" viewBox="0 0 {size} {size}" shape-rendering="crispEdges">"#,
+                r#"<rect width="{size}" height="{size}" fill="{light}"/>"#,
+                r#"<path fill="{dark}" d=""#
+            ),
+            px = px,
+            size = size,
+            light = hex_color(options.light_color),
+            dark = hex_color(options.dark_color),
+        );
+        for y in 0..modules.width {
+            for x in 0..modules.width {
+                if modules.is_dark(x, y) {
+                    let (left, top) =
+                        (x as u32 + options.quiet_zone, y as u32 + options.quiet_zone);
+                    write!(svg, "M{left} {top}h1v1h-1z").expect("writing to String cannot fail");
+                }
+            }
+        }
+        svg.push_str(r#""/></svg>"#);
+        Ok(svg)
+    }
+
+    /// Renders the payload as a **PNG** image.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - PNG file contents
+    /// * `Err(PromptPayError)` - If the payload cannot be created, encoded or written ([`ErrorKind::Render`])
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::{PromptPayQR, render::RenderOptions};
+    /// let png = PromptPayQR::new("0812345678").to_png_bytes(&RenderOptions::default()).unwrap();
+    /// assert_eq!(&png[1..4], b"PNG");
+    /// ```
+    pub fn to_png_bytes(&self, options: &RenderOptions) -> Result<Vec<u8>, PromptPayError> {
+        let modules = Modules::encode(self, options)?;
+        let scale = options.module_size;
+        let size = (modules.width as u32 + options.quiet_zone * 2) * scale;
+
+        let image = RgbImage::from_fn(size, size, |px, py| {
+            // แปลงพิกัด pixel เป็นพิกัด module (หัก quiet zone ออก)
+            let (mx, my) = (px / scale, py / scale);
+            let dark = mx >= options.quiet_zone
+                && my >= options.quiet_zone
+                && modules.is_dark(
+                    (mx - options.quiet_zone) as usize,
+                    (my - options.quiet_zone) as usize,
+                );
+            Rgb(if dark {
+                options.dark_color
+            } else {
+                options.light_color
+            })
+        });
+
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .map_err(|e| render_error(&format!("Failed to encode PNG: {}", e)))?;
+        Ok(png)
+    }
+
+    /// Renders the payload for display in a **terminal**.
+    ///
+    /// Each character cell holds two modules (`▀` with 24-bit ANSI foreground and
+    /// background colours), so the code keeps its colours regardless of the
+    /// terminal theme. `module_size` is ignored.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Lines separated by `\n`
+    /// * `Err(PromptPayError)` - If the payload cannot be created or encoded ([`ErrorKind::Render`])
+    pub fn to_terminal_string(&self, options: &RenderOptions) -> Result<String, PromptPayError> {
+        let modules = Modules::encode(self, options)?;
+        let quiet = options.quiet_zone as usize;
+        let size = modules.width + quiet * 2;
+        let color_at = |x: usize, y: usize| {
+            let dark = x >= quiet && y >= quiet && modules.is_dark(x - quiet, y - quiet);
+            if dark {
+                options.dark_color
+            } else {
+                options.light_color
+            }
+        };
+
+        let mut out = String::new();
+        for row in (0..size).step_by(2) {
+            let mut current = None;
+            for x in 0..size {
+                let top = color_at(x, row);
+                // แถวสุดท้ายของสัญลักษณ์ขนาดคี่ใช้สีพื้นหลังเป็นสี light
+                let bottom = if row + 1 < size {
+                    color_at(x, row + 1)
+                } else {
+                    options.light_color
+                };
+                // ส่ง escape code เฉพาะเมื่อสีเปลี่ยน เพื่อลดขนาดข้อความ
+                if current != Some((top, bottom)) {
+                    write!(
+                        out,
+                        "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m",
+                        top[0], top[1], top[2], bottom[0], bottom[1], bottom[2]
+                    )
+                    .expect("writing to String cannot fail");
+                    current = Some((top, bottom));
+                }
+                out.push('▀');
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        Ok(out)
+    }
+}
+
+// ผลลัพธ์ของการเข้ารหัส QR (module แบบ row-major, true = dark)
+struct Modules {
+    width: usize,
+    dark: Vec<bool>,
+}
+
+impl Modules {
+    fn encode(qr: &PromptPayQR, options: &RenderOptions) -> Result<Self, PromptPayError> {
+        if options.module_size == 0 {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "Module size must be at least 1",
+            ));
+        }
+
+        let payload = qr.create()?;
+        let ec_level = match options.ec_level {
+            EcLevel::L => qrcode::EcLevel::L,
+            EcLevel::M => qrcode::EcLevel::M,
+            EcLevel::Q => qrcode::EcLevel::Q,
+            EcLevel::H => qrcode::EcLevel::H,
+        };
+        let code = QrCode::with_error_correction_level(payload.as_bytes(), ec_level)
+            .map_err(|e| render_error(&format!("Failed to create QR code: {}", e)))?;
+        Ok(Modules {
+            width: code.width(),
+            dark: code
+                .to_colors()
+                .into_iter()
+                .map(|c| c == qrcode::Color::Dark)
+                .collect(),
+        })
+    }
+
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.width && self.dark[y * self.width + x]
+    }
+}
+
+fn hex_color(rgb: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+}
+
+fn render_error(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::Render, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการสร้าง SVG พร้อมสีและ quiet zone ที่กำหนดเอง
+    #[test]
+    fn test_to_svg_options() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(100.0);
+        let options = RenderOptions {
+            module_size: 3,
+            quiet_zone: 2,
+            dark_color: [0x1a, 0x33, 0x5f],
+            ..RenderOptions::default()
+        };
+        let svg = qr.to_svg(&options).unwrap();
+        // payload 65 ตัวอักษร ที่ระดับ M → version 4 (33 modules) + quiet zone 2×2
+        assert!(svg.contains(r#"viewBox="0 0 37 37""#));
+        assert!(svg.contains(r#"width="111""#));
+        assert!(svg.contains(r##"fill="#1a335f""##));
+        assert!(svg.contains("M2 2h1v1h-1z")); // มุมบนซ้ายของ finder pattern
+    }
+
+    /// ทดสอบการสร้าง PNG และตรวจสอบขนาดภาพ
+    #[test]
+    fn test_to_png_bytes() {
+        let qr = PromptPayQR::new("0812345678");
+        let options = RenderOptions {
+            module_size: 2,
+            ..RenderOptions::default()
+        };
+        let png = qr.to_png_bytes(&options).unwrap();
+        let image = image::load_from_memory(&png).unwrap().to_rgb8();
+        let modules = Modules::encode(&qr, &options).unwrap();
+        assert_eq!(image.width(), (modules.width as u32 + 8) * 2);
+        assert_eq!(image.get_pixel(0, 0).0, [255, 255, 255]);
+        assert_eq!(image.get_pixel(8, 8).0, [0, 0, 0]);
+    }
+
+    /// ทดสอบการแสดงผลใน terminal และการแปลงข้อผิดพลาด
+    #[test]
+    fn test_to_terminal_string_and_errors() {
+        let qr = PromptPayQR::new("0812345678");
+        let options = RenderOptions::default();
+        let text = qr.to_terminal_string(&options).unwrap();
+        let modules = Modules::encode(&qr, &options).unwrap();
+        assert_eq!(text.lines().count(), (modules.width + 8).div_ceil(2));
+        assert!(text.contains("\x1b[38;2;0;0;0m\x1b[48;2;0;0;0m▀"));
+
+        let err = PromptPayQR::new("").to_svg(&options).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let zero = RenderOptions {
+            module_size: 0,
+            ..options
+        };
+        assert!(qr.to_png_bytes(&zero).is_err());
+    }
+}
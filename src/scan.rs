@@ -0,0 +1,174 @@
+//! **Image scanning**: PromptPay QR codes from PNG / JPEG files (requires the `scan` feature).
+//!
+//! Images are decoded with the pure-Rust `image` crate and searched with the
+//! built-in detector ([`detect_codes`](crate::qr::detect_codes)), so no system
+//! libraries are needed. Screenshots and photos may contain several codes.
+//!
+//! ```toml
+//! [dependencies]
+//! promptpay-rs = { version = "0.6.0", features = ["scan"] }
+//! ```
+
+use crate::{
+    PromptPayError, PromptPayQR,
+    error::ErrorKind,
+    qr::{BoundingBox, detect_codes},
+};
+
+/// A QR code found by [`scan_image`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScannedCode {
+    /// Decoded text
+    pub text: String,
+    /// Area of the symbol in the image
+    pub bounds: BoundingBox,
+    /// Parsed payload, if the text is a valid PromptPay / Thai QR payload
+    pub qr: Option<PromptPayQR>,
+}
+
+/// Finds and decodes every QR code in a PNG or JPEG image.
+///
+/// # Returns
+/// * `Ok(Vec<ScannedCode>)` - Codes from top to bottom, empty if none were found
+/// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if the image cannot be decoded
+///
+/// # Example
+/// ```rust,no_run
+/// use promptpay_rs::scan::scan_image;
+///
+/// let image = std::fs::read("screenshot.png").unwrap();
+/// for code in scan_image(&image).unwrap() {
+///     println!("{:?}: {}", code.bounds, code.text);
+/// }
+/// ```
+pub fn scan_image(bytes: &[u8]) -> Result<Vec<ScannedCode>, PromptPayError> {
+    let image = image::load_from_memory(bytes)
+        .map_err(|e| {
+            PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Failed to decode image: {}", e),
+            )
+        })?
+        .to_luma8();
+    let (width, height) = (image.width() as usize, image.height() as usize);
+
+    let mut codes: Vec<ScannedCode> = detect_codes(width, height, image.as_raw())?
+        .into_iter()
+        .map(|code| ScannedCode {
+            qr: PromptPayQR::parse(&code.text).ok(),
+            text: code.text,
+            bounds: code.bounds,
+        })
+        .collect();
+    // เรียงตามตำแหน่งในภาพ: บนลงล่าง แล้วซ้ายไปขวา
+    codes.sort_by_key(|c| (c.bounds.y, c.bounds.x));
+    Ok(codes)
+}
+
+impl PromptPayQR {
+    /// Decodes the first PromptPay payload found in a PNG or JPEG image.
+    ///
+    /// # Returns
+    /// * `Ok(PromptPayQR)` - Parsed payload
+    /// * `Err(PromptPayError)` - If the image cannot be decoded, contains no QR code
+    ///   ([`ErrorKind::InvalidPayload`]), or its codes are not valid payloads (the
+    ///   error from [`PromptPayQR::parse`])
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use promptpay_rs::PromptPayQR;
+    ///
+    /// let qr = PromptPayQR::from_image(&std::fs::read("qr.jpg").unwrap()).unwrap();
+    /// println!("{} {:?}", qr.merchant_id(), qr.amount());
+    /// ```
+    pub fn from_image(bytes: &[u8]) -> Result<Self, PromptPayError> {
+        let codes = scan_image(bytes)?;
+        if let Some(qr) = codes.iter().find_map(|c| c.qr.clone()) {
+            return Ok(qr);
+        }
+        match codes.first() {
+            Some(code) => PromptPayQR::parse(&code.text),
+            None => Err(PromptPayError::with_kind(
+                ErrorKind::InvalidPayload,
+                "No QR code found in image",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EcLevel, qr::QrMatrix};
+    use image::{GrayImage, ImageFormat, Luma, codecs::jpeg::JpegEncoder};
+    use std::io::Cursor;
+
+    // ภาพพื้นเทาอ่อนพร้อม QR หลายอัน: (ข้อความ, ตำแหน่งซ้ายบน, ขนาด pixel ต่อ module)
+    fn render(codes: &[(&str, (u32, u32), u32)]) -> GrayImage {
+        let mut image = GrayImage::from_pixel(480, 320, Luma([230]));
+        for &(text, (left, top), scale) in codes {
+            let matrix = QrMatrix::encode(text, EcLevel::M).unwrap();
+            let side = matrix.size() as u32 * scale;
+            for y in 0..side {
+                for x in 0..side {
+                    let dark = matrix.is_dark((x / scale) as usize, (y / scale) as usize);
+                    image.put_pixel(left + x, top + y, Luma([if dark { 25 } else { 250 }]));
+                }
+            }
+        }
+        image
+    }
+
+    /// ทดสอบภาพ PNG ที่มี QR พร้อมเพย์และ QR อื่น: คืนค่าทุกอันพร้อมกรอบ
+    #[test]
+    fn test_scan_png() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(99.5);
+        let payload = qr.create().unwrap();
+        let image = render(&[
+            (&payload, (40, 30), 4),
+            ("https://example.com", (300, 180), 3),
+        ]);
+        let mut png = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+
+        let codes = scan_image(&png).unwrap();
+        assert_eq!(codes.len(), 2);
+        assert_eq!(codes[0].text, payload);
+        assert_eq!((codes[0].bounds.x, codes[0].bounds.y), (40, 30));
+        assert_eq!(codes[0].qr.as_ref().unwrap().amount(), Some(99.5));
+        assert_eq!(codes[1].text, "https://example.com");
+        assert!(codes[1].qr.is_none());
+
+        assert_eq!(
+            PromptPayQR::from_image(&png).unwrap().create().unwrap(),
+            payload
+        );
+    }
+
+    /// ทดสอบภาพ JPEG (มี artifact จากการบีบอัด) และข้อผิดพลาด
+    #[test]
+    fn test_scan_jpeg_and_errors() {
+        let payload = PromptPayQR::new("0812345678").create().unwrap();
+        let image = render(&[(&payload, (100, 60), 5)]);
+        let mut jpeg = Vec::new();
+        JpegEncoder::new_with_quality(&mut jpeg, 75)
+            .encode_image(&image)
+            .unwrap();
+        assert_eq!(
+            PromptPayQR::from_image(&jpeg).unwrap().create().unwrap(),
+            payload
+        );
+
+        let err = scan_image(b"not an image").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let mut blank = Vec::new();
+        render(&[])
+            .write_to(&mut Cursor::new(&mut blank), ImageFormat::Png)
+            .unwrap();
+        let err = PromptPayQR::from_image(&blank).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPayload);
+    }
+}
@@ -0,0 +1,474 @@
+//! **Serde support** and **JSON Schema** for QR definitions (requires the `serde` feature).
+//!
+//! [`PromptPayQR`], [`CountryCode`], [`CurrencyCode`] and [`MerchantType`]
+//! implement `Serialize` / `Deserialize` with stable representations, so QR
+//! definitions can be stored and transported without mirror structs:
+//!
+//! | Type | Representation |
+//! |------|----------------|
+//! | [`CountryCode`] | ISO 3166-1 alpha-2 string, `"TH"` |
+//! | [`CurrencyCode`] | ISO 4217 numeric string, `"764"` |
+//! | [`MerchantType`] | [`MerchantType::name`]: `"mobile_number"`, `"tax_id"`, `"ewallet_id"` or `"biller_id"` |
+//! | amount | Decimal string with up to 2 decimals, e.g. `"150.00"` |
+//! | [`PromptPayQR`] | Object described by [`json_schema`] |
+//!
+//! Deserializing a [`PromptPayQR`] applies the same checks as
+//! [`PromptPayQR::create`], so a definition that decodes always produces a
+//! payload. `merchant_type` may be omitted and is then inferred from
+//! `merchant_id`; a `reference1` makes the QR a bill payment.
+//!
+//! # Example
+//! ```rust
+//! # #[cfg(feature = "serde")] {
+//! use promptpay_rs::PromptPayQR;
+//!
+//! let mut qr = PromptPayQR::new("0812345678");
+//! qr.set_amount(150.0);
+//! let json = serde_json::to_string(&qr).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"merchant_id":"0812345678","merchant_type":"mobile_number","amount":"150.00","country_code":"TH","currency_code":"764"}"#
+//! );
+//! assert_eq!(serde_json::from_str::<PromptPayQR>(&json).unwrap(), qr);
+//!
+//! // จำนวนเงินต้องเป็นทศนิยมไม่เกิน 2 ตำแหน่ง
+//! assert!(serde_json::from_str::<PromptPayQR>(r#"{"merchant_id":"0812345678","amount":"1.234"}"#).is_err());
+//! # }
+//! ```
+
+use crate::{
+    CountryCode, CurrencyCode, PromptPayQR, additional_data::AdditionalData,
+    constants::MerchantType, language::MerchantLanguage,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use serde_json::{Value, json};
+
+// ความยาวสูงสุดของค่า tag 54 ตาม EMVCo
+const MAX_AMOUNT_LENGTH: usize = 13;
+
+impl Serialize for CountryCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CountryCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match value.as_str() {
+            "TH" => Ok(CountryCode::Thailand),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&value),
+                &"country code \"TH\"",
+            )),
+        }
+    }
+}
+
+impl Serialize for CurrencyCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.numeric_code())
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match value.as_str() {
+            "764" => Ok(CurrencyCode::THB),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&value),
+                &"numeric currency code \"764\"",
+            )),
+        }
+    }
+}
+
+const MERCHANT_TYPE_NAMES: [&str; 4] = [
+    MerchantType::MobileNumber.name(),
+    MerchantType::TaxId.name(),
+    MerchantType::EWalletId.name(),
+    MerchantType::BillerId.name(),
+];
+
+impl Serialize for MerchantType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for MerchantType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value
+            .parse()
+            .map_err(|_| de::Error::unknown_variant(&value, &MERCHANT_TYPE_NAMES))
+    }
+}
+
+// จำนวนเงินในรูปทศนิยมแบบข้อความ เพื่อไม่ให้ค่าเพี้ยนจาก floating point ของ JSON
+struct Amount(f64);
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:.2}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let (whole, fraction) = value.split_once('.').unwrap_or((&value, "00"));
+        let valid = value.len() <= MAX_AMOUNT_LENGTH
+            && !whole.is_empty()
+            && whole.bytes().all(|b| b.is_ascii_digit())
+            && (1..=2).contains(&fraction.len())
+            && fraction.bytes().all(|b| b.is_ascii_digit());
+        match value.parse::<f64>() {
+            Ok(amount) if valid => Ok(Amount(amount)),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&value),
+                &"a decimal string with up to 2 decimals, e.g. \"150.00\"",
+            )),
+        }
+    }
+}
+
+// รูปแบบ JSON ของ PromptPayQR (ฟิลด์ของ PromptPayQR เป็น private)
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct QrDefinition {
+    merchant_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    merchant_type: Option<MerchantType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    amount: Option<Amount>,
+    #[serde(default = "default_country_code")]
+    country_code: CountryCode,
+    #[serde(default = "default_currency_code")]
+    currency_code: CurrencyCode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reference1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reference2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    merchant_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    merchant_city: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    additional_data: Option<AdditionalData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    merchant_language: Option<MerchantLanguage>,
+}
+
+fn default_country_code() -> CountryCode {
+    CountryCode::Thailand
+}
+
+fn default_currency_code() -> CurrencyCode {
+    CurrencyCode::THB
+}
+
+impl Serialize for PromptPayQR {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        QrDefinition {
+            merchant_id: self.merchant_id().to_string(),
+            merchant_type: Some(self.merchant_type()),
+            amount: self.amount().map(Amount),
+            country_code: self.country_code(),
+            currency_code: self.currency_code(),
+            reference1: self.reference1().map(str::to_string),
+            reference2: self.reference2().map(str::to_string),
+            merchant_name: self.merchant_name().map(str::to_string),
+            merchant_city: self.merchant_city().map(str::to_string),
+            additional_data: self.additional_data().cloned(),
+            merchant_language: self.merchant_language().cloned(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PromptPayQR {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let definition = QrDefinition::deserialize(deserializer)?;
+        let mut qr = match (definition.merchant_type, definition.reference1) {
+            (None | Some(MerchantType::BillerId), Some(ref1)) => PromptPayQR::bill_payment(
+                &definition.merchant_id,
+                &ref1,
+                definition.reference2.as_deref(),
+            ),
+            (Some(MerchantType::BillerId), None) => {
+                return Err(de::Error::missing_field("reference1"));
+            }
+            (Some(_), Some(_)) => {
+                return Err(de::Error::custom(
+                    "reference1 is only allowed with merchant_type \"biller_id\"",
+                ));
+            }
+            (merchant_type, None) => {
+                if definition.reference2.is_some() {
+                    return Err(de::Error::custom("reference2 requires reference1"));
+                }
+                let qr = PromptPayQR::new(&definition.merchant_id);
+                // ประเภทที่ระบุต้องตรงกับที่อนุมานได้จาก merchant_id
+                if merchant_type.is_some_and(|t| t != qr.merchant_type()) {
+                    return Err(de::Error::custom(format!(
+                        "merchant_type does not match merchant_id {:?}",
+                        definition.merchant_id
+                    )));
+                }
+                qr
+            }
+        };
+        if let Some(Amount(amount)) = definition.amount {
+            qr.set_amount(amount);
+        }
+        if let Some(name) = &definition.merchant_name {
+            qr.set_merchant_name(name);
+        }
+        if let Some(city) = &definition.merchant_city {
+            qr.set_merchant_city(city);
+        }
+        if let Some(data) = definition.additional_data {
+            qr.set_additional_data(data);
+        }
+        if let Some(language) = definition.merchant_language {
+            qr.set_merchant_language(language);
+        }
+
+        // ตรวจสอบด้วยกฎเดียวกับตอนสร้าง payload
+        qr.create().map_err(de::Error::custom)?;
+        Ok(qr)
+    }
+}
+
+impl PromptPayQR {
+    /// Returns the QR as a JSON object described by [`json_schema`], e.g. to
+    /// report a decoded payload; fields that are not set are left out.
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::PromptPayQR;
+    /// let mut qr = PromptPayQR::new("0812345678");
+    /// qr.set_amount(150.0);
+    /// let json = PromptPayQR::parse(&qr.create().unwrap()).unwrap().to_json();
+    /// assert_eq!(json["merchant_type"], "mobile_number");
+    /// assert_eq!(json["amount"], "150.00");
+    /// ```
+    pub fn to_json(&self) -> Value {
+        serde_json::to_value(self).expect("QR definitions serialize to JSON")
+    }
+}
+
+/// Returns the **JSON Schema** (draft 2020-12) of a serialized [`PromptPayQR`].
+///
+/// The same document is shipped as `schema/promptpay-qr.schema.json`.
+pub fn json_schema() -> Value {
+    let text = |max: u32, description: &str| json!({ "type": "string", "minLength": 1, "maxLength": max, "description": description });
+    let reference = |description: &str| json!({ "type": "string", "pattern": "^[0-9A-Z]{1,20}$", "description": description });
+    let latin = |max: u32, description: &str| {
+        json!({
+            "type": "string",
+            "minLength": 1,
+            "maxLength": max,
+            "pattern": "^[\\u0020-\\u007E]+$",
+            "description": description
+        })
+    };
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "PromptPayQR",
+        "description": "PromptPay QR definition serialized by promptpay-rs (`serde` feature).",
+        "type": "object",
+        "required": ["merchant_id"],
+        "additionalProperties": false,
+        "properties": {
+            "merchant_id": {
+                "type": "string",
+                "minLength": 1,
+                "description": "Mobile number, national / tax ID, e-wallet ID or 15-digit Biller ID."
+            },
+            "merchant_type": { "$ref": "#/$defs/MerchantType" },
+            "amount": { "$ref": "#/$defs/Amount" },
+            "country_code": { "$ref": "#/$defs/CountryCode" },
+            "currency_code": { "$ref": "#/$defs/CurrencyCode" },
+            "reference1": reference("Ref1 of a bill payment QR; makes the QR a bill payment."),
+            "reference2": reference("Ref2 of a bill payment QR."),
+            "merchant_name": latin(25, "Merchant name in Latin script (tag 59)."),
+            "merchant_city": latin(15, "Merchant city in Latin script (tag 60)."),
+            "additional_data": { "$ref": "#/$defs/AdditionalData" },
+            "merchant_language": { "$ref": "#/$defs/MerchantLanguage" }
+        },
+        "dependentRequired": { "reference2": ["reference1"] },
+        "if": {
+            "required": ["merchant_type"],
+            "properties": { "merchant_type": { "const": "biller_id" } }
+        },
+        "then": { "required": ["reference1"] },
+        "$defs": {
+            "CountryCode": {
+                "description": "ISO 3166-1 alpha-2 country code (tag 58).",
+                "const": "TH"
+            },
+            "CurrencyCode": {
+                "description": "ISO 4217 numeric currency code (tag 53).",
+                "const": "764"
+            },
+            "MerchantType": {
+                "description": "Kind of proxy; inferred from merchant_id when omitted.",
+                "enum": MERCHANT_TYPE_NAMES
+            },
+            "Amount": {
+                "description": "Amount in THB as a decimal string (tag 54).",
+                "type": "string",
+                "pattern": "^[0-9]+(\\.[0-9]{1,2})?$",
+                "maxLength": MAX_AMOUNT_LENGTH
+            },
+            "AdditionalData": {
+                "description": "Additional data field template (tag 62).",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "bill_number": text(25, "01 Bill number or invoice number."),
+                    "mobile_number": text(25, "02 Mobile number."),
+                    "store_label": text(25, "03 Store label."),
+                    "loyalty_number": text(25, "04 Loyalty number."),
+                    "reference_label": text(25, "05 Reference label."),
+                    "customer_label": text(25, "06 Customer label."),
+                    "terminal_label": text(25, "07 Terminal label."),
+                    "purpose": text(25, "08 Purpose of transaction.")
+                }
+            },
+            "MerchantLanguage": {
+                "description": "Merchant name and city in an alternate language (tag 64).",
+                "type": "object",
+                "required": ["language", "merchant_name"],
+                "additionalProperties": false,
+                "properties": {
+                    "language": {
+                        "type": "string",
+                        "pattern": "^[A-Za-z]{2}$",
+                        "description": "ISO 639-1 language code, e.g. \"TH\"."
+                    },
+                    "merchant_name": text(25, "Merchant name in the alternate language."),
+                    "merchant_city": text(15, "Merchant city in the alternate language.")
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการแปลงเป็น JSON และกลับ รวมถึง bill payment และ tag 62 / 64
+    #[test]
+    fn test_serde_round_trip() {
+        let mut qr = PromptPayQR::bill_payment("010753600031508", "CUST001", Some("INV2025001"));
+        qr.set_amount(1500.5)
+            .set_merchant_name("COFFEE HOUSE")
+            .set_additional_data(AdditionalData {
+                terminal_label: Some("TABLE-07".to_string()),
+                ..AdditionalData::default()
+            })
+            .set_merchant_language(MerchantLanguage::thai("ร้านกาแฟ"));
+        let value = serde_json::to_value(&qr).unwrap();
+        assert_eq!(value["merchant_type"], "biller_id");
+        assert_eq!(value["amount"], "1500.50");
+        assert_eq!(value["country_code"], "TH");
+        assert_eq!(value["currency_code"], "764");
+        assert_eq!(
+            value["additional_data"],
+            json!({ "terminal_label": "TABLE-07" })
+        );
+        assert_eq!(
+            value["merchant_language"],
+            json!({ "language": "TH", "merchant_name": "ร้านกาแฟ" })
+        );
+        let decoded: PromptPayQR = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded, qr);
+        assert_eq!(decoded.create().unwrap(), qr.create().unwrap());
+
+        // merchant_type อนุมานได้จาก merchant_id
+        let decoded: PromptPayQR =
+            serde_json::from_str(r#"{"merchant_id": "1234567890123"}"#).unwrap();
+        assert_eq!(decoded.merchant_type(), MerchantType::TaxId);
+        assert_eq!(
+            serde_json::to_string(&CountryCode::Thailand).unwrap(),
+            r#""TH""#
+        );
+        assert_eq!(
+            serde_json::from_str::<CurrencyCode>(r#""764""#).unwrap(),
+            CurrencyCode::THB
+        );
+    }
+
+    /// ทดสอบว่า QR ที่ได้จาก parse แปลงเป็น JSON และกลับได้ รวมถึงจำนวนเงิน 0.00
+    #[test]
+    fn test_serde_round_trip_parsed() {
+        for (amount, json_amount) in [
+            (Some(0.0), json!("0.00")),
+            (Some(150.0), json!("150.00")),
+            (None, Value::Null),
+        ] {
+            let mut qr = PromptPayQR::new("0812345678");
+            if let Some(amount) = amount {
+                qr.set_amount(amount);
+            }
+            let decoded = PromptPayQR::parse(&qr.create().unwrap()).unwrap();
+            let value = serde_json::to_value(&decoded).unwrap();
+            assert_eq!(value["amount"], json_amount);
+            let round_trip: PromptPayQR = serde_json::from_value(value).unwrap();
+            assert_eq!(round_trip, decoded);
+            assert_eq!(round_trip.create().unwrap(), qr.create().unwrap());
+        }
+    }
+
+    /// ทดสอบการปฏิเสธข้อมูลที่ไม่ถูกต้องตอน deserialize
+    #[test]
+    fn test_serde_validation() {
+        let invalid = [
+            r#"{"merchant_id": "0812345678", "amount": 150}"#,
+            r#"{"merchant_id": "0812345678", "amount": "-1.00"}"#,
+            r#"{"merchant_id": "0812345678", "amount": "1.234"}"#,
+            r#"{"merchant_id": "0812345678", "amount": "1e3"}"#,
+            r#"{"merchant_id": "0812345678", "country_code": "Thailand"}"#,
+            r#"{"merchant_id": "0812345678", "currency_code": "THB"}"#,
+            r#"{"merchant_id": "0812345678", "merchant_type": "tax_id"}"#,
+            r#"{"merchant_id": "0812345678", "reference2": "INV1"}"#,
+            r#"{"merchant_id": "0812345678", "merchant_name": "ร้านกาแฟ"}"#,
+            r#"{"merchant_id": "0812345678", "colour": "red"}"#,
+            r#"{"merchant_id": "010753600031508", "merchant_type": "biller_id"}"#,
+            r#"{"merchant_id": "0107536", "reference1": "CUST001"}"#,
+            r#"{"merchant_id": ""}"#,
+        ];
+        for json in invalid {
+            assert!(
+                serde_json::from_str::<PromptPayQR>(json).is_err(),
+                "{}",
+                json
+            );
+        }
+        assert!(serde_json::from_str::<MerchantType>(r#""bank""#).is_err());
+    }
+
+    /// ทดสอบว่าไฟล์ schema ใน repository ตรงกับ json_schema()
+    #[test]
+    fn test_json_schema_file() {
+        let file: Value =
+            serde_json::from_str(include_str!("../schema/promptpay-qr.schema.json")).unwrap();
+        assert_eq!(file, json_schema());
+        assert_eq!(
+            json_schema()["$defs"]["MerchantType"]["enum"],
+            serde_json::to_value([
+                MerchantType::MobileNumber,
+                MerchantType::TaxId,
+                MerchantType::EWalletId,
+                MerchantType::BillerId
+            ])
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,722 @@
+//! **HTTP service** for generating and decoding payloads (requires the `server` feature).
+//!
+//! Lets services written in other languages share this implementation instead
+//! of keeping their own copy of the payload logic. Run the bundled
+//! `promptpay-server` binary, or mount [`router`] in an existing axum app.
+//!
+//! | Method | Path            | Description                                              |
+//! |--------|-----------------|----------------------------------------------------------|
+//! | `POST` | `/qr`           | JSON request → payload and SVG / PNG `data:` URI         |
+//! | `GET`  | `/qr.png`       | Query parameters → PNG image                             |
+//! | `GET`  | `/qr.svg`       | Query parameters → SVG image                             |
+//! | `POST` | `/decode`       | `{"payload": "..."}` → decoded fields and lint warnings  |
+//! | `GET`  | `/openapi.json` | OpenAPI 3.1 description of the endpoints                 |
+//!
+//! Invalid input is answered with `400` (malformed request or field) or `422`
+//! (payload that does not decode), with a body of the form
+//! `{"error": {"kind": "crc_mismatch", "message": "..."}}`. Rendered images are
+//! kept in a bounded in-memory cache keyed by payload and render options, and
+//! the `GET` endpoints send an `ETag` so clients and proxies can cache them too.
+//!
+//! ```toml
+//! [dependencies]
+//! promptpay-rs = { version = "0.6.0", features = ["server"] }
+//! ```
+
+use crate::{
+    EcLevel, PromptPayError, PromptPayQR,
+    additional_data::AdditionalData,
+    error::ErrorKind,
+    html::data_uri,
+    language::MerchantLanguage,
+    lint::{Severity, lint},
+    render::RenderOptions,
+};
+use axum::{
+    Json, Router,
+    extract::{
+        Query, State,
+        rejection::{JsonRejection, QueryRejection},
+    },
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+/// Number of rendered images [`router`] keeps by default.
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+// ขนาด module สูงสุดที่ยอมรับ (ป้องกันการขอภาพขนาดใหญ่เกินไป)
+const MAX_MODULE_SIZE: u32 = 32;
+
+/// Builds the service routes.
+///
+/// # Arguments
+/// * `cache_capacity` - Number of rendered images kept in memory (`0` disables the cache)
+///
+/// # Example
+/// ```rust,no_run
+/// use promptpay_rs::server::{DEFAULT_CACHE_CAPACITY, router};
+///
+/// # async fn run() -> std::io::Result<()> {
+/// let listener = tokio::net::TcpListener::bind("127.0.0.1:8080").await?;
+/// axum::serve(listener, router(DEFAULT_CACHE_CAPACITY)).await
+/// # }
+/// ```
+pub fn router(cache_capacity: usize) -> Router {
+    let cache = Arc::new(RenderCache::new(cache_capacity));
+    Router::new()
+        .route("/qr", post(create_qr))
+        .route(
+            "/qr.png",
+            get(|s, h, q| image_qr(s, h, q, ImageFormat::Png)),
+        )
+        .route(
+            "/qr.svg",
+            get(|s, h, q| image_qr(s, h, q, ImageFormat::Svg)),
+        )
+        .route("/decode", post(decode))
+        .route("/openapi.json", get(|| async { Json(openapi()) }))
+        .with_state(cache)
+}
+
+/// Returns the OpenAPI 3.1 description served at `/openapi.json`.
+pub fn openapi() -> Value {
+    let text = |description: &str| json!({ "type": "string", "description": description });
+    let error = |description: &str| {
+        json!({
+            "description": description,
+            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Error" } } }
+        })
+    };
+    let mut properties = serde_json::Map::new();
+    properties.insert(
+        "proxy".into(),
+        text("Mobile number, national / tax ID or e-wallet ID; Biller ID with ref1"),
+    );
+    properties.insert(
+        "amount".into(),
+        json!({ "type": "number", "minimum": 0, "description": "Amount in THB (dynamic QR)" }),
+    );
+    for (name, description) in REQUEST_TEXT_FIELDS {
+        properties.insert(name.into(), text(description));
+    }
+    properties.insert(
+        "format".into(),
+        json!({ "type": "string", "enum": ["svg", "png"], "default": "svg",
+                "description": "Image format of POST /qr (GET endpoints use the path extension)" }),
+    );
+    properties.insert(
+        "ec_level".into(),
+        json!({ "type": "string", "enum": ["L", "M", "Q", "H"], "default": "M" }),
+    );
+    properties.insert(
+        "module_size".into(),
+        json!({ "type": "integer", "minimum": 1, "maximum": MAX_MODULE_SIZE, "default": 8,
+                "description": "Pixels (PNG) or user units (SVG) per module" }),
+    );
+    let query_parameters: Vec<Value> = properties
+        .iter()
+        .filter(|(name, _)| *name != "format")
+        .map(|(name, schema)| {
+            json!({ "name": name, "in": "query", "required": name == "proxy", "schema": schema })
+        })
+        .collect();
+    let image = |mime: &str| {
+        json!({
+            "parameters": query_parameters,
+            "responses": {
+                "200": {
+                    "description": "QR code image",
+                    "headers": { "ETag": { "schema": { "type": "string" } } },
+                    "content": { mime: { "schema": { "type": "string", "format": "binary" } } }
+                },
+                "304": { "description": "Not modified (If-None-Match)" },
+                "400": error("Invalid parameters"),
+                "422": error("QR cannot be rendered")
+            }
+        })
+    };
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "PromptPay QR service",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Generates and decodes PromptPay / Thai QR Payment payloads."
+        },
+        "paths": {
+            "/qr": { "post": {
+                "summary": "Generate a payload and image",
+                "requestBody": { "required": true, "content": { "application/json": {
+                    "schema": { "$ref": "#/components/schemas/QrRequest" } } } },
+                "responses": {
+                    "200": { "description": "Payload and image", "content": { "application/json": {
+                        "schema": { "$ref": "#/components/schemas/QrResponse" } } } },
+                    "400": error("Invalid request or field")
+                }
+            } },
+            "/qr.png": { "get": image("image/png") },
+            "/qr.svg": { "get": image("image/svg+xml") },
+            "/decode": { "post": {
+                "summary": "Decode and lint a payload",
+                "requestBody": { "required": true, "content": { "application/json": {
+                    "schema": { "type": "object", "required": ["payload"],
+                                "properties": { "payload": { "type": "string" } } } } } },
+                "responses": {
+                    "200": { "description": "Decoded payload", "content": { "application/json": {
+                        "schema": { "$ref": "#/components/schemas/DecodeResponse" } } } },
+                    "400": error("Invalid request"),
+                    "422": error("Payload does not decode")
+                }
+            } }
+        },
+        "components": { "schemas": {
+            "QrRequest": {
+                "type": "object",
+                "required": ["proxy"],
+                "additionalProperties": false,
+                "properties": properties
+            },
+            "QrResponse": {
+                "type": "object",
+                "properties": {
+                    "payload": { "type": "string" },
+                    "image": text("data: URI of the rendered QR code")
+                }
+            },
+            "DecodeResponse": {
+                "type": "object",
+                "properties": {
+                    "payload": { "type": "string" },
+                    "qr": { "type": "object", "description": "Decoded fields" },
+                    "warnings": { "type": "array", "items": { "type": "object", "properties": {
+                        "tag": { "type": ["string", "null"] }, "message": { "type": "string" } } } }
+                }
+            },
+            "Error": {
+                "type": "object",
+                "properties": { "error": { "type": "object", "properties": {
+                    "kind": { "type": "string", "enum": [
+                        "invalid_request", "invalid_input", "invalid_payload",
+                        "crc_mismatch", "render", "other"
+                    ] },
+                    "message": { "type": "string" }
+                } } }
+            }
+        } }
+    })
+}
+
+// ฟิลด์ข้อความของ QrRequest (นอกจาก proxy) สำหรับคำอธิบาย OpenAPI
+const REQUEST_TEXT_FIELDS: [(&str, &str); 13] = [
+    ("ref1", "Ref1 of a bill payment QR (tag 30)"),
+    ("ref2", "Ref2 of a bill payment QR"),
+    ("merchant_name", "Merchant name in Latin script (tag 59)"),
+    ("merchant_city", "Merchant city in Latin script (tag 60)"),
+    ("thai_name", "Merchant name in Thai (tag 64)"),
+    ("bill_number", "Bill number (tag 62-01)"),
+    ("mobile_number", "Mobile number (tag 62-02)"),
+    ("store_label", "Store label (tag 62-03)"),
+    ("loyalty_number", "Loyalty number (tag 62-04)"),
+    ("reference_label", "Reference label (tag 62-05)"),
+    ("customer_label", "Customer label (tag 62-06)"),
+    ("terminal_label", "Terminal label (tag 62-07)"),
+    ("purpose", "Purpose of transaction (tag 62-08)"),
+];
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct QrRequest {
+    proxy: String,
+    amount: Option<f64>,
+    ref1: Option<String>,
+    ref2: Option<String>,
+    merchant_name: Option<String>,
+    merchant_city: Option<String>,
+    thai_name: Option<String>,
+    bill_number: Option<String>,
+    mobile_number: Option<String>,
+    store_label: Option<String>,
+    loyalty_number: Option<String>,
+    reference_label: Option<String>,
+    customer_label: Option<String>,
+    terminal_label: Option<String>,
+    purpose: Option<String>,
+    #[serde(default)]
+    format: ImageFormat,
+    #[serde(default)]
+    ec_level: Level,
+    module_size: Option<u32>,
+}
+
+impl QrRequest {
+    fn to_qr(&self) -> Result<PromptPayQR, ApiError> {
+        let mut qr = match &self.ref1 {
+            Some(ref1) => PromptPayQR::bill_payment(&self.proxy, ref1, self.ref2.as_deref()),
+            None if self.ref2.is_some() => {
+                return Err(ApiError::request("ref2 requires ref1"));
+            }
+            None => PromptPayQR::new(&self.proxy),
+        };
+        if let Some(amount) = self.amount {
+            if !amount.is_finite() || amount < 0.0 {
+                return Err(ApiError::request("amount must be a non-negative number"));
+            }
+            qr.set_amount(amount);
+        }
+        if let Some(name) = &self.merchant_name {
+            qr.set_merchant_name(name);
+        }
+        if let Some(city) = &self.merchant_city {
+            qr.set_merchant_city(city);
+        }
+        if let Some(name) = &self.thai_name {
+            qr.set_merchant_language(MerchantLanguage::thai(name));
+        }
+        qr.set_additional_data(AdditionalData {
+            bill_number: self.bill_number.clone(),
+            mobile_number: self.mobile_number.clone(),
+            store_label: self.store_label.clone(),
+            loyalty_number: self.loyalty_number.clone(),
+            reference_label: self.reference_label.clone(),
+            customer_label: self.customer_label.clone(),
+            terminal_label: self.terminal_label.clone(),
+            purpose: self.purpose.clone(),
+        });
+        Ok(qr)
+    }
+
+    fn render_options(&self) -> Result<RenderOptions, ApiError> {
+        let module_size = self.module_size.unwrap_or(8);
+        if !(1..=MAX_MODULE_SIZE).contains(&module_size) {
+            return Err(ApiError::request(&format!(
+                "module_size must be 1-{}",
+                MAX_MODULE_SIZE
+            )));
+        }
+        Ok(RenderOptions {
+            module_size,
+            ec_level: self.ec_level.into(),
+            ..RenderOptions::default()
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ImageFormat {
+    #[default]
+    Svg,
+    Png,
+}
+
+impl ImageFormat {
+    fn mime_type(self) -> &'static str {
+        match self {
+            ImageFormat::Svg => "image/svg+xml",
+            ImageFormat::Png => "image/png",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+enum Level {
+    L,
+    #[default]
+    M,
+    Q,
+    H,
+}
+
+impl From<Level> for EcLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::L => EcLevel::L,
+            Level::M => EcLevel::M,
+            Level::Q => EcLevel::Q,
+            Level::H => EcLevel::H,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DecodeRequest {
+    payload: String,
+}
+
+// แคชภาพที่ render แล้ว (FIFO ขนาดจำกัด) key = payload + ตัวเลือกการ render
+#[derive(Debug)]
+struct RenderCache {
+    capacity: usize,
+    entries: Mutex<CacheEntries>,
+}
+
+type CacheKey = (String, ImageFormat, EcLevel, u32);
+
+// ภาพตาม key และลำดับที่ใส่เข้ามา (ใช้ลบรายการเก่าสุดก่อน)
+#[derive(Debug, Default)]
+struct CacheEntries {
+    images: HashMap<CacheKey, Arc<[u8]>>,
+    order: VecDeque<CacheKey>,
+}
+
+impl RenderCache {
+    fn new(capacity: usize) -> Self {
+        RenderCache {
+            capacity,
+            entries: Mutex::default(),
+        }
+    }
+
+    // คืน (ภาพ, true ถ้ามาจากแคช)
+    async fn get_or_render(
+        &self,
+        qr: PromptPayQR,
+        payload: String,
+        format: ImageFormat,
+        options: RenderOptions,
+    ) -> Result<(Arc<[u8]>, bool), PromptPayError> {
+        let key = (payload, format, options.ec_level, options.module_size);
+        if let Some(image) = self.lock().images.get(&key) {
+            return Ok((image.clone(), true));
+        }
+
+        // render นอก lock และใน blocking thread เพื่อไม่ให้ request อื่นต้องรอ
+        let render = tokio::task::spawn_blocking(move || match format {
+            ImageFormat::Svg => qr.to_svg(&options).map(String::into_bytes),
+            ImageFormat::Png => qr.to_png_bytes(&options),
+        });
+        let image: Arc<[u8]> = render
+            .await
+            .map_err(|e| {
+                PromptPayError::with_kind(ErrorKind::Other, &format!("Rendering failed: {}", e))
+            })??
+            .into();
+        if self.capacity > 0 {
+            let entries = &mut *self.lock();
+            if entries.images.insert(key.clone(), image.clone()).is_none() {
+                entries.order.push_back(key);
+            }
+            while entries.images.len() > self.capacity {
+                let Some(oldest) = entries.order.pop_front() else {
+                    break;
+                };
+                entries.images.remove(&oldest);
+            }
+        }
+        Ok((image, false))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheEntries> {
+        // แคชเป็นแค่ตัวช่วย ถ้า thread อื่น panic ขณะถือ lock ก็ใช้ข้อมูลต่อได้
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+async fn create_qr(
+    State(cache): State<Arc<RenderCache>>,
+    request: Result<Json<QrRequest>, JsonRejection>,
+) -> Result<Response, ApiError> {
+    let Json(request) = request.map_err(|e| ApiError::request(&e.body_text()))?;
+    let qr = request.to_qr()?;
+    let options = request.render_options()?;
+    let payload = qr.create()?;
+    let (image, hit) = cache
+        .get_or_render(qr, payload.clone(), request.format, options)
+        .await?;
+    let body = json!({
+        "payload": payload,
+        "image": data_uri(request.format.mime_type(), &image),
+    });
+    Ok(([cache_header(hit)], Json(body)).into_response())
+}
+
+async fn image_qr(
+    State(cache): State<Arc<RenderCache>>,
+    headers: HeaderMap,
+    request: Result<Query<QrRequest>, QueryRejection>,
+    format: ImageFormat,
+) -> Result<Response, ApiError> {
+    let Query(request) = request.map_err(|e| ApiError::request(&e.body_text()))?;
+    let qr = request.to_qr()?;
+    let options = request.render_options()?;
+    let payload = qr.create()?;
+
+    let digest = Sha256::digest(
+        format!(
+            "{}|{:?}|{:?}|{}",
+            payload, format, options.ec_level, options.module_size
+        )
+        .as_bytes(),
+    );
+    let etag: String = digest[..16].iter().map(|b| format!("{:02x}", b)).collect();
+    let etag = format!("\"{}\"", etag);
+    let etag_value = HeaderValue::from_str(&etag).expect("hex ETag is a valid header value");
+    let cache_control = HeaderValue::from_static("public, max-age=86400");
+    if headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| {
+            v.split(',')
+                .any(|tag| tag.trim() == etag || tag.trim() == "*")
+        })
+    {
+        let headers = [
+            (header::ETAG, etag_value),
+            (header::CACHE_CONTROL, cache_control),
+        ];
+        return Ok((StatusCode::NOT_MODIFIED, headers).into_response());
+    }
+
+    let (image, hit) = cache.get_or_render(qr, payload, format, options).await?;
+    let headers = [
+        (
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(format.mime_type()),
+        ),
+        (header::ETAG, etag_value),
+        (header::CACHE_CONTROL, cache_control),
+        cache_header(hit),
+    ];
+    Ok((headers, image.to_vec()).into_response())
+}
+
+async fn decode(
+    request: Result<Json<DecodeRequest>, JsonRejection>,
+) -> Result<Json<Value>, ApiError> {
+    let Json(request) = request.map_err(|e| ApiError::request(&e.body_text()))?;
+    let payload = request.payload.trim();
+    let qr = PromptPayQR::parse(payload)?;
+    let warnings: Vec<Value> = lint(payload)
+        .into_iter()
+        .filter(|issue| issue.severity == Severity::Warning)
+        .map(|issue| json!({ "tag": issue.tag, "message": issue.message }))
+        .collect();
+    Ok(Json(json!({
+        "payload": payload,
+        "qr": qr.to_json(),
+        "warnings": warnings,
+    })))
+}
+
+fn cache_header(hit: bool) -> (header::HeaderName, HeaderValue) {
+    let value = if hit { "hit" } else { "miss" };
+    (
+        header::HeaderName::from_static("x-cache"),
+        HeaderValue::from_static(value),
+    )
+}
+
+// ข้อผิดพลาดที่ส่งกลับเป็น JSON พร้อม status code
+#[derive(Debug)]
+struct ApiError {
+    status: StatusCode,
+    kind: &'static str,
+    message: String,
+}
+
+impl ApiError {
+    fn request(message: &str) -> Self {
+        ApiError {
+            status: StatusCode::BAD_REQUEST,
+            kind: "invalid_request",
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<PromptPayError> for ApiError {
+    fn from(e: PromptPayError) -> Self {
+        let (status, kind) = match e.kind() {
+            ErrorKind::InvalidInput => (StatusCode::BAD_REQUEST, "invalid_input"),
+            ErrorKind::InvalidPayload => (StatusCode::UNPROCESSABLE_ENTITY, "invalid_payload"),
+            ErrorKind::CrcMismatch => (StatusCode::UNPROCESSABLE_ENTITY, "crc_mismatch"),
+            ErrorKind::Render => (StatusCode::UNPROCESSABLE_ENTITY, "render"),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, "other"),
+        };
+        ApiError {
+            status,
+            kind,
+            message: e.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = json!({ "error": { "kind": self.kind, "message": self.message } });
+        (self.status, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    async fn send(app: &Router, request: Request<Body>) -> (StatusCode, HeaderMap, Vec<u8>) {
+        let response = app.clone().oneshot(request).await.unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        (parts.status, parts.headers, bytes.to_vec())
+    }
+
+    fn post_json(uri: &str, body: Value) -> Request<Body> {
+        Request::post(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    fn json_body(bytes: &[u8]) -> Value {
+        serde_json::from_slice(bytes).unwrap()
+    }
+
+    /// ทดสอบ POST /qr: payload, data URI, แคช และการแปลงข้อผิดพลาดเป็น 400
+    #[tokio::test]
+    async fn test_create_qr() {
+        let app = router(DEFAULT_CACHE_CAPACITY);
+        let request =
+            json!({ "proxy": "0812345678", "amount": 150.0, "terminal_label": "TABLE-07" });
+        let (status, headers, body) = send(&app, post_json("/qr", request.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers["x-cache"], "miss");
+        let body = json_body(&body);
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(150.0).set_additional_data(AdditionalData {
+            terminal_label: Some("TABLE-07".to_string()),
+            ..AdditionalData::default()
+        });
+        assert_eq!(body["payload"], qr.create().unwrap());
+        assert!(
+            body["image"]
+                .as_str()
+                .unwrap()
+                .starts_with("data:image/svg+xml;base64,")
+        );
+
+        let (_, headers, _) = send(&app, post_json("/qr", request)).await;
+        assert_eq!(headers["x-cache"], "hit");
+
+        let (status, _, body) = send(&app, post_json("/qr", json!({ "proxy": "" }))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json_body(&body)["error"]["kind"], "invalid_input");
+        let (status, _, body) = send(
+            &app,
+            post_json("/qr", json!({ "proxy": "1", "colour": "red" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json_body(&body)["error"]["kind"], "invalid_request");
+        let request = json!({ "proxy": "0812345678", "module_size": 100 });
+        let (status, _, _) = send(&app, post_json("/qr", request)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    /// ทดสอบ GET /qr.png: ภาพ PNG, ETag และ 304 Not Modified
+    #[tokio::test]
+    async fn test_image_qr() {
+        let app = router(1);
+        let uri = "/qr.png?proxy=0812345678&amount=99.5&module_size=2&ec_level=H";
+        let (status, headers, body) =
+            send(&app, Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[header::CONTENT_TYPE], "image/png");
+        assert_eq!(&body[1..4], b"PNG");
+        let etag = headers[header::ETAG].clone();
+
+        let request = Request::get(uri)
+            .header(header::IF_NONE_MATCH, etag)
+            .body(Body::empty())
+            .unwrap();
+        let (status, _, body) = send(&app, request).await;
+        assert_eq!(status, StatusCode::NOT_MODIFIED);
+        assert!(body.is_empty());
+
+        let uri = "/qr.svg?proxy=0812345678&amount=abc";
+        let (status, _, _) = send(&app, Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _, body) = send(
+            &app,
+            Request::get("/openapi.json").body(Body::empty()).unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let body = json_body(&body);
+        let required: Vec<_> = body["paths"]["/qr.png"]["get"]["parameters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|p| p["required"] == true)
+            .map(|p| p["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(required, ["proxy"]);
+    }
+
+    /// ทดสอบว่า OpenAPI อธิบายฟิลด์ของ QrRequest ครบทุกฟิลด์และไม่มีฟิลด์เกิน
+    #[test]
+    fn test_openapi_request_fields() {
+        let openapi = openapi();
+        let mut documented: Vec<&str> = openapi["components"]["schemas"]["QrRequest"]["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        // serde บอกรายชื่อฟิลด์ทั้งหมดเมื่อเจอฟิลด์ที่ไม่รู้จัก: unknown field `?`, expected one of `proxy`, ...
+        let error = serde_json::from_value::<QrRequest>(json!({ "proxy": "1", "?": 0 }))
+            .unwrap_err()
+            .to_string();
+        let mut fields: Vec<&str> = error.split('`').skip(3).step_by(2).collect();
+        documented.sort_unstable();
+        fields.sort_unstable();
+        assert_eq!(documented, fields);
+
+        let request: QrRequest = serde_json::from_value(json!({
+            "proxy": "0812345678",
+            "mobile_number": "0899999999",
+            "loyalty_number": "***",
+            "purpose": "DONATION",
+        }))
+        .unwrap();
+        let qr = request.to_qr().unwrap();
+        let data = qr.additional_data().unwrap();
+        assert_eq!(data.mobile_number.as_deref(), Some("0899999999"));
+        assert_eq!(data.loyalty_number.as_deref(), Some("***"));
+        assert_eq!(data.purpose.as_deref(), Some("DONATION"));
+    }
+
+    /// ทดสอบ POST /decode: ผลลัพธ์, คำเตือนจาก lint และ 422 เมื่อ CRC ผิด
+    #[tokio::test]
+    async fn test_decode() {
+        let app = router(0);
+        let mut qr = PromptPayQR::new("1234567890123");
+        qr.set_amount(20.0).set_merchant_name("SHOP");
+        let payload = qr.create().unwrap();
+        let (status, _, body) =
+            send(&app, post_json("/decode", json!({ "payload": payload }))).await;
+        assert_eq!(status, StatusCode::OK);
+        let body = json_body(&body);
+        assert_eq!(body["qr"]["merchant_type"], "tax_id");
+        assert_eq!(body["qr"]["amount"], "20.00");
+        assert_eq!(body["qr"]["merchant_name"], "SHOP");
+        assert_eq!(body["warnings"], json!([]));
+
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        let (status, _, body) =
+            send(&app, post_json("/decode", json!({ "payload": tampered }))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(json_body(&body)["error"]["kind"], "crc_mismatch");
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    PromptPayError, PromptPayQR, constants::MerchantType, error::ErrorKind, slip::SlipRef,
+};
+
+/// A transfer recorded by the [`PayerSimulator`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transfer {
+    /// Transaction reference (also encoded in the slip QR)
+    pub transaction_ref: String,
+    /// 3-digit code of the simulated sending bank
+    pub sending_bank: String,
+    /// Recipient as decoded from the QR (local format for mobile numbers)
+    pub merchant_id: String,
+    /// Type of the recipient identifier
+    pub merchant_type: MerchantType,
+    /// Amount paid in THB
+    pub amount: f64,
+    /// Slip verification QR payload printed on the simulated slip
+    pub slip_payload: String,
+}
+
+impl Transfer {
+    /// Returns the slip reference of this transfer.
+    pub fn slip(&self) -> SlipRef {
+        SlipRef::new(&self.sending_bank, &self.transaction_ref)
+    }
+}
+
+/// Offline **payer simulator** for end-to-end tests.
+///
+/// Behaves like a bank app scanning a PromptPay QR:
+/// 1. Decodes and validates the payload (CRC, PromptPay AID, country, currency)
+/// 2. Asks the payer for an amount when the QR is static
+/// 3. "Pays" and records a [`Transfer`] with a slip verification QR
+///
+/// Slips it issued can later be verified with [`PayerSimulator::verify`], covering
+/// the whole scan → pay → verify flow without a real bank.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{PromptPayQR, simulator::PayerSimulator};
+///
+/// let mut qr = PromptPayQR::new("0812345678");
+/// qr.set_amount(120.0);
+///
+/// let mut bank = PayerSimulator::new("014");
+/// let transfer = bank.pay(&qr.create().unwrap(), |_| None).unwrap();
+/// assert_eq!(transfer.amount, 120.0);
+/// let slip_payload = transfer.slip_payload.clone();
+///
+/// let verified = bank.verify(&slip_payload).unwrap();
+/// assert_eq!(verified.merchant_id, "0812345678");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PayerSimulator {
+    sending_bank: String,     // รหัสธนาคารผู้โอน 3 หลัก
+    transfers: Vec<Transfer>, // ประวัติการโอนทั้งหมด
+}
+
+impl PayerSimulator {
+    /// Creates a simulator acting as the app of the given bank.
+    ///
+    /// # Arguments
+    /// * `sending_bank` - 3-digit bank code written into every slip (e.g. `"014"`)
+    pub fn new(sending_bank: &str) -> Self {
+        PayerSimulator {
+            sending_bank: sending_bank.to_string(),
+            transfers: Vec::new(),
+        }
+    }
+
+    /// Scans and pays a PromptPay payload.
+    ///
+    /// # Arguments
+    /// * `payload` - Output of [`PromptPayQR::create`]
+    /// * `prompt_amount` - Called with the decoded QR when it carries no amount
+    ///   (static QR); return `None` to simulate the payer cancelling
+    ///
+    /// # Returns
+    /// * `Ok(&Transfer)` - The recorded transfer
+    /// * `Err(PromptPayError)` - If the payload is rejected, the payer cancels or
+    ///   the amount is not a positive value with at most 2 decimals
+    pub fn pay<F>(&mut self, payload: &str, prompt_amount: F) -> Result<&Transfer, PromptPayError>
+    where
+        F: FnOnce(&PromptPayQR) -> Option<f64>,
+    {
+        let qr = PromptPayQR::parse(payload)?;
+
+        // QR แบบ static ต้องให้ผู้จ่ายกรอกจำนวนเงินเอง
+        let amount = match qr.amount() {
+            Some(amount) => amount,
+            None => prompt_amount(&qr).ok_or_else(|| {
+                PromptPayError::with_kind(ErrorKind::InvalidInput, "Payment cancelled by payer")
+            })?,
+        };
+        if !amount.is_finite()
+            || amount <= 0.0
+            || ((amount * 100.0).round() - amount * 100.0).abs() > 1e-6
+        {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Invalid amount: {}", amount),
+            ));
+        }
+
+        let transaction_ref = format!("SIM{}{:010}", self.sending_bank, self.transfers.len() + 1);
+        let slip_payload = SlipRef::new(&self.sending_bank, &transaction_ref).create()?;
+
+        self.transfers.push(Transfer {
+            transaction_ref,
+            sending_bank: self.sending_bank.clone(),
+            merchant_id: qr.merchant_id().to_string(),
+            merchant_type: qr.merchant_type(),
+            amount,
+            slip_payload,
+        });
+        Ok(self.transfers.last().expect("transfer was just recorded"))
+    }
+
+    /// Verifies a slip QR payload against the transfers made by this simulator.
+    ///
+    /// # Returns
+    /// * `Ok(&Transfer)` - The transfer the slip belongs to
+    /// * `Err(PromptPayError)` - If the slip cannot be decoded or no matching transfer exists
+    pub fn verify(&self, slip_payload: &str) -> Result<&Transfer, PromptPayError> {
+        let slip = SlipRef::parse(slip_payload)?;
+        self.transfers
+            .iter()
+            .find(|t| {
+                t.sending_bank == slip.sending_bank && t.transaction_ref == slip.transaction_ref
+            })
+            .ok_or_else(|| {
+                PromptPayError::with_kind(
+                    ErrorKind::InvalidInput,
+                    &format!("No transfer found for reference {}", slip.transaction_ref),
+                )
+            })
+    }
+
+    /// Returns all transfers made so far, oldest first.
+    pub fn transfers(&self) -> &[Transfer] {
+        &self.transfers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการจ่ายเงินด้วย QR แบบ static ที่ผู้จ่ายต้องกรอกจำนวนเงิน
+    #[test]
+    fn test_pay_static_qr_prompts_amount() {
+        let payload = PromptPayQR::new("1234567890123").create().unwrap();
+        let mut bank = PayerSimulator::new("004");
+
+        let mut prompted = false;
+        let transfer = bank
+            .pay(&payload, |qr| {
+                prompted = true;
+                assert_eq!(qr.merchant_type(), MerchantType::TaxId);
+                Some(59.25)
+            })
+            .unwrap()
+            .clone();
+        assert!(prompted);
+        assert_eq!(transfer.amount, 59.25);
+        assert_eq!(transfer.merchant_id, "1234567890123");
+        assert_eq!(transfer.slip().sending_bank, "004");
+
+        let err = bank.pay(&payload, |_| None).unwrap_err();
+        assert_eq!(err.to_string(), "Payment cancelled by payer");
+        let err = bank.pay(&payload, |_| Some(10.001)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    /// ทดสอบ flow scan → pay → verify และการปฏิเสธ payload ที่ถูกแก้ไข
+    #[test]
+    fn test_pay_and_verify() {
+        let mut qr = PromptPayQR::new("0812345678");
+        qr.set_amount(250.75);
+        let payload = qr.create().unwrap();
+
+        let mut bank = PayerSimulator::new("014");
+        let first = bank
+            .pay(&payload, |_| unreachable!())
+            .unwrap()
+            .transaction_ref
+            .clone();
+        let second = bank
+            .pay(&payload, |_| unreachable!())
+            .unwrap()
+            .slip_payload
+            .clone();
+        assert_eq!(bank.transfers().len(), 2);
+        assert_ne!(first, bank.transfers()[1].transaction_ref);
+
+        let verified = bank.verify(&second).unwrap();
+        assert_eq!(verified.amount, 250.75);
+
+        let other_bank = PayerSimulator::new("014");
+        assert!(other_bank.verify(&second).is_err());
+
+        let tampered = payload.replace("250.75", "251.75");
+        let err = bank.pay(&tampered, |_| None).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CrcMismatch);
+    }
+}
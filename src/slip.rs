@@ -0,0 +1,230 @@
+use crate::{
+    CountryCode, PromptPayError,
+    crc::{calculate_crc, verify_crc},
+    error::ErrorKind,
+    tlv,
+};
+
+/// API ID used by Thai banks for the slip verification QR (sub-tag `00` of tag `00`).
+pub const SLIP_API_ID: &str = "000001";
+
+/// Reference decoded from the **slip verification QR** printed on Thai bank transfer slips.
+///
+/// The slip QR is a small TLV payload:
+/// - `00` Slip data template
+///   - `00` API ID (`"000001"`)
+///   - `01` Sending bank code (3 digits, e.g. `"004"` for KBank)
+///   - `02` Transaction reference
+/// - `51` Country code (`"TH"`)
+/// - `91` CRC-16 (same algorithm as tag `63` of PromptPay payloads)
+///
+/// The reference can be used with the sending bank's verification service to
+/// confirm that the transfer really happened.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::slip::SlipRef;
+///
+/// let slip = SlipRef::parse("0041000600000101030040220015021103612BPM012345102TH9104DB1F").unwrap();
+/// assert_eq!(slip.sending_bank, "004");
+/// assert_eq!(slip.transaction_ref, "015021103612BPM01234");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SlipRef {
+    /// API ID (always [`SLIP_API_ID`] for slips issued by Thai banks)
+    pub api_id: String,
+    /// 3-digit code of the bank that sent the transfer
+    pub sending_bank: String,
+    /// Transaction reference assigned by the sending bank
+    pub transaction_ref: String,
+    /// Country of the sending bank
+    pub country_code: CountryCode,
+}
+
+impl SlipRef {
+    /// Creates a slip reference for a transfer sent from a Thai bank.
+    ///
+    /// # Arguments
+    /// * `sending_bank` - 3-digit bank code (e.g. `"014"` for SCB)
+    /// * `transaction_ref` - Transaction reference assigned by the bank
+    pub fn new(sending_bank: &str, transaction_ref: &str) -> Self {
+        SlipRef {
+            api_id: SLIP_API_ID.to_string(),
+            sending_bank: sending_bank.to_string(),
+            transaction_ref: transaction_ref.to_string(),
+            country_code: CountryCode::Thailand,
+        }
+    }
+
+    /// Generates the slip verification QR payload (inverse of [`SlipRef::parse`]).
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Payload including the tag `91` CRC
+    /// * `Err(PromptPayError)` - If the bank code is not 3 digits or the reference
+    ///   is empty or too long
+    ///
+    /// # Example
+    /// ```rust
+    /// use promptpay_rs::slip::SlipRef;
+    /// let payload = SlipRef::new("004", "015021103612BPM01234").create().unwrap();
+    /// assert_eq!(SlipRef::parse(&payload).unwrap().sending_bank, "004");
+    /// ```
+    pub fn create(&self) -> Result<String, PromptPayError> {
+        if self.sending_bank.len() != 3 || !self.sending_bank.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                &format!("Invalid sending bank code: {}", self.sending_bank),
+            ));
+        }
+        if self.transaction_ref.is_empty() || self.transaction_ref.chars().count() > 50 {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidInput,
+                "Transaction reference must be 1-50 characters",
+            ));
+        }
+
+        let slip_data = format!(
+            "{}{}{}",
+            tlv::encode("00", &self.api_id)?,
+            tlv::encode("01", &self.sending_bank)?,
+            tlv::encode("02", &self.transaction_ref)?
+        );
+        let mut payload = tlv::encode("00", &slip_data)?;
+        payload.push_str(&tlv::encode("51", self.country_code.as_str())?);
+
+        // ID 91: CRC (คำนวณจาก payload + "9104")
+        payload.push_str("9104");
+        let crc = calculate_crc(&payload);
+        payload.push_str(&format!("{:04X}", crc));
+
+        Ok(payload)
+    }
+
+    /// Decodes and CRC-checks a slip verification QR payload.
+    ///
+    /// # Arguments
+    /// * `payload` - Text scanned from the slip QR
+    ///
+    /// # Returns
+    /// * `Ok(SlipRef)` - Decoded reference
+    /// * `Err(PromptPayError)` - If the CRC does not match ([`ErrorKind::CrcMismatch`])
+    ///   or a required field is missing or malformed ([`ErrorKind::InvalidPayload`])
+    pub fn parse(payload: &str) -> Result<Self, PromptPayError> {
+        let payload = payload.trim();
+
+        // ตรวจสอบ CRC (tag 91) ก่อนอ่านข้อมูล
+        if !verify_crc(payload, "91") {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::CrcMismatch,
+                "Slip QR CRC mismatch",
+            ));
+        }
+
+        let fields = tlv::parse(payload)?;
+        let slip_data =
+            tlv::find(&fields, "00").ok_or_else(|| invalid("Slip data (tag 00) is missing"))?;
+        let country =
+            tlv::find(&fields, "51").ok_or_else(|| invalid("Country code (tag 51) is missing"))?;
+
+        let sub_fields = tlv::parse(slip_data)?;
+        let api_id = tlv::find(&sub_fields, "00").ok_or_else(|| invalid("API ID is missing"))?;
+        let sending_bank =
+            tlv::find(&sub_fields, "01").ok_or_else(|| invalid("Sending bank code is missing"))?;
+        let transaction_ref = tlv::find(&sub_fields, "02")
+            .ok_or_else(|| invalid("Transaction reference is missing"))?;
+
+        if api_id != SLIP_API_ID {
+            return Err(invalid(&format!("Unsupported slip API ID: {}", api_id)));
+        }
+        if sending_bank.len() != 3 || !sending_bank.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid(&format!(
+                "Invalid sending bank code: {}",
+                sending_bank
+            )));
+        }
+        if transaction_ref.is_empty() {
+            return Err(invalid("Transaction reference is empty"));
+        }
+        let country_code = CountryCode::from_str(country)
+            .ok_or_else(|| invalid(&format!("Unsupported country code: {}", country)))?;
+
+        Ok(SlipRef {
+            api_id: api_id.to_string(),
+            sending_bank: sending_bank.to_string(),
+            transaction_ref: transaction_ref.to_string(),
+            country_code,
+        })
+    }
+}
+
+fn invalid(msg: &str) -> PromptPayError {
+    PromptPayError::with_kind(ErrorKind::InvalidPayload, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // สร้าง slip payload พร้อม CRC สำหรับใช้ในการทดสอบ
+    fn build_slip(api_id: &str, bank: &str, reference: &str) -> String {
+        let data = format!(
+            "{}{}{}",
+            tlv::encode("00", api_id).unwrap(),
+            tlv::encode("01", bank).unwrap(),
+            tlv::encode("02", reference).unwrap()
+        );
+        let mut payload = format!(
+            "{}{}9104",
+            tlv::encode("00", &data).unwrap(),
+            tlv::encode("51", "TH").unwrap()
+        );
+        let crc = calculate_crc(&payload);
+        payload.push_str(&format!("{:04X}", crc));
+        payload
+    }
+
+    /// ทดสอบการอ่าน slip QR ที่ถูกต้อง
+    #[test]
+    fn test_parse_slip() {
+        let payload = build_slip("000001", "004", "015021103612BPM01234");
+        let slip = SlipRef::parse(&payload).unwrap();
+        assert_eq!(slip.api_id, SLIP_API_ID);
+        assert_eq!(slip.sending_bank, "004");
+        assert_eq!(slip.transaction_ref, "015021103612BPM01234");
+        assert_eq!(slip.country_code, CountryCode::Thailand);
+    }
+
+    /// ทดสอบ CRC ที่ไม่ตรงกัน
+    #[test]
+    fn test_parse_slip_crc_mismatch() {
+        let mut payload = build_slip("000001", "014", "2025010112345");
+        payload.replace_range(payload.len() - 4.., "0000");
+        let err = SlipRef::parse(&payload).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CrcMismatch);
+        payload.replace_range(payload.len() - 4.., "00กก");
+        assert_eq!(SlipRef::parse(&payload).unwrap_err().kind(), ErrorKind::CrcMismatch);
+    }
+
+    /// ทดสอบข้อมูลที่ไม่ถูกต้องแม้ CRC จะถูกต้อง
+    #[test]
+    fn test_parse_slip_invalid_fields() {
+        let err = SlipRef::parse(&build_slip("000002", "014", "2025010112345")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPayload);
+
+        let err = SlipRef::parse(&build_slip("000001", "KB", "2025010112345")).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid sending bank code: KB");
+    }
+
+    /// ทดสอบการสร้าง slip QR แล้วอ่านกลับ
+    #[test]
+    fn test_create_slip_roundtrip() {
+        let slip = SlipRef::new("014", "2025010112345");
+        let payload = slip.create().unwrap();
+        assert_eq!(payload, build_slip("000001", "014", "2025010112345"));
+        assert_eq!(SlipRef::parse(&payload).unwrap(), slip);
+
+        let err = SlipRef::new("14", "2025010112345").create().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+}
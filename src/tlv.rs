@@ -0,0 +1,187 @@
+use crate::{PromptPayError, error::ErrorKind};
+
+/// A single **TLV** (Tag-Length-Value) data object as used by EMVCo payloads.
+///
+/// - Tag: 2 digits (e.g. `"29"`)
+/// - Length: 2 digits, counted in characters
+/// - Value: `length` characters (may itself contain nested TLV objects)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub tag: &'a str,
+    pub value: &'a str,
+}
+
+/// Encodes a single TLV data object.
+///
+/// # Returns
+/// * `Ok(String)` - Tag, 2-digit length and value
+/// * `Err(PromptPayError)` - [`ErrorKind::InvalidInput`] if the value is longer than
+///   99 characters (the length field has 2 digits)
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::tlv::encode;
+/// assert_eq!(encode("58", "TH").unwrap(), "5802TH");
+/// assert!(encode("59", &"A".repeat(100)).is_err());
+/// ```
+pub fn encode(tag: &str, value: &str) -> Result<String, PromptPayError> {
+    let len = value.chars().count();
+    if len > 99 {
+        return Err(PromptPayError::with_kind(
+            ErrorKind::InvalidInput,
+            &format!(
+                "Value of tag {} is {} characters, the maximum is 99",
+                tag, len
+            ),
+        ));
+    }
+    Ok(format!("{}{:02}{}", tag, len, value))
+}
+
+// encode พร้อมตรวจสอบความยาว (1..=max ตัวอักษร) และห้ามมีอักขระควบคุม
+pub(crate) fn encode_checked(
+    tag: &str,
+    value: &str,
+    name: &str,
+    max: usize,
+) -> Result<String, PromptPayError> {
+    let len = value.chars().count();
+    if len == 0 || len > max.min(99) || value.chars().any(char::is_control) {
+        return Err(PromptPayError::with_kind(
+            ErrorKind::InvalidInput,
+            &format!(
+                "{} must be 1-{} printable characters: {:?}",
+                name,
+                max.min(99),
+                value
+            ),
+        ));
+    }
+    encode(tag, value)
+}
+
+/// Splits a string into its top-level TLV data objects.
+///
+/// Nested templates (e.g. tag `29`) are returned as a single field; call `parse`
+/// again on the field value to read the sub-fields.
+///
+/// # Arguments
+/// * `data` - Concatenated TLV data objects
+///
+/// # Returns
+/// * `Ok(Vec<Field>)` - Fields in payload order
+/// * `Err(PromptPayError)` - If a tag or length is malformed, or a value is truncated
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::tlv::parse;
+/// let fields = parse("0002015802TH").unwrap();
+/// assert_eq!(fields[1].tag, "58");
+/// assert_eq!(fields[1].value, "TH");
+/// ```
+pub fn parse(data: &str) -> Result<Vec<Field<'_>>, PromptPayError> {
+    let mut fields = Vec::new();
+    let mut rest = data;
+
+    while !rest.is_empty() {
+        // หัวของแต่ละฟิลด์ต้องเป็นตัวเลข 4 หลัก (tag 2 หลัก + length 2 หลัก)
+        let header = rest
+            .get(..4)
+            .filter(|h| h.bytes().all(|b| b.is_ascii_digit()));
+        let Some(header) = header else {
+            return Err(PromptPayError::with_kind(
+                ErrorKind::InvalidPayload,
+                &format!("Malformed TLV header near \"{}\"", truncate(rest)),
+            ));
+        };
+        let tag = &header[..2];
+        let len: usize = header[2..].parse().unwrap_or_default();
+        let body = &rest[4..];
+
+        // ความยาวนับเป็นจำนวนตัวอักษร (รองรับข้อความภาษาไทยใน tag 64)
+        let end = match body.char_indices().nth(len) {
+            Some((idx, _)) => idx,
+            None if body.chars().count() == len => body.len(),
+            None => {
+                return Err(PromptPayError::with_kind(
+                    ErrorKind::InvalidPayload,
+                    &format!("Value of tag {} is truncated", tag),
+                ));
+            }
+        };
+
+        fields.push(Field {
+            tag,
+            value: &body[..end],
+        });
+        rest = &body[end..];
+    }
+
+    Ok(fields)
+}
+
+/// Returns the value of the first field with the given tag.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::tlv::{find, parse};
+/// let fields = parse("0002015802TH").unwrap();
+/// assert_eq!(find(&fields, "58"), Some("TH"));
+/// assert_eq!(find(&fields, "54"), None);
+/// ```
+pub fn find<'a>(fields: &[Field<'a>], tag: &str) -> Option<&'a str> {
+    fields.iter().find(|f| f.tag == tag).map(|f| f.value)
+}
+
+// ตัดข้อความให้สั้นลงสำหรับแสดงใน error message
+fn truncate(s: &str) -> &str {
+    match s.char_indices().nth(8) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการแยก TLV หลายฟิลด์ รวมถึง template ที่ซ้อนกัน
+    #[test]
+    fn test_parse_nested() {
+        let err = parse("00020129370016").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPayload);
+
+        let fields = parse("0002012906010203").unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[1].tag, "29");
+        let nested = parse(fields[1].value).unwrap();
+        assert_eq!(
+            nested,
+            vec![Field {
+                tag: "01",
+                value: "03"
+            }]
+        );
+    }
+
+    /// ทดสอบความยาวที่นับเป็นตัวอักษรสำหรับข้อความภาษาไทย
+    #[test]
+    fn test_parse_utf8_length() {
+        let data = format!(
+            "{}{}",
+            encode("01", "ร้านกาแฟ").unwrap(),
+            encode("02", "TH").unwrap()
+        );
+        let fields = parse(&data).unwrap();
+        assert_eq!(find(&fields, "01"), Some("ร้านกาแฟ"));
+        assert_eq!(find(&fields, "02"), Some("TH"));
+    }
+
+    /// ทดสอบ header ที่ไม่ใช่ตัวเลข
+    #[test]
+    fn test_parse_malformed_header() {
+        let err = parse("00020158XXTH").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPayload);
+        assert!(parse("").unwrap().is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::constants::MerchantType;
+
 /// Removes all non-digit characters from a merchant identifier.
 ///
 /// Used to clean inputs like phone numbers with hyphens or spaces.
@@ -15,7 +17,7 @@
 /// ```
 pub fn sanitize_target(id: &str) -> String {
     // กรองเฉพาะตัวเลข 0-9 ออกมา
-    id.chars().filter(|c| c.is_digit(10)).collect()
+    id.chars().filter(|c| c.is_ascii_digit()).collect()
 }
 
 /// Formats a **sanitized** merchant ID according to PromptPay rules.
@@ -48,4 +50,144 @@ pub fn format_target(id: &str) -> String {
         // กรณีอื่น (เช่น ขึ้นต้นด้วย 66 อยู่แล้ว)
         format!("{:0>13}", id)
     }
-}
\ No newline at end of file
+}
+
+/// Checks a **bill payment Biller ID** (13-digit Tax ID followed by a 2-digit suffix).
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::utils::is_valid_biller_id;
+/// assert!(is_valid_biller_id("010753600031508"));
+/// assert!(!is_valid_biller_id("0107536000315"));
+/// ```
+pub fn is_valid_biller_id(id: &str) -> bool {
+    id.len() == 15 && id.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Checks a **bill payment reference** (`Ref1` / `Ref2`).
+///
+/// References are 1-20 characters of digits and uppercase English letters,
+/// so they can be carried both in the QR and in the Code 128 bill barcode.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::utils::is_valid_reference;
+/// assert!(is_valid_reference("INV2025001"));
+/// assert!(!is_valid_reference("inv-2025"));
+/// ```
+pub fn is_valid_reference(reference: &str) -> bool {
+    (1..=20).contains(&reference.len())
+        && reference
+            .bytes()
+            .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase())
+}
+
+/// Validates the checksum of a **13-digit Thai national ID / Tax ID**.
+///
+/// The last digit must equal `(11 - (Σ dᵢ × (13 - i)) mod 11) mod 10`,
+/// summed over the first 12 digits (`i` = 0..11).
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::utils::is_valid_thai_id;
+/// assert!(is_valid_thai_id("1101700230708"));
+/// assert!(!is_valid_thai_id("1101700230705"));
+/// ```
+pub fn is_valid_thai_id(id: &str) -> bool {
+    if id.len() != 13 || !id.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    let digits: Vec<u32> = id.bytes().map(|b| (b - b'0') as u32).collect();
+    // ถ่วงน้ำหนัก 13, 12, ..., 2 สำหรับ 12 หลักแรก
+    let sum: u32 = digits[..12]
+        .iter()
+        .enumerate()
+        .map(|(i, d)| d * (13 - i as u32))
+        .sum();
+    (11 - sum % 11) % 10 == digits[12]
+}
+
+/// Formats an amount in THB with thousands separators and two decimals.
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::utils::format_amount;
+/// assert_eq!(format_amount(1250.75), "1,250.75");
+/// assert_eq!(format_amount(5.0), "5.00");
+/// ```
+pub fn format_amount(amount: f64) -> String {
+    let fixed = format!("{:.2}", amount.abs());
+    let (baht, satang) = fixed.split_once('.').unwrap_or((&fixed, "00"));
+    let mut grouped = String::new();
+    for (i, c) in baht.chars().enumerate() {
+        // ใส่ comma ทุก 3 หลักนับจากด้านขวา
+        if i > 0 && (baht.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    let sign = if amount < 0.0 { "-" } else { "" };
+    format!("{}{}.{}", sign, grouped, satang)
+}
+
+/// Masks a PromptPay proxy for display to the payer.
+///
+/// Only the last 4 digits are shown, grouped the way banking apps display them:
+/// - Mobile → `xxx-xxx-5678`
+/// - Tax ID / National ID → `x-xxxx-xxxx5-67-8`
+/// - E-Wallet ID → `xxxxxxxxxxx2345`
+/// - Biller ID → shown in full (it identifies a business, not a person)
+///
+/// # Example
+/// ```rust
+/// use promptpay_rs::{constants::MerchantType, utils::mask_target};
+/// assert_eq!(mask_target(MerchantType::MobileNumber, "081-234-5678"), "xxx-xxx-5678");
+/// assert_eq!(mask_target(MerchantType::TaxId, "1101700230708"), "x-xxxx-xxxx0-70-8");
+/// ```
+pub fn mask_target(merchant_type: MerchantType, id: &str) -> String {
+    let mut digits = sanitize_target(id);
+    if merchant_type == MerchantType::BillerId {
+        return digits;
+    }
+    // เบอร์โทรในรูปแบบสากล (66 / 0066) → แปลงกลับเป็นรูปแบบในประเทศ
+    if merchant_type == MerchantType::MobileNumber {
+        let local = digits.trim_start_matches('0');
+        if let Some(rest) = local.strip_prefix("66") {
+            digits = format!("0{}", rest);
+        }
+    }
+    let keep = digits.len().saturating_sub(4);
+    let masked: String = digits
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i < keep { 'x' } else { c })
+        .collect();
+    let groups: &[usize] = match (merchant_type, masked.len()) {
+        (MerchantType::MobileNumber, 10) => &[3, 3, 4],
+        (MerchantType::TaxId, 13) => &[1, 4, 5, 2, 1],
+        _ => return masked,
+    };
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for len in groups {
+        parts.push(&masked[start..start + len]);
+        start += len;
+    }
+    parts.join("-")
+}
+
+// escape อักขระพิเศษของ XML ในข้อความและค่า attribute (ใช้กับ HTML ได้ด้วย)
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,272 @@
+//! **WebAssembly bindings** for browsers and Node.js (requires the `wasm` feature).
+//!
+//! The library is an `rlib` by default, so build the WebAssembly module as a
+//! `cdylib` explicitly, then generate the JavaScript glue with the
+//! [wasm-bindgen CLI](https://rustwasm.github.io/wasm-bindgen/reference/cli.html):
+//!
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen target/wasm32-unknown-unknown/release/promptpay_rs.wasm --target web --out-dir pkg
+//! ```
+//!
+//! The generated `.d.ts` declares the `PromptPayQR` and `PromptPayError`
+//! classes, the [`verify`] function and string types mirroring the Rust enums
+//! (`CountryCode`, `CurrencyCode`, `MerchantType`, `EcLevel`, `ErrorKind`):
+//!
+//! ```ts
+//! import init, { PromptPayQR, PromptPayError } from "promptpay-rs";
+//!
+//! await init();
+//! const qr = new PromptPayQR("0812345678");
+//! qr.setAmount(150);
+//! const payload = qr.create();
+//! const svg = qr.toSvg("M");
+//!
+//! try {
+//!   PromptPayQR.parse(scanned);
+//! } catch (e) {
+//!   if (e instanceof PromptPayError && e.kind === "CrcMismatch") { /* ... */ }
+//! }
+//! ```
+
+use crate::{
+    EcLevel, PromptPayError, PromptPayQR, error::ErrorKind, language::MerchantLanguage, lint::lint,
+};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &str = r#"
+/** ISO 3166-1 alpha-2 country code (tag 58). */
+export type CountryCode = "TH";
+/** ISO 4217 numeric currency code (tag 53). */
+export type CurrencyCode = "764";
+/** Kind of proxy the payload pays to. */
+export type MerchantType = "mobile_number" | "tax_id" | "ewallet_id" | "biller_id";
+/** QR code error correction level. */
+export type EcLevel = "L" | "M" | "Q" | "H";
+/** Category of a `PromptPayError`. */
+export type ErrorKind = "InvalidInput" | "InvalidPayload" | "CrcMismatch" | "Render" | "Other";
+"#;
+
+/// Error thrown by the bindings, with a machine-readable `kind`.
+#[wasm_bindgen(js_name = PromptPayError)]
+#[derive(Debug, Clone)]
+pub struct JsPromptPayError {
+    kind: &'static str, // ชื่อ ErrorKind ตาม type ErrorKind ของ TypeScript
+    message: String,    // ข้อความอธิบายข้อผิดพลาด
+}
+
+#[wasm_bindgen(js_class = PromptPayError)]
+impl JsPromptPayError {
+    /// Error category.
+    #[wasm_bindgen(getter, unchecked_return_type = "ErrorKind")]
+    pub fn kind(&self) -> String {
+        self.kind.to_string()
+    }
+
+    /// Human-readable description.
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    /// Returns `"<kind>: <message>"`.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        format!("{}: {}", self.kind, self.message)
+    }
+}
+
+impl From<PromptPayError> for JsPromptPayError {
+    fn from(e: PromptPayError) -> Self {
+        let kind = match e.kind() {
+            ErrorKind::InvalidInput => "InvalidInput",
+            ErrorKind::InvalidPayload => "InvalidPayload",
+            ErrorKind::CrcMismatch => "CrcMismatch",
+            ErrorKind::Render => "Render",
+            _ => "Other",
+        };
+        JsPromptPayError {
+            kind,
+            message: e.to_string(),
+        }
+    }
+}
+
+/// A PromptPay payload, mirroring [`PromptPayQR`].
+#[wasm_bindgen(js_name = PromptPayQR)]
+#[derive(Debug, Clone)]
+pub struct JsPromptPayQR(PromptPayQR);
+
+#[wasm_bindgen(js_class = PromptPayQR)]
+impl JsPromptPayQR {
+    /// Creates a credit transfer QR for a mobile number, national / tax ID or e-wallet ID.
+    #[wasm_bindgen(constructor)]
+    pub fn new(proxy: &str) -> JsPromptPayQR {
+        JsPromptPayQR(PromptPayQR::new(proxy))
+    }
+
+    /// Creates a bill payment QR (tag 30).
+    #[wasm_bindgen(js_name = billPayment)]
+    pub fn bill_payment(biller_id: &str, ref1: &str, ref2: Option<String>) -> JsPromptPayQR {
+        JsPromptPayQR(PromptPayQR::bill_payment(biller_id, ref1, ref2.as_deref()))
+    }
+
+    /// Decodes and CRC-checks a payload.
+    pub fn parse(payload: &str) -> Result<JsPromptPayQR, JsPromptPayError> {
+        Ok(JsPromptPayQR(PromptPayQR::parse(payload)?))
+    }
+
+    /// Sets the amount in THB, making the QR dynamic.
+    #[wasm_bindgen(js_name = setAmount)]
+    pub fn set_amount(&mut self, amount: f64) {
+        self.0.set_amount(amount);
+    }
+
+    /// Sets the merchant name in Latin script (tag 59).
+    #[wasm_bindgen(js_name = setMerchantName)]
+    pub fn set_merchant_name(&mut self, name: &str) {
+        self.0.set_merchant_name(name);
+    }
+
+    /// Sets the merchant city in Latin script (tag 60).
+    #[wasm_bindgen(js_name = setMerchantCity)]
+    pub fn set_merchant_city(&mut self, city: &str) {
+        self.0.set_merchant_city(city);
+    }
+
+    /// Sets the merchant name in Thai (tag 64).
+    #[wasm_bindgen(js_name = setThaiName)]
+    pub fn set_thai_name(&mut self, name: &str) {
+        self.0.set_merchant_language(MerchantLanguage::thai(name));
+    }
+
+    /// Builds the payload string.
+    pub fn create(&self) -> Result<String, JsPromptPayError> {
+        Ok(self.0.create()?)
+    }
+
+    /// Renders the payload as an SVG document with the built-in encoder.
+    ///
+    /// `ecLevel` defaults to `"M"`.
+    #[wasm_bindgen(js_name = toSvg)]
+    pub fn to_svg(
+        &self,
+        #[wasm_bindgen(unchecked_param_type = "EcLevel | undefined")] ec_level: Option<String>,
+    ) -> Result<String, JsPromptPayError> {
+        let ec_level = match ec_level {
+            Some(ec_level) => ec_level.parse()?,
+            None => EcLevel::M,
+        };
+        Ok(self.0.to_qr_matrix(ec_level)?.to_svg(None))
+    }
+
+    /// Proxy as given, or as decoded from the payload.
+    #[wasm_bindgen(getter, js_name = merchantId)]
+    pub fn merchant_id(&self) -> String {
+        self.0.merchant_id().to_string()
+    }
+
+    /// Kind of proxy.
+    #[wasm_bindgen(getter, js_name = merchantType, unchecked_return_type = "MerchantType")]
+    pub fn merchant_type(&self) -> String {
+        self.0.merchant_type().name().to_string()
+    }
+
+    /// Amount in THB, `undefined` for a static QR.
+    #[wasm_bindgen(getter)]
+    pub fn amount(&self) -> Option<f64> {
+        self.0.amount()
+    }
+
+    /// Country code (tag 58).
+    #[wasm_bindgen(getter, js_name = countryCode, unchecked_return_type = "CountryCode")]
+    pub fn country_code(&self) -> String {
+        self.0.country_code().as_str().to_string()
+    }
+
+    /// Numeric currency code (tag 53).
+    #[wasm_bindgen(getter, js_name = currencyCode, unchecked_return_type = "CurrencyCode")]
+    pub fn currency_code(&self) -> String {
+        self.0.currency_code().numeric_code().to_string()
+    }
+
+    /// Ref1 of a bill payment QR.
+    #[wasm_bindgen(getter)]
+    pub fn reference1(&self) -> Option<String> {
+        self.0.reference1().map(str::to_string)
+    }
+
+    /// Ref2 of a bill payment QR.
+    #[wasm_bindgen(getter)]
+    pub fn reference2(&self) -> Option<String> {
+        self.0.reference2().map(str::to_string)
+    }
+
+    /// Merchant name (tag 59).
+    #[wasm_bindgen(getter, js_name = merchantName)]
+    pub fn merchant_name(&self) -> Option<String> {
+        self.0.merchant_name().map(str::to_string)
+    }
+
+    /// Merchant city (tag 60).
+    #[wasm_bindgen(getter, js_name = merchantCity)]
+    pub fn merchant_city(&self) -> Option<String> {
+        self.0.merchant_city().map(str::to_string)
+    }
+}
+
+/// Returns `true` if the payload decodes, its CRC matches and [`lint`] finds no errors.
+#[wasm_bindgen]
+pub fn verify(payload: &str) -> bool {
+    PromptPayQR::parse(payload).is_ok() && !lint(payload).iter().any(|issue| issue.is_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ทดสอบการสร้าง, parse และ render SVG ผ่าน binding
+    #[test]
+    fn test_wasm_round_trip() {
+        let mut qr = JsPromptPayQR::new("0812345678");
+        qr.set_amount(150.0);
+        qr.set_merchant_name("COFFEE HOUSE");
+        let payload = qr.create().unwrap();
+        assert!(verify(&payload));
+
+        let parsed = JsPromptPayQR::parse(&payload).unwrap();
+        assert_eq!(parsed.merchant_type(), "mobile_number");
+        assert_eq!(parsed.amount(), Some(150.0));
+        assert_eq!(parsed.country_code(), "TH");
+        assert_eq!(parsed.currency_code(), "764");
+        assert_eq!(parsed.merchant_name().as_deref(), Some("COFFEE HOUSE"));
+
+        let svg = qr.to_svg(Some("h".to_string())).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(
+            qr.to_svg(None).unwrap(),
+            qr.0.to_qr_matrix(EcLevel::M).unwrap().to_svg(None)
+        );
+    }
+
+    /// ทดสอบการแปลงข้อผิดพลาดเป็น PromptPayError ของ JavaScript
+    #[test]
+    fn test_wasm_errors() {
+        let payload = JsPromptPayQR::new("0812345678").create().unwrap();
+        let tampered = format!("{}0000", &payload[..payload.len() - 4]);
+        assert!(!verify(&tampered));
+        let error = JsPromptPayQR::parse(&tampered).unwrap_err();
+        assert_eq!(error.kind(), "CrcMismatch");
+
+        let error = JsPromptPayQR::new("0812345678")
+            .to_svg(Some("X".to_string()))
+            .unwrap_err();
+        assert_eq!(error.kind(), "InvalidInput");
+        assert!(error.to_js_string().starts_with("InvalidInput: "));
+        assert_eq!(
+            JsPromptPayQR::new("").create().unwrap_err().kind(),
+            "InvalidInput"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Compiles `examples/c/pos.c` against the C API and runs it (requires the
+//! `ffi` feature and a C compiler, `$CC` or `cc`).
+
+#![cfg(all(feature = "ffi", unix))]
+
+use promptpay_rs::PromptPayQR;
+use std::{env, path::PathBuf, process::Command};
+
+/// ทดสอบการเรียก C API จากโปรแกรม C จริง: build cdylib, compile pos.c แล้วรัน
+#[test]
+fn test_c_example() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // ใช้ target dir แยก เพราะ cargo test ที่กำลังรันถือ lock ของ target/ อยู่
+    let target_dir = root.join("target").join("ffi-c");
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .args([
+            "rustc",
+            "--lib",
+            "--features",
+            "ffi",
+            "--crate-type",
+            "cdylib",
+        ])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .current_dir(&root)
+        .status()
+        .unwrap();
+    assert!(status.success(), "building the cdylib failed");
+
+    let lib_dir = target_dir.join("debug");
+    let program = target_dir.join("pos");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(&cc)
+        .arg(root.join("examples/c/pos.c"))
+        .arg("-I")
+        .arg(root.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .args(["-lpromptpay_rs", "-Wall", "-Werror", "-o"])
+        .arg(&program)
+        .status()
+        .unwrap_or_else(|e| panic!("cannot run C compiler {:?}: {}", cc, e));
+    assert!(status.success(), "compiling examples/c/pos.c failed");
+
+    // cargo test ตั้ง LD_LIBRARY_PATH ไว้ ซึ่งมาก่อน RUNPATH จึงต้องชี้ไปที่ lib_dir เอง
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .args(["0812345678", "150.00"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines();
+    let payload = lines.next().unwrap();
+    let qr = PromptPayQR::parse(payload).unwrap();
+    assert_eq!(qr.amount(), Some(150.0));
+    assert_eq!(
+        qr.additional_data().unwrap().terminal_label.as_deref(),
+        Some("POS-01")
+    );
+    assert_eq!(lines.next(), Some("=> 0812345678, 150.00 THB"));
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}